@@ -0,0 +1,272 @@
+use {
+    crate::*,
+    std::ops::Neg,
+};
+
+/// Approximate equality, for comparing results of float or fixed-point math.
+///
+/// There are three modes, each of which also accepts values that are within an absolute `epsilon` of each other,
+/// which is needed to compare anything near zero:
+///
+/// - [`abs_diff_eq`](ApproxEq::abs_diff_eq) only compares the absolute difference with `epsilon`.
+/// - [`relative_eq`](ApproxEq::relative_eq) accepts a difference up to `max_relative` times the larger magnitude.
+/// - [`ulps_eq`](ApproxEq::ulps_eq) accepts values that are at most `max_ulps` representable steps apart.
+///
+/// Containers compare field by field, with the same tolerances for every field. Quaternions, rotors, motors and dual
+/// quaternions also equal the negation of the other value, because both describe the same transformation. NaN is
+/// never approximately equal to anything.
+///
+/// The `assert_*_eq!` macros compare with these modes and print both values on failure.
+pub trait ApproxEq {
+    /// Tolerance type, the element type for containers.
+    type Epsilon: Copy;
+
+    /// Default absolute tolerance.
+    const DEFAULT_EPSILON: Self::Epsilon;
+
+    /// Default relative tolerance.
+    const DEFAULT_MAX_RELATIVE: Self::Epsilon;
+
+    /// Default number of representable steps.
+    const DEFAULT_MAX_ULPS: u32;
+
+    /// Equal if the difference is at most `epsilon`.
+    fn abs_diff_eq(&self,other: &Self,epsilon: Self::Epsilon) -> bool;
+
+    /// Equal if the difference is at most `epsilon`, or at most `max_relative` times the larger magnitude.
+    fn relative_eq(&self,other: &Self,epsilon: Self::Epsilon,max_relative: Self::Epsilon) -> bool;
+
+    /// Equal if the difference is at most `epsilon`, or if there are at most `max_ulps` representable values between them.
+    fn ulps_eq(&self,other: &Self,epsilon: Self::Epsilon,max_ulps: u32) -> bool;
+
+    /// [`relative_eq`](ApproxEq::relative_eq) with the default tolerances.
+    fn approx_eq(&self,other: &Self) -> bool {
+        self.relative_eq(other,Self::DEFAULT_EPSILON,Self::DEFAULT_MAX_RELATIVE)
+    }
+}
+
+// used by the assertion macros to get the default tolerances from a value
+#[doc(hidden)]
+pub fn approx_default_epsilon<T: ApproxEq>(_: &T) -> T::Epsilon {
+    T::DEFAULT_EPSILON
+}
+
+#[doc(hidden)]
+pub fn approx_default_max_relative<T: ApproxEq>(_: &T) -> T::Epsilon {
+    T::DEFAULT_MAX_RELATIVE
+}
+
+#[doc(hidden)]
+pub fn approx_default_max_ulps<T: ApproxEq>(_: &T) -> u32 {
+    T::DEFAULT_MAX_ULPS
+}
+
+macro_rules! approx_float_impl {
+    ($($t:ty)+) => {
+        $(
+            impl ApproxEq for $t {
+                type Epsilon = $t;
+                const DEFAULT_EPSILON: $t = <$t>::EPSILON;
+                const DEFAULT_MAX_RELATIVE: $t = <$t>::EPSILON;
+                const DEFAULT_MAX_ULPS: u32 = 4;
+
+                fn abs_diff_eq(&self,other: &$t,epsilon: $t) -> bool {
+                    // exact comparison first, so equal infinities match
+                    (*self == *other) || ((*self - *other).abs() <= epsilon)
+                }
+
+                fn relative_eq(&self,other: &$t,epsilon: $t,max_relative: $t) -> bool {
+                    if *self == *other {
+                        return true;
+                    }
+                    if self.is_infinite() || other.is_infinite() {
+                        return false;
+                    }
+                    let diff = (*self - *other).abs();
+                    if diff <= epsilon {
+                        return true;
+                    }
+                    let largest = if self.abs() > other.abs() { self.abs() } else { other.abs() };
+                    diff <= largest * max_relative
+                }
+
+                fn ulps_eq(&self,other: &$t,epsilon: $t,max_ulps: u32) -> bool {
+                    if self.abs_diff_eq(other,epsilon) {
+                        return true;
+                    }
+                    if self.is_nan() || other.is_nan() || (self.is_sign_negative() != other.is_sign_negative()) {
+                        return false;
+                    }
+                    // with equal signs, the bit patterns are ordered by magnitude
+                    (self.to_bits().abs_diff(other.to_bits()) as u64) <= (max_ulps as u64)
+                }
+            }
+        )+
+    }
+}
+
+approx_float_impl! { f32 f64 }
+
+// containers compare field by field
+macro_rules! approx_struct {
+    ($($s:ident { $($f:ident)+ })+) => {
+        $(
+            impl<T: ApproxEq> ApproxEq for $s<T> {
+                type Epsilon = T::Epsilon;
+                const DEFAULT_EPSILON: T::Epsilon = T::DEFAULT_EPSILON;
+                const DEFAULT_MAX_RELATIVE: T::Epsilon = T::DEFAULT_MAX_RELATIVE;
+                const DEFAULT_MAX_ULPS: u32 = T::DEFAULT_MAX_ULPS;
+
+                fn abs_diff_eq(&self,other: &Self,epsilon: T::Epsilon) -> bool {
+                    $(self.$f.abs_diff_eq(&other.$f,epsilon))&&+
+                }
+
+                fn relative_eq(&self,other: &Self,epsilon: T::Epsilon,max_relative: T::Epsilon) -> bool {
+                    $(self.$f.relative_eq(&other.$f,epsilon,max_relative))&&+
+                }
+
+                fn ulps_eq(&self,other: &Self,epsilon: T::Epsilon,max_ulps: u32) -> bool {
+                    $(self.$f.ulps_eq(&other.$f,epsilon,max_ulps))&&+
+                }
+            }
+        )+
+    }
+}
+
+approx_struct! {
+    Complex { r i }
+    Vec2 { x y }
+    Vec3 { x y z }
+    Vec4 { x y z w }
+    Mat2x2 { x y }
+    Mat2x3 { x y }
+    Mat2x4 { x y }
+    Mat3x2 { x y z }
+    Mat3x3 { x y z }
+    Mat3x4 { x y z }
+    Mat4x2 { x y z w }
+    Mat4x3 { x y z w }
+    Mat4x4 { x y z w }
+    MultiVec2 { r x y xy }
+    MultiVec3 { r x y z xy xz yz xyz }
+    MultiVec4 { r x y z w xy xz xw yz yw zw xyz xyw xzw yzw xyzw }
+    Rect { o s }
+    Rect3 { o s }
+    Plane { n d }
+    Ray { o d }
+    Segment { a b }
+    Triangle { a b c }
+    Sphere { c r }
+    Aabb { min max }
+    Obb { c axes e }
+}
+
+// transformations where the value and its negation are the same, compared field by field against both
+macro_rules! approx_double_cover {
+    ($($s:ident { $($f:ident)+ })+) => {
+        $(
+            impl<T: ApproxEq + Copy + Neg<Output=T>> ApproxEq for $s<T> {
+                type Epsilon = T::Epsilon;
+                const DEFAULT_EPSILON: T::Epsilon = T::DEFAULT_EPSILON;
+                const DEFAULT_MAX_RELATIVE: T::Epsilon = T::DEFAULT_MAX_RELATIVE;
+                const DEFAULT_MAX_ULPS: u32 = T::DEFAULT_MAX_ULPS;
+
+                fn abs_diff_eq(&self,other: &Self,epsilon: T::Epsilon) -> bool {
+                    ($(self.$f.abs_diff_eq(&other.$f,epsilon))&&+)
+                    || ($(self.$f.abs_diff_eq(&-other.$f,epsilon))&&+)
+                }
+
+                fn relative_eq(&self,other: &Self,epsilon: T::Epsilon,max_relative: T::Epsilon) -> bool {
+                    ($(self.$f.relative_eq(&other.$f,epsilon,max_relative))&&+)
+                    || ($(self.$f.relative_eq(&-other.$f,epsilon,max_relative))&&+)
+                }
+
+                fn ulps_eq(&self,other: &Self,epsilon: T::Epsilon,max_ulps: u32) -> bool {
+                    ($(self.$f.ulps_eq(&other.$f,epsilon,max_ulps))&&+)
+                    || ($(self.$f.ulps_eq(&-other.$f,epsilon,max_ulps))&&+)
+                }
+            }
+        )+
+    }
+}
+
+approx_double_cover! {
+    Quaternion { r i j k }
+    Rotor { r xy xz yz }
+    Motor { r xy xz yz xw yw zw xyzw }
+}
+
+impl<T: ApproxEq + Copy + Neg<Output=T>> ApproxEq for Pose<T> {
+    type Epsilon = T::Epsilon;
+    const DEFAULT_EPSILON: T::Epsilon = T::DEFAULT_EPSILON;
+    const DEFAULT_MAX_RELATIVE: T::Epsilon = T::DEFAULT_MAX_RELATIVE;
+    const DEFAULT_MAX_ULPS: u32 = T::DEFAULT_MAX_ULPS;
+
+    fn abs_diff_eq(&self,other: &Self,epsilon: T::Epsilon) -> bool {
+        self.p.abs_diff_eq(&other.p,epsilon) && self.o.abs_diff_eq(&other.o,epsilon)
+    }
+
+    fn relative_eq(&self,other: &Self,epsilon: T::Epsilon,max_relative: T::Epsilon) -> bool {
+        self.p.relative_eq(&other.p,epsilon,max_relative) && self.o.relative_eq(&other.o,epsilon,max_relative)
+    }
+
+    fn ulps_eq(&self,other: &Self,epsilon: T::Epsilon,max_ulps: u32) -> bool {
+        self.p.ulps_eq(&other.p,epsilon,max_ulps) && self.o.ulps_eq(&other.o,epsilon,max_ulps)
+    }
+}
+
+// both parts flip sign together, so compare all eight fields at once instead of through the quaternions
+fn dual_quaternion_parts<T: Copy>(q: &DualQuaternion<T>) -> [T; 8] {
+    [q.r.r,q.r.i,q.r.j,q.r.k,q.d.r,q.d.i,q.d.j,q.d.k]
+}
+
+impl<T: ApproxEq + Copy + Neg<Output=T>> ApproxEq for DualQuaternion<T> {
+    type Epsilon = T::Epsilon;
+    const DEFAULT_EPSILON: T::Epsilon = T::DEFAULT_EPSILON;
+    const DEFAULT_MAX_RELATIVE: T::Epsilon = T::DEFAULT_MAX_RELATIVE;
+    const DEFAULT_MAX_ULPS: u32 = T::DEFAULT_MAX_ULPS;
+
+    fn abs_diff_eq(&self,other: &Self,epsilon: T::Epsilon) -> bool {
+        let a = dual_quaternion_parts(self);
+        let b = dual_quaternion_parts(other);
+        a.iter().zip(b.iter()).all(|(a,b)| a.abs_diff_eq(b,epsilon))
+        || a.iter().zip(b.iter()).all(|(a,b)| a.abs_diff_eq(&-*b,epsilon))
+    }
+
+    fn relative_eq(&self,other: &Self,epsilon: T::Epsilon,max_relative: T::Epsilon) -> bool {
+        let a = dual_quaternion_parts(self);
+        let b = dual_quaternion_parts(other);
+        a.iter().zip(b.iter()).all(|(a,b)| a.relative_eq(b,epsilon,max_relative))
+        || a.iter().zip(b.iter()).all(|(a,b)| a.relative_eq(&-*b,epsilon,max_relative))
+    }
+
+    fn ulps_eq(&self,other: &Self,epsilon: T::Epsilon,max_ulps: u32) -> bool {
+        let a = dual_quaternion_parts(self);
+        let b = dual_quaternion_parts(other);
+        a.iter().zip(b.iter()).all(|(a,b)| a.ulps_eq(b,epsilon,max_ulps))
+        || a.iter().zip(b.iter()).all(|(a,b)| a.ulps_eq(&-*b,epsilon,max_ulps))
+    }
+}
+
+// matrices of different sizes are never equal
+impl<T: ApproxEq> ApproxEq for Mat<T> {
+    type Epsilon = T::Epsilon;
+    const DEFAULT_EPSILON: T::Epsilon = T::DEFAULT_EPSILON;
+    const DEFAULT_MAX_RELATIVE: T::Epsilon = T::DEFAULT_MAX_RELATIVE;
+    const DEFAULT_MAX_ULPS: u32 = T::DEFAULT_MAX_ULPS;
+
+    fn abs_diff_eq(&self,other: &Self,epsilon: T::Epsilon) -> bool {
+        (self.r == other.r) && (self.c == other.c)
+        && self.v.iter().zip(other.v.iter()).all(|(a,b)| a.abs_diff_eq(b,epsilon))
+    }
+
+    fn relative_eq(&self,other: &Self,epsilon: T::Epsilon,max_relative: T::Epsilon) -> bool {
+        (self.r == other.r) && (self.c == other.c)
+        && self.v.iter().zip(other.v.iter()).all(|(a,b)| a.relative_eq(b,epsilon,max_relative))
+    }
+
+    fn ulps_eq(&self,other: &Self,epsilon: T::Epsilon,max_ulps: u32) -> bool {
+        (self.r == other.r) && (self.c == other.c)
+        && self.v.iter().zip(other.v.iter()).all(|(a,b)| a.ulps_eq(b,epsilon,max_ulps))
+    }
+}
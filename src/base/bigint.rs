@@ -0,0 +1,558 @@
+use {
+    crate::*,
+    std::{
+        cmp::{
+            Ordering,
+            PartialEq,
+            PartialOrd,
+        },
+        fmt::{
+            Display,
+            Formatter,
+            LowerHex,
+            Result,
+            UpperHex,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            Rem,
+            Shl,
+            Shr,
+            Neg,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            RemAssign,
+            ShlAssign,
+            ShrAssign,
+        },
+        str::FromStr,
+    },
+};
+
+/// arbitrary precision signed integer
+///
+/// Sign and [`BigUint`] magnitude. Division truncates towards zero like the primitive integers. There are no extreme
+/// values, so [`Unsigned::MIN`] and [`Unsigned::MAX`] are those of `i128`, only useful as sentinels.
+#[derive(Clone,Debug,PartialEq,Eq,Hash)]
+pub struct BigInt {
+    // zero is never negative
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    pub fn new(negative: bool,magnitude: BigUint) -> Self {
+        BigInt {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    pub fn into_magnitude(self) -> BigUint {
+        self.magnitude
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Quotient and remainder, truncated towards zero.
+    pub fn divrem(&self,other: &BigInt) -> (BigInt,BigInt) {
+        let (quotient,rest) = self.magnitude.divrem(&other.magnitude);
+        (BigInt::new(self.negative != other.negative,quotient),BigInt::new(self.negative,rest))
+    }
+
+    pub fn pow(&self,exponent: u32) -> BigInt {
+        BigInt::new(self.negative && ((exponent & 1) != 0),self.magnitude.pow(exponent))
+    }
+
+    /// `self` to the power `exponent`, modulo `modulus`, in `0..modulus`.
+    pub fn modpow(&self,exponent: &BigUint,modulus: &BigUint) -> BigUint {
+        let result = self.magnitude.modpow(exponent,modulus);
+        if self.negative && exponent.bit(0) && !result.is_zero() {
+            modulus - result
+        }
+        else {
+            result
+        }
+    }
+
+    /// Greatest common divisor, never negative.
+    pub fn gcd(&self,other: &BigInt) -> BigUint {
+        self.magnitude.gcd(&other.magnitude)
+    }
+
+    /// Parse optionally signed digits in `radix` 2 to 36.
+    pub fn from_str_radix(s: &str,radix: u32) -> std::result::Result<BigInt,String> {
+        match s.strip_prefix('-') {
+            Some(rest) => Ok(BigInt::new(true,BigUint::from_str_radix(rest,radix)?)),
+            None => Ok(BigInt::new(false,BigUint::from_str_radix(s.strip_prefix('+').unwrap_or(s),radix)?)),
+        }
+    }
+
+    /// Digits in `radix` 2 to 36, lowercase.
+    pub fn to_str_radix(&self,radix: u32) -> String {
+        if self.negative {
+            format!("-{}",self.magnitude.to_str_radix(radix))
+        }
+        else {
+            self.magnitude.to_str_radix(radix)
+        }
+    }
+}
+
+impl Zero for BigInt {
+    const ZERO: BigInt = BigInt { negative: false,magnitude: BigUint::ZERO, };
+}
+
+impl One for BigInt {
+    const ONE: BigInt = BigInt { negative: false,magnitude: BigUint::ONE, };
+}
+
+impl Display for BigInt {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        f.pad_integral(!self.negative,"",&self.magnitude.to_str_radix(10))
+    }
+}
+
+impl LowerHex for BigInt {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        f.pad_integral(!self.negative,"0x",&self.magnitude.to_str_radix(16))
+    }
+}
+
+impl UpperHex for BigInt {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        f.pad_integral(!self.negative,"0x",&self.magnitude.to_str_radix(16).to_uppercase())
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = String;
+
+    /// Parse optionally signed decimal, or hexadecimal with `0x` prefix.
+    fn from_str(s: &str) -> std::result::Result<BigInt,String> {
+        let s = s.trim();
+        match s.strip_prefix('-') {
+            Some(rest) => Ok(BigInt::new(true,rest.parse::<BigUint>()?)),
+            None => Ok(BigInt::new(false,s.parse::<BigUint>()?)),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self,other: &BigInt) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self,other: &BigInt) -> Ordering {
+        match (self.negative,other.negative) {
+            (false,false) => self.magnitude.cmp(&other.magnitude),
+            (true,true) => other.magnitude.cmp(&self.magnitude),
+            (false,true) => Ordering::Greater,
+            (true,false) => Ordering::Less,
+        }
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(value: BigUint) -> Self {
+        BigInt::new(false,value)
+    }
+}
+
+macro_rules! bigint_from_impl {
+    ($($t:ty)+) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> Self {
+                    BigInt::new(false,BigUint::from(value))
+                }
+            }
+        )+
+    }
+}
+
+bigint_from_impl! { usize u8 u16 u32 u64 u128 }
+
+macro_rules! bigint_from_signed_impl {
+    ($($t:ty)+) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> Self {
+                    BigInt::new(value < 0,BigUint::from(value.unsigned_abs()))
+                }
+            }
+        )+
+    }
+}
+
+bigint_from_signed_impl! { isize i8 i16 i32 i64 i128 }
+
+macro_rules! bigint_cast_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Cast<$t> for BigInt {
+                fn checked_cast(self) -> Option<$t> {
+                    let magnitude: u128 = self.magnitude.checked_cast()?;
+                    if self.negative {
+                        if magnitude <= (1u128 << 127) { (magnitude as i128).wrapping_neg().checked_cast() } else { None }
+                    }
+                    else {
+                        magnitude.checked_cast()
+                    }
+                }
+
+                fn saturating_cast(self) -> $t {
+                    let negative = self.negative;
+                    match self.checked_cast() {
+                        Some(value) => value,
+                        None => if negative { <$t>::MIN } else { <$t>::MAX },
+                    }
+                }
+
+                fn lossy_cast(self) -> $t {
+                    let magnitude: u128 = self.magnitude.lossy_cast();
+                    if self.negative { magnitude.wrapping_neg() as $t } else { magnitude as $t }
+                }
+            }
+        )+
+    }
+}
+
+bigint_cast_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+macro_rules! bigint_cast_float_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Cast<$t> for BigInt {
+                fn checked_cast(self) -> Option<$t> {
+                    let value: $t = self.magnitude.checked_cast()?;
+                    Some(if self.negative { -value } else { value })
+                }
+
+                fn saturating_cast(self) -> $t {
+                    let value: $t = self.magnitude.saturating_cast();
+                    if self.negative { -value } else { value }
+                }
+
+                fn lossy_cast(self) -> $t {
+                    let value: $t = self.magnitude.lossy_cast();
+                    if self.negative { -value } else { value }
+                }
+            }
+        )+
+    }
+}
+
+bigint_cast_float_impl! { f32 f64 }
+
+// operators by value and by reference, all through the reference version
+macro_rules! bigint_op_impl {
+    ($($trait:ident $method:ident $assign_trait:ident $assign_method:ident)+) => {
+        $(
+            impl $trait<BigInt> for BigInt {
+                type Output = BigInt;
+                fn $method(self,other: BigInt) -> Self::Output {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl $trait<&BigInt> for BigInt {
+                type Output = BigInt;
+                fn $method(self,other: &BigInt) -> Self::Output {
+                    (&self).$method(other)
+                }
+            }
+
+            impl $trait<BigInt> for &BigInt {
+                type Output = BigInt;
+                fn $method(self,other: BigInt) -> Self::Output {
+                    self.$method(&other)
+                }
+            }
+
+            impl $assign_trait<BigInt> for BigInt {
+                fn $assign_method(&mut self,other: BigInt) {
+                    *self = (&*self).$method(&other);
+                }
+            }
+
+            impl $assign_trait<&BigInt> for BigInt {
+                fn $assign_method(&mut self,other: &BigInt) {
+                    *self = (&*self).$method(other);
+                }
+            }
+        )+
+    }
+}
+
+bigint_op_impl! {
+    Add add AddAssign add_assign
+    Sub sub SubAssign sub_assign
+    Mul mul MulAssign mul_assign
+    Div div DivAssign div_assign
+    Rem rem RemAssign rem_assign
+}
+
+// bigint + bigint
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn add(self,other: &BigInt) -> Self::Output {
+        if self.negative == other.negative {
+            BigInt::new(self.negative,&self.magnitude + &other.magnitude)
+        }
+        else if self.magnitude >= other.magnitude {
+            BigInt::new(self.negative,&self.magnitude - &other.magnitude)
+        }
+        else {
+            BigInt::new(other.negative,&other.magnitude - &self.magnitude)
+        }
+    }
+}
+
+// bigint - bigint
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn sub(self,other: &BigInt) -> Self::Output {
+        self + &-other
+    }
+}
+
+// bigint * bigint
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn mul(self,other: &BigInt) -> Self::Output {
+        BigInt::new(self.negative != other.negative,&self.magnitude * &other.magnitude)
+    }
+}
+
+// bigint / bigint
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn div(self,other: &BigInt) -> Self::Output {
+        self.divrem(other).0
+    }
+}
+
+// bigint % bigint
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn rem(self,other: &BigInt) -> Self::Output {
+        self.divrem(other).1
+    }
+}
+
+// -bigint
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> Self::Output {
+        BigInt::new(!self.negative,self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> Self::Output {
+        BigInt::new(!self.negative,self.magnitude)
+    }
+}
+
+// bigint << bits
+impl Shl<usize> for &BigInt {
+    type Output = BigInt;
+    fn shl(self,bits: usize) -> Self::Output {
+        BigInt::new(self.negative,&self.magnitude << bits)
+    }
+}
+
+impl Shl<usize> for BigInt {
+    type Output = BigInt;
+    fn shl(self,bits: usize) -> Self::Output {
+        &self << bits
+    }
+}
+
+impl ShlAssign<usize> for BigInt {
+    fn shl_assign(&mut self,bits: usize) {
+        *self = &*self << bits;
+    }
+}
+
+// bigint >> bits, rounds towards negative infinity like the primitive integers
+impl Shr<usize> for &BigInt {
+    type Output = BigInt;
+    fn shr(self,bits: usize) -> Self::Output {
+        if self.negative {
+            BigInt::new(true,&(&(&self.magnitude - &BigUint::ONE) >> bits) + &BigUint::ONE)
+        }
+        else {
+            BigInt::new(false,&self.magnitude >> bits)
+        }
+    }
+}
+
+impl Shr<usize> for BigInt {
+    type Output = BigInt;
+    fn shr(self,bits: usize) -> Self::Output {
+        &self >> bits
+    }
+}
+
+impl ShrAssign<usize> for BigInt {
+    fn shr_assign(&mut self,bits: usize) {
+        *self = &*self >> bits;
+    }
+}
+
+// big integers have no bounds, MIN and MAX are the i128 range for the trait only and nothing saturates to them
+impl Unsigned for BigInt {
+    const MIN: BigInt = BigInt { negative: true,magnitude: BigUint::MIN_I128, };
+    const MAX: BigInt = BigInt { negative: false,magnitude: BigUint::MAX_I128, };
+
+    fn div_euclid(self,other: BigInt) -> BigInt {
+        let (quotient,rest) = self.divrem(&other);
+        if rest.negative {
+            if other.negative { quotient + BigInt::ONE } else { quotient - BigInt::ONE }
+        }
+        else {
+            quotient
+        }
+    }
+
+    fn rem_euclid(self,other: BigInt) -> BigInt {
+        let rest = &self % &other;
+        if rest.negative {
+            BigInt::new(false,&other.magnitude - &rest.magnitude)
+        }
+        else {
+            rest
+        }
+    }
+
+    fn min(self,other: BigInt) -> BigInt {
+        if other < self {
+            other
+        }
+        else {
+            self
+        }
+    }
+
+    fn max(self,other: BigInt) -> BigInt {
+        if other > self {
+            other
+        }
+        else {
+            self
+        }
+    }
+
+    fn clamp(self,min: BigInt,max: BigInt) -> BigInt {
+        if max < self {
+            max
+        }
+        else if min > self {
+            min
+        }
+        else {
+            self
+        }
+    }
+
+    fn mul_add(self,b: BigInt,c: BigInt) -> BigInt {
+        self * b + c
+    }
+
+    /// Integer power, negative powers truncate towards zero like integer division.
+    fn powi(self,n: i32) -> BigInt {
+        if n >= 0 {
+            self.pow(n as u32)
+        }
+        else {
+            &BigInt::ONE / &self.pow(n.unsigned_abs())
+        }
+    }
+
+    fn checked_add(self,other: BigInt) -> Option<BigInt> {
+        Some(self + other)
+    }
+
+    fn checked_sub(self,other: BigInt) -> Option<BigInt> {
+        Some(self - other)
+    }
+
+    fn checked_mul(self,other: BigInt) -> Option<BigInt> {
+        Some(self * other)
+    }
+
+    fn checked_div(self,other: BigInt) -> Option<BigInt> {
+        if other.is_zero() { None } else { Some(self / other) }
+    }
+
+    fn saturating_add(self,other: BigInt) -> BigInt {
+        self + other
+    }
+
+    fn saturating_sub(self,other: BigInt) -> BigInt {
+        self - other
+    }
+
+    fn saturating_mul(self,other: BigInt) -> BigInt {
+        self * other
+    }
+
+    fn saturating_div(self,other: BigInt) -> BigInt {
+        self / other
+    }
+
+    fn wrapping_add(self,other: BigInt) -> BigInt {
+        self + other
+    }
+
+    fn wrapping_sub(self,other: BigInt) -> BigInt {
+        self - other
+    }
+
+    fn wrapping_mul(self,other: BigInt) -> BigInt {
+        self * other
+    }
+
+    fn wrapping_div(self,other: BigInt) -> BigInt {
+        self / other
+    }
+}
+
+impl Signed for BigInt {
+    fn abs(self) -> BigInt {
+        BigInt::new(false,self.magnitude)
+    }
+
+    fn signum(self) -> BigInt {
+        if self.is_zero() {
+            BigInt::ZERO
+        }
+        else {
+            BigInt::new(self.negative,BigUint::ONE)
+        }
+    }
+
+    fn is_negative(self) -> bool {
+        self.negative
+    }
+
+    fn copysign(self,sign: BigInt) -> BigInt {
+        BigInt::new(sign.negative,self.magnitude)
+    }
+}
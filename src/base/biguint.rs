@@ -0,0 +1,726 @@
+use {
+    crate::*,
+    std::{
+        borrow::Cow,
+        cmp::{
+            Ordering,
+            PartialEq,
+            PartialOrd,
+        },
+        fmt::{
+            Binary,
+            Display,
+            Formatter,
+            LowerHex,
+            Result,
+            UpperHex,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            Rem,
+            Shl,
+            Shr,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            RemAssign,
+            ShlAssign,
+            ShrAssign,
+        },
+        str::FromStr,
+    },
+};
+
+/// arbitrary precision unsigned integer
+///
+/// Stored as little endian base 2^32 digits. There is no largest value, so [`Unsigned::MAX`] is `u128::MAX`, only
+/// useful as a sentinel.
+#[derive(Clone,Debug,PartialEq,Eq,Hash)]
+pub struct BigUint {
+    // no trailing zero digits, so zero is empty
+    digits: Cow<'static,[u32]>,
+}
+
+// a + b
+fn add_digits(a: &[u32],b: &[u32]) -> Vec<u32> {
+    let (a,b) = if a.len() >= b.len() { (a,b) } else { (b,a) };
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u64;
+    for (i,x) in a.iter().enumerate() {
+        let sum = (*x as u64) + (b.get(i).copied().unwrap_or(0) as u64) + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry != 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+// a - b, for a >= b
+fn sub_digits(a: &[u32],b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i,x) in a.iter().enumerate() {
+        let difference = (*x as i64) - (b.get(i).copied().unwrap_or(0) as i64) - borrow;
+        result.push(difference as u32);
+        borrow = if difference < 0 { 1 } else { 0 };
+    }
+    result
+}
+
+// a * b, schoolbook
+fn mul_digits(a: &[u32],b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i,x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j,y) in b.iter().enumerate() {
+            let t = (*x as u64) * (*y as u64) + (result[i + j] as u64) + carry;
+            result[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    result
+}
+
+fn cmp_digits(a: &[u32],b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn shl_digits(a: &[u32],bits: usize) -> Vec<u32> {
+    if a.is_empty() {
+        return Vec::new();
+    }
+    let shift = bits % 32;
+    let mut result = vec![0u32; bits / 32];
+    if shift == 0 {
+        result.extend_from_slice(a);
+    }
+    else {
+        let mut carry = 0u32;
+        for x in a {
+            result.push((x << shift) | carry);
+            carry = x >> (32 - shift);
+        }
+        if carry != 0 {
+            result.push(carry);
+        }
+    }
+    result
+}
+
+fn shr_digits(a: &[u32],bits: usize) -> Vec<u32> {
+    let words = bits / 32;
+    if words >= a.len() {
+        return Vec::new();
+    }
+    let shift = bits % 32;
+    (words..a.len()).map(|i| {
+        let high = if (shift > 0) && (i + 1 < a.len()) { a[i + 1] << (32 - shift) } else { 0 };
+        (a[i] >> shift) | high
+    }).collect()
+}
+
+// (a / d,a % d) for a single digit d
+fn divrem_digit(a: &[u32],d: u32) -> (Vec<u32>,u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut rest = 0u64;
+    for i in (0..a.len()).rev() {
+        let t = (rest << 32) | (a[i] as u64);
+        quotient[i] = (t / (d as u64)) as u32;
+        rest = t % (d as u64);
+    }
+    (quotient,rest as u32)
+}
+
+// (a / b,a % b), Knuth algorithm D
+fn divrem_digits(a: &[u32],b: &[u32]) -> (Vec<u32>,Vec<u32>) {
+    assert!(!b.is_empty(),"BigUint::div: division by zero");
+    if cmp_digits(a,b) == Ordering::Less {
+        return (Vec::new(),a.to_vec());
+    }
+    if b.len() == 1 {
+        let (quotient,rest) = divrem_digit(a,b[0]);
+        return (quotient,vec![rest]);
+    }
+
+    // normalize so the top digit of the divisor has its high bit set
+    let shift = b[b.len() - 1].leading_zeros() as usize;
+    let v = shl_digits(b,shift);
+    let mut u = shl_digits(a,shift);
+    if u.len() == a.len() {
+        u.push(0);
+    }
+    let n = v.len();
+    let m = u.len() - n;
+    let mut quotient = vec![0u32; m];
+    for j in (0..m).rev() {
+
+        // estimate the quotient digit from the top two digits
+        let top = ((u[j + n] as u64) << 32) | (u[j + n - 1] as u64);
+        let mut qhat = top / (v[n - 1] as u64);
+        let mut rhat = top % (v[n - 1] as u64);
+        while (qhat >> 32 != 0) || (qhat * (v[n - 2] as u64) > ((rhat << 32) | (u[j + n - 2] as u64))) {
+            qhat -= 1;
+            rhat += v[n - 1] as u64;
+            if rhat >> 32 != 0 {
+                break;
+            }
+        }
+
+        // subtract qhat * v
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let p = qhat * (v[i] as u64) + carry;
+            carry = p >> 32;
+            let t = (u[i + j] as i64) - borrow - ((p & 0xFFFFFFFF) as i64);
+            u[i + j] = t as u32;
+            borrow = if t < 0 { 1 } else { 0 };
+        }
+        let t = (u[j + n] as i64) - borrow - (carry as i64);
+        u[j + n] = t as u32;
+
+        // the estimate was one too large, add v back
+        if t < 0 {
+            qhat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let s = (u[i + j] as u64) + (v[i] as u64) + carry;
+                u[i + j] = s as u32;
+                carry = s >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = qhat as u32;
+    }
+    (quotient,shr_digits(&u[0..n],shift))
+}
+
+impl BigUint {
+    fn from_vec(mut digits: Vec<u32>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        BigUint { digits: Cow::Owned(digits), }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Number of significant bits.
+    pub fn bits(&self) -> usize {
+        match self.digits.last() {
+            Some(top) => self.digits.len() * 32 - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Value of bit `index`.
+    pub fn bit(&self,index: usize) -> bool {
+        match self.digits.get(index / 32) {
+            Some(digit) => ((digit >> (index % 32)) & 1) != 0,
+            None => false,
+        }
+    }
+
+    /// Subtract, or `None` if `other` is larger.
+    pub fn checked_sub(&self,other: &BigUint) -> Option<BigUint> {
+        if cmp_digits(&self.digits,&other.digits) == Ordering::Less {
+            None
+        }
+        else {
+            Some(BigUint::from_vec(sub_digits(&self.digits,&other.digits)))
+        }
+    }
+
+    /// Quotient and remainder.
+    pub fn divrem(&self,other: &BigUint) -> (BigUint,BigUint) {
+        let (quotient,rest) = divrem_digits(&self.digits,&other.digits);
+        (BigUint::from_vec(quotient),BigUint::from_vec(rest))
+    }
+
+    pub fn pow(&self,exponent: u32) -> BigUint {
+        let mut base = self.clone();
+        let mut e = exponent;
+        let mut result = BigUint::ONE;
+        while e > 0 {
+            if (e & 1) != 0 {
+                result = &result * &base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// `self` to the power `exponent`, modulo `modulus`.
+    pub fn modpow(&self,exponent: &BigUint,modulus: &BigUint) -> BigUint {
+        assert!(!modulus.is_zero(),"BigUint::modpow: zero modulus");
+        let mut base = self % modulus;
+        let mut result = &BigUint::ONE % modulus;
+        for i in 0..exponent.bits() {
+            if exponent.bit(i) {
+                result = &(&result * &base) % modulus;
+            }
+            base = &(&base * &base) % modulus;
+        }
+        result
+    }
+
+    /// Greatest common divisor.
+    pub fn gcd(&self,other: &BigUint) -> BigUint {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let c = &a % &b;
+            a = b;
+            b = c;
+        }
+        a
+    }
+
+    /// Parse digits in `radix` 2 to 36.
+    pub fn from_str_radix(s: &str,radix: u32) -> std::result::Result<BigUint,String> {
+        assert!((2..=36).contains(&radix),"BigUint::from_str_radix: radix must be 2 to 36");
+        if s.is_empty() {
+            return Err("BigUint::from_str_radix: empty string".to_string());
+        }
+        let mut digits: Vec<u32> = Vec::new();
+        let mut empty = true;
+        for c in s.chars() {
+            if c == '_' {
+                continue;
+            }
+            empty = false;
+            let value = c.to_digit(radix).ok_or_else(|| format!("BigUint::from_str_radix: invalid digit '{}'",c))?;
+
+            // digits = digits * radix + value
+            let mut carry = value as u64;
+            for digit in digits.iter_mut() {
+                let t = (*digit as u64) * (radix as u64) + carry;
+                *digit = t as u32;
+                carry = t >> 32;
+            }
+            if carry != 0 {
+                digits.push(carry as u32);
+            }
+        }
+        if empty {
+            return Err("BigUint::from_str_radix: no digits".to_string());
+        }
+        Ok(BigUint::from_vec(digits))
+    }
+
+    /// Digits in `radix` 2 to 36, lowercase.
+    pub fn to_str_radix(&self,radix: u32) -> String {
+        assert!((2..=36).contains(&radix),"BigUint::to_str_radix: radix must be 2 to 36");
+        if self.is_zero() {
+            return "0".to_string();
+        }
+        let mut result = Vec::new();
+        let mut digits = self.digits.to_vec();
+        while !digits.is_empty() {
+            let (quotient,rest) = divrem_digit(&digits,radix);
+            result.push(std::char::from_digit(rest,radix).unwrap());
+            digits = quotient;
+            while digits.last() == Some(&0) {
+                digits.pop();
+            }
+        }
+        result.iter().rev().collect()
+    }
+
+    // low 128 bits
+    fn low_u128(&self) -> u128 {
+        self.digits.iter().take(4).enumerate().fold(0u128,|value,(i,digit)| value | ((*digit as u128) << (32 * i)))
+    }
+
+    // top 64 bits with a sticky lowest bit for correct float rounding, and the shift to apply
+    fn float_parts(&self) -> (u64,i32) {
+        let bits = self.bits();
+        if bits <= 64 {
+            (self.low_u128() as u64,0)
+        }
+        else {
+            let shift = bits - 64;
+            let top = BigUint::from_vec(shr_digits(&self.digits,shift)).low_u128() as u64;
+            let sticky = shl_digits(&shr_digits(&self.digits,shift),shift) != self.digits.to_vec();
+            (top | (sticky as u64),shift as i32)
+        }
+    }
+}
+
+impl BigUint {
+    // magnitudes of the BigInt sentinels
+    pub(crate) const MIN_I128: BigUint = BigUint { digits: Cow::Borrowed(&[0,0,0,0x80000000]), };
+    pub(crate) const MAX_I128: BigUint = BigUint { digits: Cow::Borrowed(&[u32::MAX,u32::MAX,u32::MAX,0x7FFFFFFF]), };
+}
+
+impl Zero for BigUint {
+    const ZERO: BigUint = BigUint { digits: Cow::Borrowed(&[]), };
+}
+
+impl One for BigUint {
+    const ONE: BigUint = BigUint { digits: Cow::Borrowed(&[1]), };
+}
+
+impl Display for BigUint {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        f.pad_integral(true,"",&self.to_str_radix(10))
+    }
+}
+
+impl LowerHex for BigUint {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        f.pad_integral(true,"0x",&self.to_str_radix(16))
+    }
+}
+
+impl UpperHex for BigUint {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        f.pad_integral(true,"0x",&self.to_str_radix(16).to_uppercase())
+    }
+}
+
+impl Binary for BigUint {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        f.pad_integral(true,"0b",&self.to_str_radix(2))
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = String;
+
+    /// Parse decimal, or hexadecimal with `0x` prefix.
+    fn from_str(s: &str) -> std::result::Result<BigUint,String> {
+        let s = s.trim();
+        let s = s.strip_prefix('+').unwrap_or(s);
+        match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => BigUint::from_str_radix(hex,16),
+            None => BigUint::from_str_radix(s,10),
+        }
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self,other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self,other: &BigUint) -> Ordering {
+        cmp_digits(&self.digits,&other.digits)
+    }
+}
+
+macro_rules! biguint_from_impl {
+    ($($t:ty)+) => {
+        $(
+            impl From<$t> for BigUint {
+                fn from(value: $t) -> Self {
+                    let value = value as u128;
+                    BigUint::from_vec(vec![value as u32,(value >> 32) as u32,(value >> 64) as u32,(value >> 96) as u32])
+                }
+            }
+        )+
+    }
+}
+
+biguint_from_impl! { usize u8 u16 u32 u64 u128 }
+
+macro_rules! biguint_cast_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Cast<$t> for BigUint {
+                fn checked_cast(self) -> Option<$t> {
+                    if self.bits() <= 128 { self.low_u128().checked_cast() } else { None }
+                }
+
+                fn saturating_cast(self) -> $t {
+                    if self.bits() <= 128 { self.low_u128().saturating_cast() } else { <$t>::MAX }
+                }
+
+                fn lossy_cast(self) -> $t {
+                    self.low_u128() as $t
+                }
+            }
+        )+
+    }
+}
+
+biguint_cast_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+macro_rules! biguint_cast_float_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Cast<$t> for BigUint {
+                /// Nearest float, or `None` if it overflows to infinity.
+                fn checked_cast(self) -> Option<$t> {
+                    let value: $t = self.lossy_cast();
+                    if value.is_finite() { Some(value) } else { None }
+                }
+
+                fn saturating_cast(self) -> $t {
+                    let value: $t = self.lossy_cast();
+                    if value.is_finite() { value } else { <$t>::MAX }
+                }
+
+                fn lossy_cast(self) -> $t {
+                    let (top,shift) = self.float_parts();
+                    (top as $t) * (2.0 as $t).powi(shift)
+                }
+            }
+        )+
+    }
+}
+
+biguint_cast_float_impl! { f32 f64 }
+
+// operators by value and by reference, all through the reference version
+macro_rules! biguint_op_impl {
+    ($($trait:ident $method:ident $assign_trait:ident $assign_method:ident)+) => {
+        $(
+            impl $trait<BigUint> for BigUint {
+                type Output = BigUint;
+                fn $method(self,other: BigUint) -> Self::Output {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl $trait<&BigUint> for BigUint {
+                type Output = BigUint;
+                fn $method(self,other: &BigUint) -> Self::Output {
+                    (&self).$method(other)
+                }
+            }
+
+            impl $trait<BigUint> for &BigUint {
+                type Output = BigUint;
+                fn $method(self,other: BigUint) -> Self::Output {
+                    self.$method(&other)
+                }
+            }
+
+            impl $assign_trait<BigUint> for BigUint {
+                fn $assign_method(&mut self,other: BigUint) {
+                    *self = (&*self).$method(&other);
+                }
+            }
+
+            impl $assign_trait<&BigUint> for BigUint {
+                fn $assign_method(&mut self,other: &BigUint) {
+                    *self = (&*self).$method(other);
+                }
+            }
+        )+
+    }
+}
+
+biguint_op_impl! {
+    Add add AddAssign add_assign
+    Sub sub SubAssign sub_assign
+    Mul mul MulAssign mul_assign
+    Div div DivAssign div_assign
+    Rem rem RemAssign rem_assign
+}
+
+// biguint + biguint
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn add(self,other: &BigUint) -> Self::Output {
+        BigUint::from_vec(add_digits(&self.digits,&other.digits))
+    }
+}
+
+// biguint - biguint
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn sub(self,other: &BigUint) -> Self::Output {
+        self.checked_sub(other).expect("BigUint::sub: underflow")
+    }
+}
+
+// biguint * biguint
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn mul(self,other: &BigUint) -> Self::Output {
+        BigUint::from_vec(mul_digits(&self.digits,&other.digits))
+    }
+}
+
+// biguint / biguint
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn div(self,other: &BigUint) -> Self::Output {
+        self.divrem(other).0
+    }
+}
+
+// biguint % biguint
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn rem(self,other: &BigUint) -> Self::Output {
+        self.divrem(other).1
+    }
+}
+
+// biguint << bits
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+    fn shl(self,bits: usize) -> Self::Output {
+        BigUint::from_vec(shl_digits(&self.digits,bits))
+    }
+}
+
+impl Shl<usize> for BigUint {
+    type Output = BigUint;
+    fn shl(self,bits: usize) -> Self::Output {
+        &self << bits
+    }
+}
+
+impl ShlAssign<usize> for BigUint {
+    fn shl_assign(&mut self,bits: usize) {
+        *self = &*self << bits;
+    }
+}
+
+// biguint >> bits
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+    fn shr(self,bits: usize) -> Self::Output {
+        BigUint::from_vec(shr_digits(&self.digits,bits))
+    }
+}
+
+impl Shr<usize> for BigUint {
+    type Output = BigUint;
+    fn shr(self,bits: usize) -> Self::Output {
+        &self >> bits
+    }
+}
+
+impl ShrAssign<usize> for BigUint {
+    fn shr_assign(&mut self,bits: usize) {
+        *self = &*self >> bits;
+    }
+}
+
+// big integers have no upper bound, MAX is the u128 maximum for the trait only and nothing saturates to it
+impl Unsigned for BigUint {
+    const MIN: BigUint = BigUint::ZERO;
+    const MAX: BigUint = BigUint { digits: Cow::Borrowed(&[u32::MAX,u32::MAX,u32::MAX,u32::MAX]), };
+
+    fn div_euclid(self,other: BigUint) -> BigUint {
+        self / other
+    }
+
+    fn rem_euclid(self,other: BigUint) -> BigUint {
+        self % other
+    }
+
+    fn min(self,other: BigUint) -> BigUint {
+        if other < self {
+            other
+        }
+        else {
+            self
+        }
+    }
+
+    fn max(self,other: BigUint) -> BigUint {
+        if other > self {
+            other
+        }
+        else {
+            self
+        }
+    }
+
+    fn clamp(self,min: BigUint,max: BigUint) -> BigUint {
+        if max < self {
+            max
+        }
+        else if min > self {
+            min
+        }
+        else {
+            self
+        }
+    }
+
+    fn mul_add(self,b: BigUint,c: BigUint) -> BigUint {
+        self * b + c
+    }
+
+    /// Integer power, negative powers truncate to zero like integer division.
+    fn powi(self,n: i32) -> BigUint {
+        if n >= 0 {
+            self.pow(n as u32)
+        }
+        else {
+            &BigUint::ONE / &self.pow(n.unsigned_abs())
+        }
+    }
+
+    fn checked_add(self,other: BigUint) -> Option<BigUint> {
+        Some(self + other)
+    }
+
+    fn checked_sub(self,other: BigUint) -> Option<BigUint> {
+        BigUint::checked_sub(&self,&other)
+    }
+
+    fn checked_mul(self,other: BigUint) -> Option<BigUint> {
+        Some(self * other)
+    }
+
+    fn checked_div(self,other: BigUint) -> Option<BigUint> {
+        if other.is_zero() { None } else { Some(self / other) }
+    }
+
+    fn saturating_add(self,other: BigUint) -> BigUint {
+        self + other
+    }
+
+    fn saturating_sub(self,other: BigUint) -> BigUint {
+        BigUint::checked_sub(&self,&other).unwrap_or(BigUint::ZERO)
+    }
+
+    fn saturating_mul(self,other: BigUint) -> BigUint {
+        self * other
+    }
+
+    fn saturating_div(self,other: BigUint) -> BigUint {
+        self / other
+    }
+
+    fn wrapping_add(self,other: BigUint) -> BigUint {
+        self + other
+    }
+
+    fn wrapping_sub(self,other: BigUint) -> BigUint {
+        self - other
+    }
+
+    fn wrapping_mul(self,other: BigUint) -> BigUint {
+        self * other
+    }
+
+    fn wrapping_div(self,other: BigUint) -> BigUint {
+        self / other
+    }
+}
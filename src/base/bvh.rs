@@ -0,0 +1,359 @@
+use {
+    crate::*,
+    std::cmp::Ordering,
+};
+
+/// Number of bins per axis when searching for the best split.
+const BVH_BINS: usize = 16;
+
+/// Leaves with more primitives than this are always split.
+const BVH_MAX_LEAF: usize = 8;
+
+/// Cost of visiting a node, relative to testing one primitive.
+const BVH_TRAVERSAL_COST: f64 = 1.0;
+
+/// Node of a [`Bvh`].
+///
+/// The children of an interior node are next to each other, at `first` and `first + 1`, always after their parent.
+#[derive(Copy,Clone,Debug)]
+pub struct BvhNode<T> {
+    pub aabb: Aabb<T>,
+    /// first child for interior nodes, first entry in [`Bvh::indices`] for leaves
+    pub first: u32,
+    /// number of primitives, 0 for interior nodes
+    pub count: u32,
+}
+
+/// [`BvhNode`] for uploading to the GPU, as `vec3 min; uint first; vec3 max; uint count;` in std430.
+#[repr(C)]
+#[derive(Copy,Clone,Debug)]
+pub struct BvhGpuNode {
+    pub min: [f32; 3],
+    pub first: u32,
+    pub max: [f32; 3],
+    pub count: u32,
+}
+
+/// Bounding volume hierarchy over primitives with axis-aligned bounds.
+///
+/// The hierarchy only knows the bounds of the primitives, which are referred to by their index in the slice passed to
+/// [`Bvh::build`]. Queries call back into the caller to test the actual primitives.
+#[derive(Clone,Debug)]
+pub struct Bvh<T> {
+    /// nodes, with the root at 0
+    pub nodes: Vec<BvhNode<T>>,
+    /// primitive indices, each leaf covers a contiguous range
+    pub indices: Vec<u32>,
+    /// bounds of each primitive
+    pub bounds: Vec<Aabb<T>>,
+}
+
+macro_rules! bvh_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Bvh<$t> {
+                /// Build with the surface area heuristic, binning primitive centers along each axis.
+                pub fn build(bounds: &[Aabb<$t>]) -> Self {
+                    let mut bvh = Bvh {
+                        nodes: Vec::new(),
+                        indices: (0..bounds.len() as u32).collect(),
+                        bounds: bounds.to_vec(),
+                    };
+                    if !bounds.is_empty() {
+                        let centers: Vec<Vec3<$t>> = bounds.iter().map(|b| b.center()).collect();
+                        bvh.nodes.push(BvhNode { aabb: Aabb::<$t>::EMPTY,first: 0,count: bounds.len() as u32, });
+                        bvh.subdivide(0,&centers);
+                    }
+                    bvh
+                }
+
+                fn axis(v: Vec3<$t>,axis: usize) -> $t {
+                    match axis {
+                        0 => v.x,
+                        1 => v.y,
+                        _ => v.z,
+                    }
+                }
+
+                // bin of center `c` along `axis`, for centers from `min` to `min + BVH_BINS / scale`
+                fn bin(c: Vec3<$t>,axis: usize,min: $t,scale: $t) -> usize {
+                    Unsigned::min(((Bvh::<$t>::axis(c,axis) - min) * scale) as usize,BVH_BINS - 1)
+                }
+
+                fn subdivide(&mut self,index: usize,centers: &[Vec3<$t>]) {
+                    let first = self.nodes[index].first as usize;
+                    let count = self.nodes[index].count as usize;
+                    let range = first..first + count;
+                    let aabb = self.indices[range.clone()].iter().fold(Aabb::<$t>::EMPTY,|aabb,i| aabb.union(&self.bounds[*i as usize]));
+                    self.nodes[index].aabb = aabb;
+                    if count <= 1 {
+                        return;
+                    }
+
+                    // find the cheapest split between bins
+                    let cb = self.indices[range.clone()].iter().fold(Aabb::<$t>::EMPTY,|cb,i| cb.grow(centers[*i as usize]));
+                    let mut best: Option<(usize,usize,$t)> = None;
+                    for axis in 0..3 {
+                        let min = Bvh::<$t>::axis(cb.min,axis);
+                        let max = Bvh::<$t>::axis(cb.max,axis);
+                        if max <= min {
+                            continue;
+                        }
+                        let scale = (BVH_BINS as $t) / (max - min);
+                        let mut bins = [(Aabb::<$t>::EMPTY,0usize); BVH_BINS];
+                        for i in &self.indices[range.clone()] {
+                            let bin = &mut bins[Bvh::<$t>::bin(centers[*i as usize],axis,min,scale)];
+                            bin.0 = bin.0.union(&self.bounds[*i as usize]);
+                            bin.1 += 1;
+                        }
+                        // sweep from the right to get the cost of everything right of each split, then from the left
+                        let mut right_cost = [<$t>::ZERO; BVH_BINS];
+                        let mut right = (Aabb::<$t>::EMPTY,0usize);
+                        for split in (1..BVH_BINS).rev() {
+                            right = (right.0.union(&bins[split].0),right.1 + bins[split].1);
+                            right_cost[split] = if right.1 > 0 { (right.1 as $t) * right.0.surface_area() } else { <$t>::ZERO };
+                        }
+                        let mut left = (Aabb::<$t>::EMPTY,0usize);
+                        for split in 1..BVH_BINS {
+                            left = (left.0.union(&bins[split - 1].0),left.1 + bins[split - 1].1);
+                            if (left.1 == 0) || (left.1 == count) {
+                                continue;
+                            }
+                            let cost = (left.1 as $t) * left.0.surface_area() + right_cost[split];
+                            if best.map_or(true,|(_,_,best)| cost < best) {
+                                best = Some((axis,split,cost));
+                            }
+                        }
+                    }
+
+                    let i = match best {
+                        Some((axis,split,cost)) => {
+                            // compare with keeping all primitives in one leaf
+                            let area = aabb.surface_area();
+                            let split_cost = (BVH_TRAVERSAL_COST as $t) * area + cost;
+                            if (split_cost >= (count as $t) * area) && (count <= BVH_MAX_LEAF) {
+                                return;
+                            }
+
+                            // partition the indices
+                            let min = Bvh::<$t>::axis(cb.min,axis);
+                            let scale = (BVH_BINS as $t) / (Bvh::<$t>::axis(cb.max,axis) - min);
+                            let mut i = first;
+                            let mut j = first + count;
+                            while i < j {
+                                if Bvh::<$t>::bin(centers[self.indices[i] as usize],axis,min,scale) < split {
+                                    i += 1;
+                                }
+                                else {
+                                    j -= 1;
+                                    self.indices.swap(i,j);
+                                }
+                            }
+                            i
+                        },
+                        None => {
+                            // all centers are the same, so binning cannot split, but too many primitives for one leaf
+                            if count <= BVH_MAX_LEAF {
+                                return;
+                            }
+                            let extent = cb.max - cb.min;
+                            let axis = if (extent.x >= extent.y) && (extent.x >= extent.z) { 0 } else if extent.y >= extent.z { 1 } else { 2 };
+                            self.indices[range].select_nth_unstable_by(count / 2,|a,b| {
+                                let a = Bvh::<$t>::axis(centers[*a as usize],axis);
+                                let b = Bvh::<$t>::axis(centers[*b as usize],axis);
+                                a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+                            });
+                            first + count / 2
+                        },
+                    };
+
+                    let child = self.nodes.len();
+                    self.nodes.push(BvhNode { aabb: Aabb::<$t>::EMPTY,first: first as u32,count: (i - first) as u32, });
+                    self.nodes.push(BvhNode { aabb: Aabb::<$t>::EMPTY,first: i as u32,count: (first + count - i) as u32, });
+                    self.nodes[index].first = child as u32;
+                    self.nodes[index].count = 0;
+                    self.subdivide(child,centers);
+                    self.subdivide(child + 1,centers);
+                }
+
+                /// Update the node bounds for primitives that moved, keeping the tree structure.
+                ///
+                /// Quality degrades as primitives move further from where they were at [`Bvh::build`].
+                pub fn refit(&mut self,bounds: &[Aabb<$t>]) {
+                    assert_eq!(bounds.len(),self.bounds.len(),"Bvh::refit: number of primitives changed");
+                    self.bounds.copy_from_slice(bounds);
+                    // children are always after their parents
+                    for index in (0..self.nodes.len()).rev() {
+                        let node = self.nodes[index];
+                        let first = node.first as usize;
+                        self.nodes[index].aabb = if node.count > 0 {
+                            self.indices[first..first + node.count as usize].iter().fold(Aabb::<$t>::EMPTY,|aabb,i| aabb.union(&self.bounds[*i as usize]))
+                        }
+                        else {
+                            self.nodes[first].aabb.union(&self.nodes[first + 1].aabb)
+                        };
+                    }
+                }
+
+                /// Closest primitive hit by `ray` up to `tmax`, and its `t`.
+                ///
+                /// `hit` is called with a primitive index and the current closest `t`, and returns the `t` where the ray
+                /// hits the primitive, if it does.
+                pub fn closest_hit<F: FnMut(u32,$t) -> Option<$t>>(&self,ray: &Ray<$t>,tmax: $t,mut hit: F) -> Option<(u32,$t)> {
+                    let mut result = None;
+                    self.traverse_ray(ray,tmax,|index,tmax| {
+                        match hit(index,tmax) {
+                            Some(t) if (t >= <$t>::ZERO) && (t <= tmax) => {
+                                result = Some((index,t));
+                                (t,false)
+                            },
+                            _ => (tmax,false),
+                        }
+                    });
+                    result
+                }
+
+                /// Any primitive hit by `ray` up to `tmax`, for shadow and visibility rays.
+                ///
+                /// `hit` is called with a primitive index and `tmax`, and returns whether the ray hits the primitive.
+                pub fn any_hit<F: FnMut(u32,$t) -> bool>(&self,ray: &Ray<$t>,tmax: $t,mut hit: F) -> Option<u32> {
+                    let mut result = None;
+                    self.traverse_ray(ray,tmax,|index,tmax| {
+                        if hit(index,tmax) {
+                            result = Some(index);
+                            (tmax,true)
+                        }
+                        else {
+                            (tmax,false)
+                        }
+                    });
+                    result
+                }
+
+                // visit leaf primitives along the ray front to back, `visit` returns the new tmax and whether to stop
+                fn traverse_ray<F: FnMut(u32,$t) -> ($t,bool)>(&self,ray: &Ray<$t>,tmax: $t,mut visit: F) {
+                    let mut tmax = tmax;
+                    let enter = |node: &BvhNode<$t>| ray.intersect_aabb(&node.aabb).map(|(near,_)| near);
+                    let mut stack = match self.nodes.first().and_then(enter) {
+                        Some(near) => vec![(0usize,near)],
+                        None => return,
+                    };
+                    while let Some((index,near)) = stack.pop() {
+                        if near > tmax {
+                            continue;
+                        }
+                        let node = &self.nodes[index];
+                        let first = node.first as usize;
+                        if node.count > 0 {
+                            for i in &self.indices[first..first + node.count as usize] {
+                                let (t,stop) = visit(*i,tmax);
+                                if stop {
+                                    return;
+                                }
+                                tmax = t;
+                            }
+                        }
+                        else {
+                            // push the far child first, so the near child is visited first
+                            let a = enter(&self.nodes[first]).map(|near| (first,near));
+                            let b = enter(&self.nodes[first + 1]).map(|near| (first + 1,near));
+                            match (a,b) {
+                                (Some(a),Some(b)) => if a.1 <= b.1 { stack.push(b); stack.push(a); } else { stack.push(a); stack.push(b); },
+                                (Some(a),None) => stack.push(a),
+                                (None,Some(b)) => stack.push(b),
+                                (None,None) => { },
+                            }
+                        }
+                    }
+                }
+
+                /// Indices of the primitives with bounds overlapping `aabb`.
+                pub fn overlapping(&self,aabb: &Aabb<$t>) -> Vec<u32> {
+                    let mut result = Vec::new();
+                    let mut stack = if self.nodes.is_empty() { Vec::new() } else { vec![0usize] };
+                    while let Some(index) = stack.pop() {
+                        let node = &self.nodes[index];
+                        if !node.aabb.intersects_aabb(aabb) {
+                            continue;
+                        }
+                        let first = node.first as usize;
+                        if node.count > 0 {
+                            result.extend(self.indices[first..first + node.count as usize].iter().filter(|i| self.bounds[**i as usize].intersects_aabb(aabb)));
+                        }
+                        else {
+                            stack.push(first);
+                            stack.push(first + 1);
+                        }
+                    }
+                    result
+                }
+
+                /// Primitive nearest to `p`, and its squared distance.
+                ///
+                /// `distance_sqr` is called with a primitive index and returns the squared distance from `p` to the
+                /// primitive, which must not be less than the squared distance to its bounds.
+                pub fn nearest<F: FnMut(u32) -> $t>(&self,p: Vec3<$t>,mut distance_sqr: F) -> Option<(u32,$t)> {
+                    let mut best = <$t>::INFINITY;
+                    let mut result = None;
+                    let mut stack = match self.nodes.first() {
+                        Some(node) => vec![(0usize,node.aabb.distance_sqr(p))],
+                        None => return None,
+                    };
+                    while let Some((index,d)) = stack.pop() {
+                        if d >= best {
+                            continue;
+                        }
+                        let node = &self.nodes[index];
+                        let first = node.first as usize;
+                        if node.count > 0 {
+                            for i in &self.indices[first..first + node.count as usize] {
+                                if self.bounds[*i as usize].distance_sqr(p) < best {
+                                    let d = distance_sqr(*i);
+                                    if d < best {
+                                        best = d;
+                                        result = Some((*i,d));
+                                    }
+                                }
+                            }
+                        }
+                        else {
+                            let a = (first,self.nodes[first].aabb.distance_sqr(p));
+                            let b = (first + 1,self.nodes[first + 1].aabb.distance_sqr(p));
+                            if a.1 <= b.1 {
+                                stack.push(b);
+                                stack.push(a);
+                            }
+                            else {
+                                stack.push(a);
+                                stack.push(b);
+                            }
+                        }
+                    }
+                    result
+                }
+
+                /// Nodes in the layout of [`BvhGpuNode`], to go with [`Bvh::indices`].
+                ///
+                /// Bounds are rounded outwards to `f32`, so they still contain the primitives.
+                pub fn to_gpu(&self) -> Vec<BvhGpuNode> {
+                    let down = |x: $t| {
+                        let y = x as f32;
+                        if (y as $t) > x { y.next_down() } else { y }
+                    };
+                    let up = |x: $t| {
+                        let y = x as f32;
+                        if (y as $t) < x { y.next_up() } else { y }
+                    };
+                    self.nodes.iter().map(|node| BvhGpuNode {
+                        min: [down(node.aabb.min.x),down(node.aabb.min.y),down(node.aabb.min.z)],
+                        first: node.first,
+                        max: [up(node.aabb.max.x),up(node.aabb.max.y),up(node.aabb.max.z)],
+                        count: node.count,
+                    }).collect()
+                }
+            }
+        )+
+    }
+}
+
+bvh_impl! { f32 f64 }
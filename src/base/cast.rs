@@ -0,0 +1,222 @@
+use {
+    crate::*,
+    std::convert::TryFrom,
+};
+
+/// Numeric conversion to element type or container `T`.
+///
+/// Containers convert element by element, so `Vec3<f32>` casts to `Vec3<f64>` and `Rect<i32>` to `Rect<f32>`.
+pub trait Cast<T>: Sized {
+    /// Convert, or `None` if the value is out of range for `T` (or NaN, when `T` is an integer).
+    fn checked_cast(self) -> Option<T>;
+
+    /// Convert, clamping out of range values to the nearest value of `T`.
+    fn saturating_cast(self) -> T;
+
+    /// Convert like `as`, possibly wrapping or losing precision.
+    fn lossy_cast(self) -> T;
+}
+
+macro_rules! cast_int_int {
+    ($f:ty => $($t:ty)+) => {
+        $(
+            impl Cast<$t> for $f {
+                fn checked_cast(self) -> Option<$t> {
+                    <$t>::try_from(self).ok()
+                }
+
+                fn saturating_cast(self) -> $t {
+                    match <$t>::try_from(self) {
+                        Ok(value) => value,
+                        Err(_) => if self > <$f>::ZERO { <$t>::MAX } else { <$t>::MIN },
+                    }
+                }
+
+                fn lossy_cast(self) -> $t {
+                    self as $t
+                }
+            }
+        )+
+    }
+}
+
+macro_rules! cast_int_float {
+    ($f:ty => $($t:ty)+) => {
+        $(
+            impl Cast<$t> for $f {
+                /// Nearest float, or `None` if it overflows to infinity.
+                fn checked_cast(self) -> Option<$t> {
+                    let value = self as $t;
+                    if value.is_finite() { Some(value) } else { None }
+                }
+
+                fn saturating_cast(self) -> $t {
+                    let value = self as $t;
+                    if value.is_finite() { value } else { <$t>::MAX }
+                }
+
+                fn lossy_cast(self) -> $t {
+                    self as $t
+                }
+            }
+        )+
+    }
+}
+
+macro_rules! cast_float_int {
+    ($f:ty => $($t:ty)+) => {
+        $(
+            impl Cast<$t> for $f {
+                fn checked_cast(self) -> Option<$t> {
+                    // MIN and MAX + 1 are powers of two, so exact as floats
+                    let t = self.trunc();
+                    if (t >= <$t>::MIN as $f) && (t < 2.0 * ((<$t>::MAX / 2 + 1) as $f)) {
+                        Some(self as $t)
+                    }
+                    else {
+                        None
+                    }
+                }
+
+                fn saturating_cast(self) -> $t {
+                    self as $t
+                }
+
+                fn lossy_cast(self) -> $t {
+                    self as $t
+                }
+            }
+        )+
+    }
+}
+
+macro_rules! cast_float_float {
+    ($f:ty => $($t:ty)+) => {
+        $(
+            impl Cast<$t> for $f {
+                fn checked_cast(self) -> Option<$t> {
+                    let value = self as $t;
+                    if value.is_infinite() && self.is_finite() {
+                        None
+                    }
+                    else {
+                        Some(value)
+                    }
+                }
+
+                fn saturating_cast(self) -> $t {
+                    let value = self as $t;
+                    if value.is_infinite() && self.is_finite() {
+                        if self > <$f>::ZERO { <$t>::MAX } else { <$t>::MIN }
+                    }
+                    else {
+                        value
+                    }
+                }
+
+                fn lossy_cast(self) -> $t {
+                    self as $t
+                }
+            }
+        )+
+    }
+}
+
+cast_int_int! { usize => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+cast_int_int! { u8 => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+cast_int_int! { u16 => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+cast_int_int! { u32 => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+cast_int_int! { u64 => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+cast_int_int! { u128 => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+cast_int_int! { isize => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+cast_int_int! { i8 => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+cast_int_int! { i16 => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+cast_int_int! { i32 => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+cast_int_int! { i64 => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+cast_int_int! { i128 => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+cast_int_float! { usize => f32 f64 }
+cast_int_float! { u8 => f32 f64 }
+cast_int_float! { u16 => f32 f64 }
+cast_int_float! { u32 => f32 f64 }
+cast_int_float! { u64 => f32 f64 }
+cast_int_float! { u128 => f32 f64 }
+cast_int_float! { isize => f32 f64 }
+cast_int_float! { i8 => f32 f64 }
+cast_int_float! { i16 => f32 f64 }
+cast_int_float! { i32 => f32 f64 }
+cast_int_float! { i64 => f32 f64 }
+cast_int_float! { i128 => f32 f64 }
+
+cast_float_int! { f32 => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+cast_float_int! { f64 => usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+cast_float_float! { f32 => f32 f64 }
+cast_float_float! { f64 => f32 f64 }
+
+// containers cast field by field
+macro_rules! cast_struct {
+    ($($s:ident { $($f:ident)+ })+) => {
+        $(
+            impl<A: Cast<B>,B> Cast<$s<B>> for $s<A> {
+                fn checked_cast(self) -> Option<$s<B>> {
+                    Some($s { $($f: self.$f.checked_cast()?,)+ })
+                }
+
+                fn saturating_cast(self) -> $s<B> {
+                    $s { $($f: self.$f.saturating_cast(),)+ }
+                }
+
+                fn lossy_cast(self) -> $s<B> {
+                    $s { $($f: self.$f.lossy_cast(),)+ }
+                }
+            }
+        )+
+    }
+}
+
+cast_struct! {
+    Complex { r i }
+    Quaternion { r i j k }
+    Vec2 { x y }
+    Vec3 { x y z }
+    Vec4 { x y z w }
+    Mat2x2 { x y }
+    Mat2x3 { x y }
+    Mat2x4 { x y }
+    Mat3x2 { x y z }
+    Mat3x3 { x y z }
+    Mat3x4 { x y z }
+    Mat4x2 { x y z w }
+    Mat4x3 { x y z w }
+    Mat4x4 { x y z w }
+    Rect { o s }
+    Rect3 { o s }
+    Pose { p o }
+}
+
+impl<A: Cast<B>,B> Cast<Mat<B>> for Mat<A> {
+    fn checked_cast(self) -> Option<Mat<B>> {
+        Some(Mat {
+            r: self.r,
+            c: self.c,
+            v: self.v.into_iter().map(|a| a.checked_cast()).collect::<Option<Vec<B>>>()?,
+        })
+    }
+
+    fn saturating_cast(self) -> Mat<B> {
+        Mat {
+            r: self.r,
+            c: self.c,
+            v: self.v.into_iter().map(|a| a.saturating_cast()).collect(),
+        }
+    }
+
+    fn lossy_cast(self) -> Mat<B> {
+        Mat {
+            r: self.r,
+            c: self.c,
+            v: self.v.into_iter().map(|a| a.lossy_cast()).collect(),
+        }
+    }
+}
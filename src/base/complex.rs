@@ -22,6 +22,10 @@ use {
     },
 };
 
+/// Complex number.
+///
+/// The transcendental functions are inherent methods rather than an implementation of [`Real`], because complex
+/// numbers have no ordering, so `Unsigned` and `Signed` (min, max, clamp, abs as a real, ...) cannot be implemented.
 #[derive(Copy,Clone,Debug)]
 pub struct Complex<T> {
     pub r: T,
@@ -39,8 +43,247 @@ macro_rules! complex_impl {
                     }
                 }
 
+                /// Angle in `(-π,π]`, with the branch cut along the negative real axis.
                 pub fn arg(&self) -> $t {
-                    self.r.atan2(self.i)
+                    self.i.atan2(self.r)
+                }
+
+                pub fn from_polar(norm: $t,arg: $t) -> Self {
+                    let (s,c) = arg.sin_cos();
+                    Complex {
+                        r: norm * c,
+                        i: norm * s,
+                    }
+                }
+
+                /// Norm and argument.
+                pub fn to_polar(self) -> ($t,$t) {
+                    (self.norm(),self.arg())
+                }
+
+                pub fn norm(self) -> $t {
+                    self.r.hypot(self.i)
+                }
+
+                pub fn norm_sqr(self) -> $t {
+                    self.r * self.r + self.i * self.i
+                }
+
+                pub fn inv(self) -> Self {
+                    // Smith's algorithm, divides by the larger part first so norm_sqr cannot underflow or overflow
+                    if self.r.abs() >= self.i.abs() {
+                        let t = self.i / self.r;
+                        let f = self.r + self.i * t;
+                        Complex {
+                            r: <$t>::ONE / f,
+                            i: -t / f,
+                        }
+                    }
+                    else {
+                        let t = self.r / self.i;
+                        let f = self.r * t + self.i;
+                        Complex {
+                            r: t / f,
+                            i: -<$t>::ONE / f,
+                        }
+                    }
+                }
+
+                pub fn is_nan(self) -> bool {
+                    self.r.is_nan() || self.i.is_nan()
+                }
+
+                pub fn is_finite(self) -> bool {
+                    self.r.is_finite() && self.i.is_finite()
+                }
+
+                pub fn exp(self) -> Self {
+                    Complex::<$t>::from_polar(self.r.exp(),self.i)
+                }
+
+                /// Principal logarithm, branch cut along the negative real axis.
+                pub fn ln(self) -> Self {
+                    Complex {
+                        r: self.norm().ln(),
+                        i: self.arg(),
+                    }
+                }
+
+                /// Principal logarithm with real `base`.
+                pub fn log(self,base: $t) -> Self {
+                    self.ln() / base.ln()
+                }
+
+                pub fn log2(self) -> Self {
+                    self.ln() / std::f64::consts::LN_2 as $t
+                }
+
+                pub fn log10(self) -> Self {
+                    self.ln() / std::f64::consts::LN_10 as $t
+                }
+
+                /// Principal square root with non-negative real part, branch cut along the negative real axis.
+                pub fn sqrt(self) -> Self {
+                    if (self.r == <$t>::ZERO) && (self.i == <$t>::ZERO) {
+                        return Complex { r: <$t>::ZERO,i: self.i, };
+                    }
+                    if (self.r.abs() > 0.25 * <$t>::MAX) || (self.i.abs() > 0.25 * <$t>::MAX) {
+                        // scale down, so norm + |r| cannot overflow
+                        return (self * 0.25).sqrt() * 2.0;
+                    }
+                    let norm = self.norm();
+                    if self.r >= <$t>::ZERO {
+                        let t = ((norm + self.r) * 0.5).sqrt();
+                        Complex {
+                            r: t,
+                            i: self.i / (2.0 * t),
+                        }
+                    }
+                    else {
+                        let t = ((norm - self.r) * 0.5).sqrt();
+                        Complex {
+                            r: self.i.abs() / (2.0 * t),
+                            i: t.copysign(self.i),
+                        }
+                    }
+                }
+
+                /// All `n` roots, starting with the principal root and going counterclockwise.
+                pub fn nth_roots(self,n: usize) -> Vec<Self> {
+                    assert!(n > 0,"Complex::nth_roots: n must be positive");
+                    let (norm,arg) = self.to_polar();
+                    let norm = norm.powf(<$t>::ONE / n as $t);
+                    (0..n).map(|k| {
+                        Complex::<$t>::from_polar(norm,(arg + 2.0 * <$t as Real>::PI * k as $t) / n as $t)
+                    }).collect()
+                }
+
+                pub fn powi(self,n: i32) -> Self {
+                    let mut base = if n < 0 { self.inv() } else { self };
+                    let mut e = n.unsigned_abs();
+                    let mut result = Complex::<$t>::ONE;
+                    while e > 0 {
+                        if (e & 1) != 0 {
+                            result *= base;
+                        }
+                        base *= base;
+                        e >>= 1;
+                    }
+                    result
+                }
+
+                /// Principal power with real exponent, branch cut along the negative real axis.
+                pub fn powf(self,exponent: $t) -> Self {
+                    if (self.r == <$t>::ZERO) && (self.i == <$t>::ZERO) {
+                        return if exponent == <$t>::ZERO { Complex::<$t>::ONE } else { Complex::<$t>::ZERO };
+                    }
+                    let (norm,arg) = self.to_polar();
+                    Complex::<$t>::from_polar(norm.powf(exponent),arg * exponent)
+                }
+
+                /// Principal power `exp(exponent * ln(self))`, branch cut along the negative real axis.
+                pub fn powc(self,exponent: Self) -> Self {
+                    if (self.r == <$t>::ZERO) && (self.i == <$t>::ZERO) {
+                        return if (exponent.r == <$t>::ZERO) && (exponent.i == <$t>::ZERO) { Complex::<$t>::ONE } else { Complex::<$t>::ZERO };
+                    }
+                    (exponent * self.ln()).exp()
+                }
+
+                pub fn sin(self) -> Self {
+                    Complex {
+                        r: self.r.sin() * self.i.cosh(),
+                        i: self.r.cos() * self.i.sinh(),
+                    }
+                }
+
+                pub fn cos(self) -> Self {
+                    Complex {
+                        r: self.r.cos() * self.i.cosh(),
+                        i: -self.r.sin() * self.i.sinh(),
+                    }
+                }
+
+                pub fn tan(self) -> Self {
+                    // -i tanh(iz)
+                    let w = Complex { r: -self.i,i: self.r, }.tanh();
+                    Complex { r: w.i,i: -w.r, }
+                }
+
+                pub fn sinh(self) -> Self {
+                    Complex {
+                        r: self.r.sinh() * self.i.cos(),
+                        i: self.r.cosh() * self.i.sin(),
+                    }
+                }
+
+                pub fn cosh(self) -> Self {
+                    Complex {
+                        r: self.r.cosh() * self.i.cos(),
+                        i: self.r.sinh() * self.i.sin(),
+                    }
+                }
+
+                pub fn tanh(self) -> Self {
+                    if self.r.abs() > 0.25 * <$t>::MAX.ln() {
+                        // cosh(2r) would overflow, but tanh(r) is 1 to full precision here
+                        return Complex {
+                            r: <$t>::ONE.copysign(self.r),
+                            i: 2.0 * (2.0 * self.i).sin() * (-2.0 * self.r.abs()).exp(),
+                        };
+                    }
+                    let (r,i) = (2.0 * self.r,2.0 * self.i);
+                    let f = r.cosh() + i.cos();
+                    Complex {
+                        r: r.sinh() / f,
+                        i: i.sin() / f,
+                    }
+                }
+
+                /// Principal arcsine, branch cuts along the real axis outside `[-1,1]`.
+                pub fn asin(self) -> Self {
+                    // -i ln(iz + sqrt(1 - z^2))
+                    let iz = Complex { r: -self.i,i: self.r, };
+                    let w = (iz + (<$t>::ONE - self * self).sqrt()).ln();
+                    Complex { r: w.i,i: -w.r, }
+                }
+
+                /// Principal arccosine, branch cuts along the real axis outside `[-1,1]`.
+                pub fn acos(self) -> Self {
+                    // π/2 - asin(z)
+                    let w = self.asin();
+                    Complex { r: 0.5 * <$t as Real>::PI - w.r,i: -w.i, }
+                }
+
+                /// Principal arctangent, branch cuts along the imaginary axis outside `[-i,i]`.
+                pub fn atan(self) -> Self {
+                    // i/2 (ln(1 - iz) - ln(1 + iz))
+                    let iz = Complex { r: -self.i,i: self.r, };
+                    let w = (<$t>::ONE - iz).ln() - (<$t>::ONE + iz).ln();
+                    Complex { r: -0.5 * w.i,i: 0.5 * w.r, }
+                }
+
+                /// Principal inverse hyperbolic sine, branch cuts along the imaginary axis outside `[-i,i]`.
+                pub fn asinh(self) -> Self {
+                    (self + (self * self + <$t>::ONE).sqrt()).ln()
+                }
+
+                /// Principal inverse hyperbolic cosine, branch cut along the real axis left of 1.
+                pub fn acosh(self) -> Self {
+                    (self + (self + <$t>::ONE).sqrt() * (self - <$t>::ONE).sqrt()).ln()
+                }
+
+                /// Principal inverse hyperbolic tangent, branch cuts along the real axis outside `[-1,1]`.
+                pub fn atanh(self) -> Self {
+                    ((<$t>::ONE + self).ln() - (<$t>::ONE - self).ln()) * 0.5
+                }
+            }
+
+            impl From<$t> for Complex<$t> {
+                fn from(value: $t) -> Self {
+                    Complex {
+                        r: value,
+                        i: <$t>::ZERO,
+                    }
                 }
             }
 
@@ -260,20 +503,6 @@ macro_rules! complex_impl {
                     }
                 }
             }
-
-            /*
-            impl Unsigned for Complex<$t> {
-                // TODO
-            }
-
-            impl Signed for Complex<$t> {
-                // TODO
-            }
-
-            impl Real for Complex<$t> {
-                // TODO
-            }
-            */
         )+
     }
 }
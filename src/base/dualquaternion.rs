@@ -0,0 +1,245 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Mul,
+            MulAssign,
+            Neg,
+        },
+    },
+};
+
+/// Dual quaternion template.
+///
+/// A dual quaternion `r + εd` combines two [`Quaternion`]s with the dual unit `ε`, which squares to zero. A unit dual
+/// quaternion describes a rigid body transformation: `r` is the rotation and `d` is half the translation times `r`.
+/// Dual quaternions compose by multiplication and blend well, which makes them popular for skinning.
+#[derive(Copy,Clone,Debug)]
+pub struct DualQuaternion<T> {
+    pub r: Quaternion<T>,
+    pub d: Quaternion<T>,
+}
+
+macro_rules! dualquaternion_impl {
+    ($($t:ty)+) => {
+        $(
+            impl DualQuaternion<$t> {
+                /// Create dual quaternion that first rotates by `rotation` and then translates by `translation`.
+                pub fn new(rotation: Quaternion<$t>,translation: Vec3<$t>) -> Self {
+                    let t = Quaternion { r: <$t>::ZERO,i: translation.x,j: translation.y,k: translation.z, };
+                    DualQuaternion {
+                        r: rotation,
+                        d: t * rotation * 0.5,
+                    }
+                }
+
+                pub fn from_translation(translation: Vec3<$t>) -> Self {
+                    DualQuaternion::<$t>::new(Quaternion::ONE,translation)
+                }
+
+                /// Rotation part of the dual quaternion.
+                pub fn rotation(self) -> Quaternion<$t> {
+                    self.r
+                }
+
+                /// Translation part of the dual quaternion, applied after the rotation.
+                pub fn translation(self) -> Vec3<$t> {
+                    let t = self.d * self.r.conj() * (2.0 / self.r.dot(self.r));
+                    Vec3 { x: t.i,y: t.j,z: t.k, }
+                }
+
+                /// Quaternion conjugate of both parts.
+                pub fn conj(self) -> Self {
+                    DualQuaternion {
+                        r: self.r.conj(),
+                        d: self.d.conj(),
+                    }
+                }
+
+                /// Dual conjugate, negates the dual part.
+                pub fn dual_conj(self) -> Self {
+                    DualQuaternion {
+                        r: self.r,
+                        d: -self.d,
+                    }
+                }
+
+                /// Quaternion and dual conjugate combined.
+                pub fn combined_conj(self) -> Self {
+                    DualQuaternion {
+                        r: self.r.conj(),
+                        d: -self.d.conj(),
+                    }
+                }
+
+                pub fn norm(self) -> $t {
+                    self.r.norm()
+                }
+
+                /// Normalize the dual quaternion, so that `r` is a unit quaternion and `d` is orthogonal to it.
+                pub fn normalize(self) -> Self {
+                    let f = <$t>::ONE / self.r.norm();
+                    let r = self.r * f;
+                    let d = self.d * f;
+                    DualQuaternion {
+                        r,
+                        d: d - r * r.dot(d),
+                    }
+                }
+
+                pub fn inv(self) -> Self {
+                    let r = self.r.inv();
+                    DualQuaternion {
+                        r,
+                        d: -(r * self.d * r),
+                    }
+                }
+
+                /// Rotate direction `v`, ignoring the translation.
+                pub fn transform_direction(self,v: Vec3<$t>) -> Vec3<$t> {
+                    self.r.normalize() * v
+                }
+
+                /// Rotate and then translate point `p`.
+                pub fn transform_point(self,p: Vec3<$t>) -> Vec3<$t> {
+                    self.transform_direction(p) + self.translation()
+                }
+
+                /// Screw linear interpolation between unit dual quaternions, see [`Motor::sclerp`].
+                pub fn sclerp(self,other: Self,t: $t) -> Self {
+                    DualQuaternion::<$t>::from(Pose::<$t>::from(self).sclerp(Pose::<$t>::from(other),t))
+                }
+
+                /// Dual quaternion linear blending of unit dual quaternions `items` with `weights`.
+                pub fn blend(items: &[DualQuaternion<$t>],weights: &[$t]) -> Self {
+                    let mut result = DualQuaternion {
+                        r: Quaternion::ZERO,
+                        d: Quaternion::ZERO,
+                    };
+                    if let Some(first) = items.first() {
+                        for (item,weight) in items.iter().zip(weights.iter()) {
+                            // keep all items on the same hemisphere as the first
+                            let w = if first.r.dot(item.r) < <$t>::ZERO { -*weight } else { *weight };
+                            result = result + *item * w;
+                        }
+                    }
+                    result.normalize()
+                }
+            }
+
+            impl Display for DualQuaternion<$t> {
+                fn fmt(&self,f: &mut Formatter) -> Result {
+                    write!(f,"({} + ε({}))",self.r,self.d)
+                }
+            }
+
+            impl PartialEq for DualQuaternion<$t> {
+                fn eq(&self,other: &Self) -> bool {
+                    (self.r == other.r) && (self.d == other.d)
+                }
+            }
+
+            impl One for DualQuaternion<$t> {
+                const ONE: DualQuaternion<$t> = DualQuaternion {
+                    r: Quaternion::ONE,
+                    d: Quaternion::ZERO,
+                };
+            }
+
+            // dual quaternion + dual quaternion
+            impl Add<DualQuaternion<$t>> for DualQuaternion<$t> {
+                type Output = DualQuaternion<$t>;
+                fn add(self,other: DualQuaternion<$t>) -> Self::Output {
+                    DualQuaternion {
+                        r: self.r + other.r,
+                        d: self.d + other.d,
+                    }
+                }
+            }
+
+            // dual quaternion * scalar
+            impl Mul<$t> for DualQuaternion<$t> {
+                type Output = DualQuaternion<$t>;
+                fn mul(self,other: $t) -> Self::Output {
+                    DualQuaternion {
+                        r: self.r * other,
+                        d: self.d * other,
+                    }
+                }
+            }
+
+            // dual quaternion * point
+            impl Mul<Vec3<$t>> for DualQuaternion<$t> {
+                type Output = Vec3<$t>;
+                fn mul(self,other: Vec3<$t>) -> Self::Output {
+                    self.transform_point(other)
+                }
+            }
+
+            // dual quaternion * dual quaternion
+            impl Mul<DualQuaternion<$t>> for DualQuaternion<$t> {
+                type Output = DualQuaternion<$t>;
+                fn mul(self,other: DualQuaternion<$t>) -> Self::Output {
+                    DualQuaternion {
+                        r: self.r * other.r,
+                        d: self.r * other.d + self.d * other.r,
+                    }
+                }
+            }
+
+            // dual quaternion *= dual quaternion
+            impl MulAssign<DualQuaternion<$t>> for DualQuaternion<$t> {
+                fn mul_assign(&mut self,other: DualQuaternion<$t>) {
+                    *self = *self * other;
+                }
+            }
+
+            // -dual quaternion
+            impl Neg for DualQuaternion<$t> {
+                type Output = DualQuaternion<$t>;
+                fn neg(self) -> Self::Output {
+                    DualQuaternion {
+                        r: -self.r,
+                        d: -self.d,
+                    }
+                }
+            }
+
+            impl From<Pose<$t>> for DualQuaternion<$t> {
+                fn from(value: Pose<$t>) -> Self {
+                    DualQuaternion::<$t>::new(value.o,value.p)
+                }
+            }
+
+            impl From<DualQuaternion<$t>> for Pose<$t> {
+                fn from(value: DualQuaternion<$t>) -> Self {
+                    Pose {
+                        p: value.translation(),
+                        o: value.r.normalize(),
+                    }
+                }
+            }
+
+            impl From<DualQuaternion<$t>> for Mat4x4<$t> {
+                fn from(value: DualQuaternion<$t>) -> Self {
+                    Mat4x4::<$t>::from(Pose::<$t>::from(value))
+                }
+            }
+
+            impl From<Mat4x4<$t>> for DualQuaternion<$t> {
+                fn from(value: Mat4x4<$t>) -> Self {
+                    DualQuaternion::<$t>::from(Pose::<$t>::from(value))
+                }
+            }
+        )+
+    }
+}
+
+dualquaternion_impl! { f32 f64 }
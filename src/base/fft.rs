@@ -0,0 +1,403 @@
+use crate::*;
+
+// largest prime factor handled by the mixed radix butterflies, larger ones use Bluestein
+const MAX_RADIX: usize = 13;
+
+#[derive(Clone,Debug)]
+enum FftAlgorithm<T> {
+    // iterative in-place radix-2
+    Radix2,
+    // recursive Cooley-Tukey over (radix,remaining length) factors
+    MixedRadix(Vec<(usize,usize)>),
+    // chirp-z convolution through a power of two transform
+    Bluestein {
+        chirp: Vec<Complex<T>>,
+        spectrum: Vec<Complex<T>>,
+        inner: Box<FftPlan<T>>,
+    },
+}
+
+/// Fast Fourier transform plan
+///
+/// Precomputes the twiddle factors for transforms of length `n`, so the plan can be reused. Powers of two use
+/// radix-2, lengths with only small prime factors use mixed radix and everything else uses Bluestein's algorithm.
+/// The forward transform is unscaled, the inverse transform scales by `1 / n`.
+#[derive(Clone,Debug)]
+pub struct FftPlan<T> {
+    n: usize,
+    twiddles: Vec<Complex<T>>,
+    algorithm: FftAlgorithm<T>,
+}
+
+/// Real input fast Fourier transform plan
+///
+/// Transforms `n` real values into the `n / 2 + 1` non-redundant bins of the spectrum and back. Even lengths run
+/// a complex transform of half the length.
+#[derive(Clone,Debug)]
+pub struct RealFftPlan<T> {
+    n: usize,
+    twiddles: Vec<Complex<T>>,
+    complex: FftPlan<T>,
+}
+
+/// Two dimensional fast Fourier transform plan
+///
+/// Transforms row major data of `width` by `height` values, first along the rows, then along the columns.
+#[derive(Clone,Debug)]
+pub struct FftPlan2D<T> {
+    width: usize,
+    height: usize,
+    rows: FftPlan<T>,
+    columns: FftPlan<T>,
+}
+
+/// In-place fast Fourier transform of a slice, planning on every call.
+pub trait Fft {
+    fn fft(&mut self);
+
+    /// Inverse transform, scaled by `1 / n`.
+    fn ifft(&mut self);
+}
+
+fn factorize(mut n: usize) -> Vec<usize> {
+    let mut factors = Vec::new();
+    let mut p = 2;
+    while p * p <= n {
+        while n.is_multiple_of(p) {
+            factors.push(p);
+            n /= p;
+        }
+        p += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+macro_rules! fft_impl {
+    ($($t:ty)+) => {
+        $(
+            impl FftPlan<$t> {
+                pub fn new(n: usize) -> Self {
+                    // twiddles in f64, so f32 plans are accurate too
+                    let twiddles = (0..n).map(|k| {
+                        let angle = -2.0 * std::f64::consts::PI * (k as f64) / (n as f64);
+                        Complex { r: angle.cos() as $t,i: angle.sin() as $t, }
+                    }).collect();
+                    let algorithm = if n.is_power_of_two() || (n == 0) {
+                        FftAlgorithm::Radix2
+                    }
+                    else {
+                        let primes = factorize(n);
+                        if primes.iter().all(|p| *p <= MAX_RADIX) {
+                            let mut m = n;
+                            FftAlgorithm::MixedRadix(primes.iter().map(|p| {
+                                m /= p;
+                                (*p,m)
+                            }).collect())
+                        }
+                        else {
+                            // chirp e^(-πik²/n), with k² reduced modulo 2n to keep the angle accurate
+                            let chirp: Vec<Complex<$t>> = (0..n).map(|k| {
+                                let k2 = ((k as u128) * (k as u128)) % (2 * n as u128);
+                                let angle = -std::f64::consts::PI * (k2 as f64) / (n as f64);
+                                Complex { r: angle.cos() as $t,i: angle.sin() as $t, }
+                            }).collect();
+                            let m = (2 * n - 1).next_power_of_two();
+                            let inner = FftPlan::<$t>::new(m);
+                            let mut spectrum = vec![Complex::<$t>::ZERO; m];
+                            spectrum[0] = chirp[0].conj();
+                            for k in 1..n {
+                                spectrum[k] = chirp[k].conj();
+                                spectrum[m - k] = chirp[k].conj();
+                            }
+                            inner.forward(&mut spectrum);
+                            FftAlgorithm::Bluestein {
+                                chirp,
+                                spectrum,
+                                inner: Box::new(inner),
+                            }
+                        }
+                    };
+                    FftPlan {
+                        n,
+                        twiddles,
+                        algorithm,
+                    }
+                }
+
+                pub fn len(&self) -> usize {
+                    self.n
+                }
+
+                pub fn is_empty(&self) -> bool {
+                    self.n == 0
+                }
+
+                /// Forward transform of `data` in place.
+                pub fn forward(&self,data: &mut [Complex<$t>]) {
+                    assert!(data.len() == self.n,"FftPlan::forward: length mismatch");
+                    match &self.algorithm {
+                        FftAlgorithm::Radix2 => self.radix2(data),
+                        FftAlgorithm::MixedRadix(factors) => {
+                            let input = data.to_vec();
+                            self.mixed_radix(data,&input,0,1,factors);
+                        },
+                        FftAlgorithm::Bluestein { chirp,spectrum,inner, } => {
+                            let mut a = vec![Complex::<$t>::ZERO; inner.n];
+                            for (a,(x,w)) in a.iter_mut().zip(data.iter().zip(chirp.iter())) {
+                                *a = *x * *w;
+                            }
+                            inner.forward(&mut a);
+                            for (a,b) in a.iter_mut().zip(spectrum.iter()) {
+                                *a *= *b;
+                            }
+                            inner.inverse(&mut a);
+                            for (x,(a,w)) in data.iter_mut().zip(a.iter().zip(chirp.iter())) {
+                                *x = *a * *w;
+                            }
+                        },
+                    }
+                }
+
+                /// Inverse transform of `data` in place, scaled by `1 / n`.
+                pub fn inverse(&self,data: &mut [Complex<$t>]) {
+                    // conj(fft(conj(x))) is the unscaled inverse
+                    for x in data.iter_mut() {
+                        *x = x.conj();
+                    }
+                    self.forward(data);
+                    let f = <$t>::ONE / (self.n as $t);
+                    for x in data.iter_mut() {
+                        *x = x.conj() * f;
+                    }
+                }
+
+                fn radix2(&self,data: &mut [Complex<$t>]) {
+                    let n = self.n;
+
+                    // bit reversal permutation
+                    let mut j = 0;
+                    for i in 1..n {
+                        let mut bit = n >> 1;
+                        while (j & bit) != 0 {
+                            j ^= bit;
+                            bit >>= 1;
+                        }
+                        j ^= bit;
+                        if i < j {
+                            data.swap(i,j);
+                        }
+                    }
+
+                    // butterflies
+                    let mut len = 2;
+                    while len <= n {
+                        let half = len / 2;
+                        let step = n / len;
+                        for start in (0..n).step_by(len) {
+                            for k in 0..half {
+                                let a = data[start + k];
+                                let b = data[start + k + half] * self.twiddles[k * step];
+                                data[start + k] = a + b;
+                                data[start + k + half] = a - b;
+                            }
+                        }
+                        len <<= 1;
+                    }
+                }
+
+                // decimation in time, transforms input[offset + j * stride] into output
+                fn mixed_radix(&self,output: &mut [Complex<$t>],input: &[Complex<$t>],offset: usize,stride: usize,factors: &[(usize,usize)]) {
+                    let (p,m) = factors[0];
+                    if m == 1 {
+                        for (j,x) in output.iter_mut().enumerate().take(p) {
+                            *x = input[offset + j * stride];
+                        }
+                    }
+                    else {
+                        for j in 0..p {
+                            self.mixed_radix(&mut output[j * m..(j + 1) * m],input,offset + j * stride,stride * p,&factors[1..]);
+                        }
+                    }
+
+                    // combine p transforms of length m, the twiddle stride is n / (p * m)
+                    if p == 2 {
+                        for u in 0..m {
+                            let t = output[u + m] * self.twiddles[u * stride];
+                            output[u + m] = output[u] - t;
+                            output[u] += t;
+                        }
+                    }
+                    else {
+                        let mut scratch = vec![Complex::<$t>::ZERO; p];
+                        for u in 0..m {
+                            for (q,s) in scratch.iter_mut().enumerate() {
+                                *s = output[u + q * m];
+                            }
+                            for q1 in 0..p {
+                                let k = u + q1 * m;
+                                let mut index = 0;
+                                let mut sum = scratch[0];
+                                for s in scratch.iter().skip(1) {
+                                    index = (index + stride * k) % self.n;
+                                    sum += *s * self.twiddles[index];
+                                }
+                                output[k] = sum;
+                            }
+                        }
+                    }
+                }
+            }
+
+            impl RealFftPlan<$t> {
+                pub fn new(n: usize) -> Self {
+                    let half = n / 2;
+                    let twiddles = (0..half).map(|k| {
+                        let angle = -2.0 * std::f64::consts::PI * (k as f64) / (n as f64);
+                        Complex { r: angle.cos() as $t,i: angle.sin() as $t, }
+                    }).collect();
+                    RealFftPlan {
+                        n,
+                        twiddles,
+                        complex: FftPlan::<$t>::new(if n % 2 == 0 { half } else { n }),
+                    }
+                }
+
+                pub fn len(&self) -> usize {
+                    self.n
+                }
+
+                pub fn is_empty(&self) -> bool {
+                    self.n == 0
+                }
+
+                /// Transform `n` real values into `n / 2 + 1` bins.
+                pub fn forward(&self,input: &[$t],output: &mut [Complex<$t>]) {
+                    assert!(input.len() == self.n,"RealFftPlan::forward: input length mismatch");
+                    assert!(output.len() == self.n / 2 + 1,"RealFftPlan::forward: output length mismatch");
+                    if self.n % 2 != 0 {
+                        let mut data: Vec<Complex<$t>> = input.iter().map(|x| Complex::<$t>::from(*x)).collect();
+                        self.complex.forward(&mut data);
+                        output.copy_from_slice(&data[0..output.len()]);
+                        return;
+                    }
+
+                    // pack even and odd samples into one complex transform of half the length
+                    let m = self.n / 2;
+                    if m == 0 {
+                        // no samples, the only bin is zero
+                        output[0] = Complex::<$t>::ZERO;
+                        return;
+                    }
+                    let mut z: Vec<Complex<$t>> = input.chunks(2).map(|pair| Complex { r: pair[0],i: pair[1], }).collect();
+                    self.complex.forward(&mut z);
+                    for (k,x) in output.iter_mut().enumerate() {
+                        let a = z[k % m];
+                        let b = z[(m - k % m) % m].conj();
+                        let even = (a + b) * 0.5;
+                        let odd = a - b;
+                        let odd = Complex { r: odd.i * 0.5,i: -odd.r * 0.5, };
+                        let w = if k < m { self.twiddles[k] } else { Complex { r: -<$t>::ONE,i: <$t>::ZERO, } };
+                        *x = even + w * odd;
+                    }
+                }
+
+                /// Transform `n / 2 + 1` bins back into `n` real values, scaled by `1 / n`.
+                pub fn inverse(&self,input: &[Complex<$t>],output: &mut [$t]) {
+                    assert!(input.len() == self.n / 2 + 1,"RealFftPlan::inverse: input length mismatch");
+                    assert!(output.len() == self.n,"RealFftPlan::inverse: output length mismatch");
+                    if self.n % 2 != 0 {
+                        // restore the redundant half by hermitian symmetry
+                        let mut data: Vec<Complex<$t>> = (0..self.n).map(|k| if k < input.len() { input[k] } else { input[self.n - k].conj() }).collect();
+                        self.complex.inverse(&mut data);
+                        for (x,c) in output.iter_mut().zip(data.iter()) {
+                            *x = c.r;
+                        }
+                        return;
+                    }
+
+                    // unpack into the half length transform of the packed samples
+                    let m = self.n / 2;
+                    if m == 0 {
+                        return;
+                    }
+                    let mut z: Vec<Complex<$t>> = (0..m).map(|k| {
+                        let a = input[k];
+                        let b = input[m - k].conj();
+                        let even = (a + b) * 0.5;
+                        let odd = (a - b) * self.twiddles[k].conj() * 0.5;
+                        Complex { r: even.r - odd.i,i: even.i + odd.r, }
+                    }).collect();
+                    self.complex.inverse(&mut z);
+                    for (pair,c) in output.chunks_mut(2).zip(z.iter()) {
+                        pair[0] = c.r;
+                        pair[1] = c.i;
+                    }
+                }
+            }
+
+            impl FftPlan2D<$t> {
+                pub fn new(width: usize,height: usize) -> Self {
+                    FftPlan2D {
+                        width,
+                        height,
+                        rows: FftPlan::<$t>::new(width),
+                        columns: FftPlan::<$t>::new(height),
+                    }
+                }
+
+                pub fn width(&self) -> usize {
+                    self.width
+                }
+
+                pub fn height(&self) -> usize {
+                    self.height
+                }
+
+                /// Forward transform of row major `data` in place.
+                pub fn forward(&self,data: &mut [Complex<$t>]) {
+                    self.transform(data,false);
+                }
+
+                /// Inverse transform of row major `data` in place, scaled by `1 / (width * height)`.
+                pub fn inverse(&self,data: &mut [Complex<$t>]) {
+                    self.transform(data,true);
+                }
+
+                fn transform(&self,data: &mut [Complex<$t>],inverse: bool) {
+                    assert!(data.len() == self.width * self.height,"FftPlan2D::transform: length mismatch");
+                    if data.is_empty() {
+                        return;
+                    }
+                    for row in data.chunks_mut(self.width) {
+                        if inverse { self.rows.inverse(row) } else { self.rows.forward(row) }
+                    }
+                    let mut column = vec![Complex::<$t>::ZERO; self.height];
+                    for x in 0..self.width {
+                        for (y,c) in column.iter_mut().enumerate() {
+                            *c = data[y * self.width + x];
+                        }
+                        if inverse { self.columns.inverse(&mut column) } else { self.columns.forward(&mut column) }
+                        for (y,c) in column.iter().enumerate() {
+                            data[y * self.width + x] = *c;
+                        }
+                    }
+                }
+            }
+
+            impl Fft for [Complex<$t>] {
+                fn fft(&mut self) {
+                    FftPlan::<$t>::new(self.len()).forward(self);
+                }
+
+                fn ifft(&mut self) {
+                    FftPlan::<$t>::new(self.len()).inverse(self);
+                }
+            }
+        )+
+    }
+}
+
+fft_impl! { f32 f64 }
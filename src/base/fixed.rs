@@ -27,108 +27,372 @@ use {
 };
 
 /// fixed-point real number
+///
+/// `T` stores the value times 2^`B`, so `Fixed<i16,15>` is Q1.15 and `Fixed<i32,16>` is Q16.16. `B` must be smaller
+/// than the number of bits in `T`. Formats convert between each other with [`Cast`], rounding to nearest.
+///
+/// All arithmetic is done on integers only. Operators panic on overflow in debug builds, like primitive integers;
+/// use the checked, saturating and wrapping variants where overflow is expected.
 #[derive(Copy,Clone,Debug)]
 pub struct Fixed<T,const B: usize>(T);
 
+// raw multiply and divide through a double width intermediate
+trait Widening: Sized {
+    // `(self * other) >> shift` rounded down, and whether it overflowed
+    fn mul_shr(self,other: Self,shift: usize) -> (Self,bool);
+
+    // `(self << shift) / other` rounded towards zero, and whether it overflowed
+    fn shl_div(self,other: Self,shift: usize) -> (Self,bool);
+}
+
+macro_rules! widening_impl {
+    ($(($t:ty,$w:ty))+) => {
+        $(
+            impl Widening for $t {
+                fn mul_shr(self,other: Self,shift: usize) -> (Self,bool) {
+                    let result = ((self as $w) * (other as $w)) >> shift;
+                    (result as $t,(result as $t) as $w != result)
+                }
+
+                fn shl_div(self,other: Self,shift: usize) -> (Self,bool) {
+                    let result = ((self as $w) << shift) / (other as $w);
+                    (result as $t,(result as $t) as $w != result)
+                }
+            }
+        )+
+    }
+}
+
+widening_impl! { (u8,u16) (u16,u32) (u32,u64) (u64,u128) (i8,i16) (i16,i32) (i32,i64) (i64,i128) }
+
+// 128 x 128 -> 256 bit multiply, returns (high,low)
+fn mul_u256(a: u128,b: u128) -> (u128,u128) {
+    let mask = (1u128 << 64) - 1;
+    let (a1,a0) = (a >> 64,a & mask);
+    let (b1,b0) = (b >> 64,b & mask);
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+    let mid = (p00 >> 64) + (p01 & mask) + (p10 & mask);
+    (p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64),(p00 & mask) | (mid << 64))
+}
+
+// 256 / 128 -> 256 bit restoring division, returns (high,low) of the quotient
+fn div_u256(hi: u128,lo: u128,d: u128) -> (u128,u128) {
+    let mut r = 0u128;
+    let mut q = (0u128,0u128);
+    for i in (0..256).rev() {
+        let bit = if i >= 128 { (hi >> (i - 128)) & 1 } else { (lo >> i) & 1 };
+        let carry = r >> 127;
+        r = (r << 1) | bit;
+        if (carry != 0) || (r >= d) {
+            r = r.wrapping_sub(d);
+            if i >= 128 {
+                q.0 |= 1 << (i - 128);
+            }
+            else {
+                q.1 |= 1 << i;
+            }
+        }
+    }
+    q
+}
+
+// (high,low) >> shift, returns the shifted value and whether any one bits were shifted out
+fn shr_u256(hi: u128,lo: u128,shift: usize) -> ((u128,u128),bool) {
+    if shift == 0 {
+        ((hi,lo),false)
+    }
+    else {
+        ((hi >> shift,(lo >> shift) | (hi << (128 - shift))),(lo << (128 - shift)) != 0)
+    }
+}
+
+// (high,low) for value << shift
+fn shl_u256(value: u128,shift: usize) -> (u128,u128) {
+    if shift == 0 {
+        (0,value)
+    }
+    else {
+        (value >> (128 - shift),value << shift)
+    }
+}
+
+impl Widening for u128 {
+    fn mul_shr(self,other: Self,shift: usize) -> (Self,bool) {
+        let (hi,lo) = mul_u256(self,other);
+        let ((hi,lo),_) = shr_u256(hi,lo,shift);
+        (lo,hi != 0)
+    }
+
+    fn shl_div(self,other: Self,shift: usize) -> (Self,bool) {
+        let (hi,lo) = shl_u256(self,shift);
+        let (hi,lo) = div_u256(hi,lo,other);
+        (lo,hi != 0)
+    }
+}
+
+impl Widening for i128 {
+    fn mul_shr(self,other: Self,shift: usize) -> (Self,bool) {
+        let negative = (self < 0) != (other < 0);
+        let (hi,lo) = mul_u256(self.unsigned_abs(),other.unsigned_abs());
+        let ((mut hi,mut lo),inexact) = shr_u256(hi,lo,shift);
+        // round down, so away from zero for negative results
+        if negative && inexact {
+            lo = lo.wrapping_add(1);
+            if lo == 0 {
+                hi = hi.wrapping_add(1);
+            }
+        }
+        i128_from_magnitude(hi,lo,negative)
+    }
+
+    fn shl_div(self,other: Self,shift: usize) -> (Self,bool) {
+        let negative = (self < 0) != (other < 0);
+        let (hi,lo) = shl_u256(self.unsigned_abs(),shift);
+        let (hi,lo) = div_u256(hi,lo,other.unsigned_abs());
+        i128_from_magnitude(hi,lo,negative)
+    }
+}
+
+// apply sign to a 256 bit magnitude, returns the wrapped value and whether it overflowed
+fn i128_from_magnitude(hi: u128,lo: u128,negative: bool) -> (i128,bool) {
+    if negative {
+        ((lo as i128).wrapping_neg(),(hi != 0) || (lo > (1u128 << 127)))
+    }
+    else {
+        (lo as i128,(hi != 0) || (lo > (i128::MAX as u128)))
+    }
+}
+
 macro_rules! fixed_impl {
-    ($(($t:ty,$b:expr))+) => {
+    ($($t:ty)+) => {
         $(
-            impl Fixed<$t,$b> {
-                pub const BITS: usize = $b;
+            impl<const B: usize> Fixed<$t,B> {
+                pub const BITS: usize = B;
+
+                // 2^B, for constants and float conversion
+                const SCALE: f64 = (1u128 << B) as f64;
+
+                // raw value >> shift for 0 < shift < bits, rounded to nearest with ties away from zero
+                fn round_shr(self,shift: usize) -> $t {
+                    let rest = self.0 & (1 as $t).wrapping_shl(shift as u32).wrapping_sub(1);
+                    let half = (1 as $t) << (shift - 1);
+                    if (rest > half) || ((rest == half) && (self.0 >= <$t>::ZERO)) {
+                        (self.0 >> shift) + 1
+                    }
+                    else {
+                        self.0 >> shift
+                    }
+                }
+
+                pub fn to_f32(self) -> f32 {
+                    self.lossy_cast()
+                }
+
+                pub fn to_f64(self) -> f64 {
+                    self.lossy_cast()
+                }
+
+                pub fn checked_add(self,other: Self) -> Option<Self> {
+                    self.0.checked_add(other.0).map(Fixed)
+                }
+
+                pub fn checked_sub(self,other: Self) -> Option<Self> {
+                    self.0.checked_sub(other.0).map(Fixed)
+                }
+
+                pub fn checked_mul(self,other: Self) -> Option<Self> {
+                    match self.0.mul_shr(other.0,B) {
+                        (value,false) => Some(Fixed(value)),
+                        _ => None,
+                    }
+                }
+
+                /// Divide, or `None` on overflow or division by zero.
+                pub fn checked_div(self,other: Self) -> Option<Self> {
+                    if other.0 == <$t>::ZERO {
+                        return None;
+                    }
+                    match self.0.shl_div(other.0,B) {
+                        (value,false) => Some(Fixed(value)),
+                        _ => None,
+                    }
+                }
+
+                pub fn saturating_add(self,other: Self) -> Self {
+                    Fixed(self.0.saturating_add(other.0))
+                }
+
+                pub fn saturating_sub(self,other: Self) -> Self {
+                    Fixed(self.0.saturating_sub(other.0))
+                }
+
+                pub fn saturating_mul(self,other: Self) -> Self {
+                    match self.0.mul_shr(other.0,B) {
+                        (value,false) => Fixed(value),
+                        _ => self.saturate(other),
+                    }
+                }
+
+                pub fn saturating_div(self,other: Self) -> Self {
+                    match self.0.shl_div(other.0,B) {
+                        (value,false) => Fixed(value),
+                        _ => self.saturate(other),
+                    }
+                }
+
+                pub fn wrapping_add(self,other: Self) -> Self {
+                    Fixed(self.0.wrapping_add(other.0))
+                }
+
+                pub fn wrapping_sub(self,other: Self) -> Self {
+                    Fixed(self.0.wrapping_sub(other.0))
+                }
+
+                pub fn wrapping_mul(self,other: Self) -> Self {
+                    Fixed(self.0.mul_shr(other.0,B).0)
+                }
+
+                pub fn wrapping_div(self,other: Self) -> Self {
+                    Fixed(self.0.shl_div(other.0,B).0)
+                }
+
+                // limit for an overflowing product or quotient of self and other
+                fn saturate(self,other: Self) -> Self {
+                    if (self.0 < <$t>::ZERO) != (other.0 < <$t>::ZERO) {
+                        Fixed(<$t>::MIN)
+                    }
+                    else {
+                        Fixed(<$t>::MAX)
+                    }
+                }
             }
             
-            impl Zero for Fixed<$t,$b> {
+            impl<const B: usize> Zero for Fixed<$t,B> {
                 const ZERO: Self = Fixed(<$t>::ZERO);
             }
             
-            impl One for Fixed<$t,$b> {
-                const ONE: Self = Fixed(<$t>::ONE << $b);
+            impl<const B: usize> One for Fixed<$t,B> {
+                // saturate when there are no integer bits, as in Q0.7
+                const ONE: Self = Fixed(if (<$t>::MAX >> B) == 0 { <$t>::MAX } else { <$t>::ONE << B });
             }
 
-            impl Display for Fixed<$t,$b> {
+            impl<const B: usize> Display for Fixed<$t,B> {
                 fn fmt(&self,f: &mut Formatter) -> Result {
-                    write!(f,"{}:{}",self.0,$b)
+                    write!(f,"{}:{}",self.0,B)
                 }
             }
             
-            impl PartialEq<Fixed<$t,$b>> for Fixed<$t,$b> {
-                fn eq(&self,other: &Fixed<$t,$b>) -> bool {
+            impl<const B: usize> PartialEq<Fixed<$t,B>> for Fixed<$t,B> {
+                fn eq(&self,other: &Fixed<$t,B>) -> bool {
                     self.0 == other.0
                 }
             }
             
-            impl PartialOrd<Fixed<$t,$b>> for Fixed<$t,$b> {
-                fn partial_cmp(&self,other: &Fixed<$t,$b>) -> Option<Ordering> {
+            impl<const B: usize> PartialOrd<Fixed<$t,B>> for Fixed<$t,B> {
+                fn partial_cmp(&self,other: &Fixed<$t,B>) -> Option<Ordering> {
                     self.0.partial_cmp(&other.0)
                 }
             }
 
-            impl Add<Fixed<$t,$b>> for Fixed<$t,$b> {
+            // one step of the representation is one ULP, tolerances are taken by magnitude
+            impl<const B: usize> ApproxEq for Fixed<$t,B> {
+                type Epsilon = Self;
+                const DEFAULT_EPSILON: Self = Fixed(1);
+                const DEFAULT_MAX_RELATIVE: Self = Fixed(1);
+                const DEFAULT_MAX_ULPS: u32 = 1;
+
+                fn abs_diff_eq(&self,other: &Self,epsilon: Self) -> bool {
+                    self.0.abs_diff(other.0) <= epsilon.0.abs_diff(<$t>::ZERO)
+                }
+
+                fn relative_eq(&self,other: &Self,epsilon: Self,max_relative: Self) -> bool {
+                    if self.abs_diff_eq(other,epsilon) {
+                        return true;
+                    }
+                    let largest = if self.0.abs_diff(<$t>::ZERO) > other.0.abs_diff(<$t>::ZERO) { *self } else { *other };
+                    match largest.checked_mul(max_relative) {
+                        Some(limit) => self.0.abs_diff(other.0) <= limit.0.abs_diff(<$t>::ZERO),
+                        // the limit is beyond the range, so any difference fits
+                        None => true,
+                    }
+                }
+
+                fn ulps_eq(&self,other: &Self,epsilon: Self,max_ulps: u32) -> bool {
+                    self.abs_diff_eq(other,epsilon) || ((self.0.abs_diff(other.0) as u128) <= (max_ulps as u128))
+                }
+            }
+
+            impl<const B: usize> Add<Fixed<$t,B>> for Fixed<$t,B> {
                 type Output = Self;
                 fn add(self,other: Self) -> Self::Output {
                     Fixed(self.0 + other.0)
                 }
             }
 
-            impl AddAssign<Fixed<$t,$b>> for Fixed<$t,$b> {
+            impl<const B: usize> AddAssign<Fixed<$t,B>> for Fixed<$t,B> {
                 fn add_assign(&mut self,other: Self) {
                     self.0 += other.0;
                 }
             }
 
-            impl Sub<Fixed<$t,$b>> for Fixed<$t,$b> {
+            impl<const B: usize> Sub<Fixed<$t,B>> for Fixed<$t,B> {
                 type Output = Self;
                 fn sub(self,other: Self) -> Self::Output {
                     Fixed(self.0 - other.0)
                 }
             }
 
-            impl SubAssign<Fixed<$t,$b>> for Fixed<$t,$b> {
+            impl<const B: usize> SubAssign<Fixed<$t,B>> for Fixed<$t,B> {
                 fn sub_assign(&mut self,other: Self) {
                     self.0 -= other.0;
                 }
             }
             
-            impl Mul<Fixed<$t,$b>> for Fixed<$t,$b> {
+            impl<const B: usize> Mul<Fixed<$t,B>> for Fixed<$t,B> {
                 type Output = Self;
                 fn mul(self,other: Self) -> Self::Output {
-                    Fixed((self.0 * other.0) >> $b)
+                    let (value,overflow) = self.0.mul_shr(other.0,B);
+                    debug_assert!(!overflow,"Fixed::mul: overflow");
+                    Fixed(value)
                 }
             }
 
-            impl MulAssign<Fixed<$t,$b>> for Fixed<$t,$b> {
+            impl<const B: usize> MulAssign<Fixed<$t,B>> for Fixed<$t,B> {
                 fn mul_assign(&mut self,other: Self) {
-                    self.0 *= other.0;
-                    self.0 >>= $b;
+                    *self = *self * other;
                 }
             }
 
-            impl Div<Fixed<$t,$b>> for Fixed<$t,$b> {
+            impl<const B: usize> Div<Fixed<$t,B>> for Fixed<$t,B> {
                 type Output = Self;
                 fn div(self,other: Self) -> Self::Output {
-                    Fixed((self.0 << $b) / other.0)
+                    let (value,overflow) = self.0.shl_div(other.0,B);
+                    debug_assert!(!overflow,"Fixed::div: overflow");
+                    Fixed(value)
                 }
             }
 
-            impl DivAssign<Fixed<$t,$b>> for Fixed<$t,$b> {
+            impl<const B: usize> DivAssign<Fixed<$t,B>> for Fixed<$t,B> {
                 fn div_assign(&mut self,other: Self) {
-                    self.0 <<= $b;
-                    self.0 /= other.0;
+                    *self = *self / other;
                 }
             }
             
-            impl Unsigned for Fixed<$t,$b> {
-                const MIN: Self = Self::ZERO;
+            impl<const B: usize> Unsigned for Fixed<$t,B> {
+                const MIN: Self = Fixed(<$t>::MIN);
                 const MAX: Self = Fixed(<$t>::MAX);
 
-                fn div_euclid(self,_other: Self) -> Self {
-                    // TODO
-                    Self::ZERO
+                fn div_euclid(self,other: Self) -> Self {
+                    let (value,overflow) = self.0.div_euclid(other.0).shl_div(1,B);
+                    debug_assert!(!overflow,"Fixed::div_euclid: overflow");
+                    Fixed(value)
                 }
             
-                fn rem_euclid(self,_other: Self) -> Self {
-                    // TODO
-                    Self::ZERO
+                fn rem_euclid(self,other: Self) -> Self {
+                    Fixed(self.0.rem_euclid(other.0))
                 }
             
                 fn min(self,other: Self) -> Self {
@@ -165,29 +429,200 @@ macro_rules! fixed_impl {
                     self * b + c
                 }
             
-                fn powi(self,_n: i32) -> Self {
-                    // TODO
-                    Self::ZERO
-                }            
+                fn powi(self,n: i32) -> Self {
+                    let mut base = self;
+                    let mut e = n.unsigned_abs();
+                    let mut result = Self::ONE;
+                    while e > 0 {
+                        if (e & 1) != 0 {
+                            result *= base;
+                        }
+                        e >>= 1;
+                        if e > 0 {
+                            base *= base;
+                        }
+                    }
+                    if n < 0 {
+                        Self::ONE / result
+                    }
+                    else {
+                        result
+                    }
+                }
+
+                fn checked_add(self,other: Self) -> Option<Self> {
+                    self.checked_add(other)
+                }
+
+                fn checked_sub(self,other: Self) -> Option<Self> {
+                    self.checked_sub(other)
+                }
+
+                fn checked_mul(self,other: Self) -> Option<Self> {
+                    self.checked_mul(other)
+                }
+
+                fn checked_div(self,other: Self) -> Option<Self> {
+                    self.checked_div(other)
+                }
+
+                fn saturating_add(self,other: Self) -> Self {
+                    self.saturating_add(other)
+                }
+
+                fn saturating_sub(self,other: Self) -> Self {
+                    self.saturating_sub(other)
+                }
+
+                fn saturating_mul(self,other: Self) -> Self {
+                    self.saturating_mul(other)
+                }
+
+                fn saturating_div(self,other: Self) -> Self {
+                    self.saturating_div(other)
+                }
+
+                fn wrapping_add(self,other: Self) -> Self {
+                    self.wrapping_add(other)
+                }
+
+                fn wrapping_sub(self,other: Self) -> Self {
+                    self.wrapping_sub(other)
+                }
+
+                fn wrapping_mul(self,other: Self) -> Self {
+                    self.wrapping_mul(other)
+                }
+
+                fn wrapping_div(self,other: Self) -> Self {
+                    self.wrapping_div(other)
+                }
+            }
+        )+
+    }
+}
+
+fixed_impl! { u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+
+macro_rules! fixed_cast_impl {
+    ($f:ty => $($t:ty)+) => {
+        $(
+            impl<const B: usize> Cast<$f> for Fixed<$t,B> {
+                fn checked_cast(self) -> Option<$f> {
+                    Some(self.lossy_cast())
+                }
+
+                fn saturating_cast(self) -> $f {
+                    self.lossy_cast()
+                }
+
+                fn lossy_cast(self) -> $f {
+                    (self.0 as $f) / (Self::SCALE as $f)
+                }
+            }
+
+            impl<const B: usize> Cast<Fixed<$t,B>> for $f {
+                fn checked_cast(self) -> Option<Fixed<$t,B>> {
+                    Some(Fixed((self * (Fixed::<$t,B>::SCALE as $f)).round().checked_cast()?))
+                }
+
+                fn saturating_cast(self) -> Fixed<$t,B> {
+                    Fixed((self * (Fixed::<$t,B>::SCALE as $f)).round().saturating_cast())
+                }
+
+                fn lossy_cast(self) -> Fixed<$t,B> {
+                    Fixed((self * (Fixed::<$t,B>::SCALE as $f)).round() as $t)
+                }
+            }
+
+            impl<const B: usize> From<$f> for Fixed<$t,B> {
+                /// Round to nearest, saturating out of range values.
+                fn from(value: $f) -> Self {
+                    value.saturating_cast()
+                }
             }
         )+
     }
 }
 
-//fixed_impl! { (u8,8) (u16,8) (u32,16) (u64,32) (u128,64) (i8,8) (i16,8) (i32,16) (i64,32) (i128,64) }
-fixed_impl! { (u16,8) (u32,16) (u64,32) (u128,64) (i16,8) (i32,16) (i64,32) (i128,64) }
+fixed_cast_impl! { f32 => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+fixed_cast_impl! { f64 => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+
+// conversion between formats, rounding to nearest with ties away from zero when fraction bits are dropped
+macro_rules! fixed_convert_impl {
+    ($t:ty => $($u:ty)+) => {
+        $(
+            impl<const B: usize,const C: usize> Cast<Fixed<$u,C>> for Fixed<$t,B> {
+                fn checked_cast(self) -> Option<Fixed<$u,C>> {
+                    if C < B {
+                        Some(Fixed(self.round_shr(B - C).checked_cast()?))
+                    }
+                    else {
+                        let value: $u = self.0.checked_cast()?;
+                        let result = value << (C - B);
+                        if (result >> (C - B)) == value {
+                            Some(Fixed(result))
+                        }
+                        else {
+                            None
+                        }
+                    }
+                }
+
+                fn saturating_cast(self) -> Fixed<$u,C> {
+                    if C < B {
+                        Fixed(self.round_shr(B - C).saturating_cast())
+                    }
+                    else {
+                        let value: $u = self.0.saturating_cast();
+                        let result = value << (C - B);
+                        if (result >> (C - B)) == value {
+                            Fixed(result)
+                        }
+                        else if value < <$u>::ZERO {
+                            Fixed(<$u>::MIN)
+                        }
+                        else {
+                            Fixed(<$u>::MAX)
+                        }
+                    }
+                }
+
+                fn lossy_cast(self) -> Fixed<$u,C> {
+                    if C < B {
+                        Fixed(self.round_shr(B - C).lossy_cast())
+                    }
+                    else {
+                        Fixed((self.0 as $u) << (C - B))
+                    }
+                }
+            }
+        )+
+    }
+}
+
+fixed_convert_impl! { u8 => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+fixed_convert_impl! { u16 => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+fixed_convert_impl! { u32 => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+fixed_convert_impl! { u64 => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+fixed_convert_impl! { u128 => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+fixed_convert_impl! { i8 => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+fixed_convert_impl! { i16 => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+fixed_convert_impl! { i32 => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+fixed_convert_impl! { i64 => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+fixed_convert_impl! { i128 => u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
 
 macro_rules! fixed_impl_signed {
-    ($(($t:ty,$b:expr))+) => {
+    ($($t:ty)+) => {
         $(
-            impl Neg for Fixed<$t,$b> {
+            impl<const B: usize> Neg for Fixed<$t,B> {
                 type Output = Self;
                 fn neg(self) -> Self::Output {
                     Fixed(-self.0)
                 }
             }
 
-            impl Signed for Fixed<$t,$b> {
+            impl<const B: usize> Signed for Fixed<$t,B> {
                 fn abs(self) -> Self {
                     if self < Self::ZERO {
                         -self
@@ -230,284 +665,558 @@ macro_rules! fixed_impl_signed {
                 }            
             }
 
-            impl Real for Fixed<$t,$b> {
-                const PI: Self = Fixed((std::f64::consts::PI * (Self::ONE.0 as f64)) as $t);
+        )+
+    }
+}
+
+fixed_impl_signed! { i8 i16 i32 i64 i128 }
+
+// transcendental functions need at least three integer bits to hold pi, so B <= bits - 3
+macro_rules! fixed_real_impl {
+    ($($t:ty)+) => {
+        $(
+            impl<const B: usize> Fixed<$t,B> {
+                // mask of the fraction bits
+                const FRACTION: $t = (1 << B) - 1;
+                const HALF: Self = Fixed(if B == 0 { 0 } else { 1 << (B - 1) });
+                const FRAC_PI_2: Self = Fixed((std::f64::consts::FRAC_PI_2 * Self::SCALE) as $t);
+                const FRAC_PI_4: Self = Fixed((std::f64::consts::FRAC_PI_4 * Self::SCALE) as $t);
+                const TAN_FRAC_PI_8: Self = Fixed(((std::f64::consts::SQRT_2 - 1.0) * Self::SCALE) as $t);
+                const LN_2: Self = Fixed((std::f64::consts::LN_2 * Self::SCALE) as $t);
+                const LN_10: Self = Fixed((std::f64::consts::LN_10 * Self::SCALE) as $t);
+                const DEGREES: Self = Fixed(((180.0 / std::f64::consts::PI) * Self::SCALE) as $t);
+                const RADIANS: Self = Fixed(((std::f64::consts::PI / 180.0) * Self::SCALE) as $t);
+
+                // exp(r) for small r as Taylor series
+                fn exp_series(r: Self) -> Self {
+                    let mut sum = Self::ONE;
+                    let mut term = Self::ONE;
+                    let mut n: $t = 1;
+                    loop {
+                        term = Fixed((term * r).0 / n);
+                        if term.0 == 0 {
+                            return sum;
+                        }
+                        sum += term;
+                        n += 1;
+                    }
+                }
+
+                // (sin(r),cos(r)) for |r| <= pi/4 as Taylor series
+                fn sin_cos_series(r: Self) -> (Self,Self) {
+                    let r2 = r * r;
+                    let mut sin = r;
+                    let mut cos = Self::ONE;
+                    let mut sin_term = r;
+                    let mut cos_term = Self::ONE;
+                    let mut n: $t = 1;
+                    while (sin_term.0 != 0) || (cos_term.0 != 0) {
+                        sin_term = Fixed(-(sin_term * r2).0 / ((2 * n) * (2 * n + 1)));
+                        cos_term = Fixed(-(cos_term * r2).0 / ((2 * n - 1) * (2 * n)));
+                        sin += sin_term;
+                        cos += cos_term;
+                        n += 1;
+                    }
+                    (sin,cos)
+                }
+
+                // atan(z) for |z| <= tan(pi/8) as Taylor series
+                fn atan_series(z: Self) -> Self {
+                    let z2 = z * z;
+                    let mut sum = z;
+                    let mut power = z;
+                    let mut n: $t = 1;
+                    loop {
+                        power = -(power * z2);
+                        let term = power.0 / (2 * n + 1);
+                        if term == 0 {
+                            return sum;
+                        }
+                        sum.0 += term;
+                        n += 1;
+                    }
+                }
+
+                // atan(z) for |z| <= 1
+                fn atan_unit(z: Self) -> Self {
+                    if z > Self::TAN_FRAC_PI_8 {
+                        Self::FRAC_PI_4 + Self::atan_series((z - Self::ONE) / (z + Self::ONE))
+                    }
+                    else if z < -Self::TAN_FRAC_PI_8 {
+                        Self::atan_series((z + Self::ONE) / (Self::ONE - z)) - Self::FRAC_PI_4
+                    }
+                    else {
+                        Self::atan_series(z)
+                    }
+                }
+            }
+
+            impl<const B: usize> Real for Fixed<$t,B> {
+                // fails to compile when the integer part has no room for 3
+                const PI: Self = {
+                    assert!(std::f64::consts::PI * Self::SCALE < <$t>::MAX as f64,"Fixed::PI: not enough integer bits");
+                    Fixed((std::f64::consts::PI * Self::SCALE) as $t)
+                };
 
                 fn floor(self) -> Self {
-                    Fixed(self.0 & !(Self::ONE.0 - 1))
+                    Fixed(self.0 & !Self::FRACTION)
                 }
 
                 fn ceil(self) -> Self {
-                    self.floor() + Self::ONE
+                    let floor = self.floor();
+                    if floor == self {
+                        floor
+                    }
+                    else {
+                        floor + Self::ONE
+                    }
                 }
 
+                /// Round half away from zero.
                 fn round(self) -> Self { 
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.round();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    if self.0 < 0 {
+                        (self - Self::HALF).ceil()
+                    }
+                    else {
+                        (self + Self::HALF).floor()
+                    }
                 }
 
                 fn trunc(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.trunc();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    if self.0 < 0 {
+                        self.ceil()
+                    }
+                    else {
+                        self.floor()
+                    }
                 }
 
                 fn fract(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.fract();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    self - self.trunc()
                 }
 
+                /// `self` to the power `n`, or zero when `self` is not positive.
                 fn powf(self,n: Self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.powf((n.0 as f64) / one);
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    if self.0 <= 0 {
+                        Self::ZERO
+                    }
+                    else {
+                        (n * self.ln()).exp()
+                    }
                 }
 
+                /// Square root, or zero for negative numbers.
                 fn sqrt(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.sqrt();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    if self.0 <= 0 {
+                        return Self::ZERO;
+                    }
+                    // integer Newton iteration from above on the raw value times 2^B
+                    let bits = (<$t>::BITS - self.0.leading_zeros()) as usize + B;
+                    let shift = bits.div_ceil(2);
+                    let mut y = if shift >= (<$t>::BITS - 1) as usize { <$t>::MAX } else { 1 << shift };
+                    loop {
+                        let (q,_) = self.0.shl_div(y,B);
+                        let next = (y >> 1) + (q >> 1) + (y & q & 1);
+                        if next >= y {
+                            return Fixed(y);
+                        }
+                        y = next;
+                    }
                 }
 
+                /// Exponential, saturating to the maximum on overflow.
                 fn exp(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.exp();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    // split into k * ln(2) + r, with |r| <= ln(2) / 2
+                    let k = match self.checked_div(Self::LN_2) {
+                        Some(q) => q.round().0 >> B,
+                        None => return if self.0 < 0 { Self::ZERO } else { Fixed(<$t>::MAX) },
+                    };
+                    let r = Fixed(self.0.wrapping_sub(Self::LN_2.0.wrapping_mul(k)));
+                    let e = Self::exp_series(r);
+                    if k < 0 {
+                        Fixed(e.0.checked_shr((-k) as u32).unwrap_or(0))
+                    }
+                    else if (k as u32) < e.0.leading_zeros() {
+                        Fixed(e.0 << k)
+                    }
+                    else {
+                        Fixed(<$t>::MAX)
+                    }
                 }
 
                 fn exp2(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.exp2();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    (self * Self::LN_2).exp()
                 }
 
+                /// Natural logarithm, saturating to the minimum when `self` is not positive.
                 fn ln(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.ln();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    if self.0 <= 0 {
+                        return Fixed(<$t>::MIN);
+                    }
+                    // split into 2^e * m, with 1 <= m < 2
+                    let e = (<$t>::BITS - 1 - self.0.leading_zeros()) as $t - B as $t;
+                    let m = if e < 0 { Fixed(self.0 << -e) } else { Fixed(self.0 >> e) };
+                    // ln(m) = 2 * atanh((m - 1) / (m + 1))
+                    let z = (m - Self::ONE) / (m + Self::ONE);
+                    let z2 = z * z;
+                    let mut sum = z;
+                    let mut power = z;
+                    let mut n: $t = 1;
+                    loop {
+                        power *= z2;
+                        let term = power.0 / (2 * n + 1);
+                        if term == 0 {
+                            break;
+                        }
+                        sum.0 += term;
+                        n += 1;
+                    }
+                    Fixed(Self::LN_2.0 * e) + sum + sum
                 }
 
                 fn log(self,base: Self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.log((base.0 as f64) / one);
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    self.ln() / base.ln()
                 }
 
                 fn log2(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.log2();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    self.ln() / Self::LN_2
                 }
 
                 fn log10(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.log10();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    self.ln() / Self::LN_10
                 }
 
                 fn cbrt(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.cbrt();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    if self.0 == 0 {
+                        Self::ZERO
+                    }
+                    else {
+                        Fixed(self.abs().ln().0 / 3).exp().copysign(self)
+                    }
                 }
 
                 fn hypot(self,other: Self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.hypot((other.0 as f64) / one);
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    let a = self.abs();
+                    let b = other.abs();
+                    let (max,min) = if a > b { (a,b) } else { (b,a) };
+                    if max.0 == 0 {
+                        Self::ZERO
+                    }
+                    else {
+                        let r = min / max;
+                        max * (Self::ONE + r * r).sqrt()
+                    }
                 }
 
                 fn sin(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.sin();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    self.sin_cos().0
                 }
 
                 fn cos(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.cos();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    self.sin_cos().1
                 }
 
+                /// Tangent, saturating where the cosine rounds to zero.
                 fn tan(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.tan();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    let (sin,cos) = self.sin_cos();
+                    if cos.0 == 0 {
+                        if sin.0 < 0 { Fixed(<$t>::MIN) } else { Fixed(<$t>::MAX) }
+                    }
+                    else {
+                        sin.saturating_div(cos)
+                    }
                 }
 
                 fn asin(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.asin();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    self.atan2(((Self::ONE - self) * (Self::ONE + self)).sqrt())
                 }
 
                 fn acos(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.acos();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    ((Self::ONE - self) * (Self::ONE + self)).sqrt().atan2(self)
                 }
 
                 fn atan(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.atan();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    if self.abs() > Self::ONE {
+                        Self::FRAC_PI_2.copysign(self) - Self::atan_unit(Self::ONE / self)
+                    }
+                    else {
+                        Self::atan_unit(self)
+                    }
                 }
 
                 fn atan2(self,other: Self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.atan2((other.0 as f64) / one);
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    if other.0 == 0 {
+                        if self.0 == 0 { Self::ZERO } else { Self::FRAC_PI_2.copysign(self) }
+                    }
+                    else if self.abs() <= other.abs() {
+                        let a = Self::atan_unit(self / other);
+                        if other.0 > 0 {
+                            a
+                        }
+                        else if self.0 < 0 {
+                            a - Self::PI
+                        }
+                        else {
+                            a + Self::PI
+                        }
+                    }
+                    else {
+                        Self::FRAC_PI_2.copysign(self) - Self::atan_unit(other / self)
+                    }
                 }
 
                 fn sin_cos(self) -> (Self,Self) {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let s = x.sin();
-                    let c = x.cos();
-                    let ys = (s * one) as $t;
-                    let yc = (c * one) as $t;
-                    (Fixed(ys),Fixed(yc))
+                    // split into k * pi/2 + r, with |r| <= pi/4, the wrapping cancels out in r
+                    let k = (self / Self::FRAC_PI_2).round().0 >> B;
+                    let r = Fixed(self.0.wrapping_sub(Self::FRAC_PI_2.0.wrapping_mul(k)));
+                    let (sin,cos) = Self::sin_cos_series(r);
+                    match k & 3 {
+                        0 => (sin,cos),
+                        1 => (cos,-sin),
+                        2 => (-sin,-cos),
+                        _ => (-cos,sin),
+                    }
                 }
 
                 fn exp_m1(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.exp_m1();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    self.exp() - Self::ONE
                 }
 
                 fn ln_1p(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.ln_1p();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    (Self::ONE + self).ln()
                 }
 
                 fn sinh(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.sinh();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    let e = self.abs().exp();
+                    (e.saturating_sub(Self::ONE / e) * Self::HALF).copysign(self)
                 }
 
                 fn cosh(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.cosh();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    let e = self.abs().exp();
+                    (e * Self::HALF).saturating_add((Self::ONE / e) * Self::HALF)
                 }
 
                 fn tanh(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.tanh();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    let e = (-(self.abs() + self.abs())).exp();
+                    ((Self::ONE - e) / (Self::ONE + e)).copysign(self)
                 }
 
                 fn asinh(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.asinh();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    let a = self.abs();
+                    (a + a.hypot(Self::ONE)).ln().copysign(self)
                 }
 
+                /// Inverse hyperbolic cosine, or zero when `self` is smaller than one.
                 fn acosh(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.acosh();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    if self <= Self::ONE {
+                        Self::ZERO
+                    }
+                    else {
+                        (self + ((self - Self::ONE) * (self + Self::ONE)).sqrt()).ln()
+                    }
                 }
 
+                /// Inverse hyperbolic tangent, saturating outside (-1,1).
                 fn atanh(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.atanh();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    if self >= Self::ONE {
+                        Fixed(<$t>::MAX)
+                    }
+                    else if self <= -Self::ONE {
+                        Fixed(<$t>::MIN)
+                    }
+                    else {
+                        ((Self::ONE + self) / (Self::ONE - self)).ln() * Self::HALF
+                    }
                 }
 
                 fn inv(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.inv();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    Self::ONE / self
                 }
 
                 fn to_degrees(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.to_degrees();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    self * Self::DEGREES
                 }
 
                 fn to_radians(self) -> Self {
-                    let one = Self::ONE.0 as f64;
-                    let x = (self.0 as f64) / one;
-                    let r = x.to_radians();
-                    let y = (r * one) as $t;
-                    Fixed(y)
+                    self * Self::RADIANS
                 }
             }
         )+
     }
 }
 
-//fixed_impl_signed! { (i8,8) (i16,8) (i32,16) (i64,32) (i128,64) }
-fixed_impl_signed! { (i16,8) (i32,16) (i64,32) (i128,64) }
+fixed_real_impl! { i64 i128 }
+
+// smaller formats evaluate in Q31.32, so formats without integer bits like Q1.15 and Q0.7 work too
+macro_rules! fixed_real_via_impl {
+    ($($t:ty)+) => {
+        $(
+            impl<const B: usize> Fixed<$t,B> {
+                fn widen(self) -> Fixed<i64,32> {
+                    self.saturating_cast()
+                }
+
+                fn narrow(value: Fixed<i64,32>) -> Self {
+                    value.saturating_cast()
+                }
+            }
 
-//#[allow(non_camel_case_types)]
-//pub type u88 = Fixed<u8,8>;
+            impl<const B: usize> Real for Fixed<$t,B> {
+                // fails to compile when the integer part has no room for 3
+                const PI: Self = {
+                    assert!(std::f64::consts::PI * Self::SCALE < <$t>::MAX as f64,"Fixed::PI: not enough integer bits");
+                    Fixed((std::f64::consts::PI * Self::SCALE) as $t)
+                };
+
+                fn floor(self) -> Self {
+                    Self::narrow(self.widen().floor())
+                }
+
+                fn ceil(self) -> Self {
+                    Self::narrow(self.widen().ceil())
+                }
+
+                fn round(self) -> Self {
+                    Self::narrow(self.widen().round())
+                }
+
+                fn trunc(self) -> Self {
+                    Self::narrow(self.widen().trunc())
+                }
+
+                fn fract(self) -> Self {
+                    Self::narrow(self.widen().fract())
+                }
+
+                fn powf(self,n: Self) -> Self {
+                    Self::narrow(self.widen().powf(n.widen()))
+                }
+
+                fn sqrt(self) -> Self {
+                    Self::narrow(self.widen().sqrt())
+                }
+
+                fn exp(self) -> Self {
+                    Self::narrow(self.widen().exp())
+                }
+
+                fn exp2(self) -> Self {
+                    Self::narrow(self.widen().exp2())
+                }
+
+                fn ln(self) -> Self {
+                    Self::narrow(self.widen().ln())
+                }
+
+                fn log(self,base: Self) -> Self {
+                    Self::narrow(self.widen().log(base.widen()))
+                }
+
+                fn log2(self) -> Self {
+                    Self::narrow(self.widen().log2())
+                }
+
+                fn log10(self) -> Self {
+                    Self::narrow(self.widen().log10())
+                }
+
+                fn cbrt(self) -> Self {
+                    Self::narrow(self.widen().cbrt())
+                }
+
+                fn hypot(self,other: Self) -> Self {
+                    Self::narrow(self.widen().hypot(other.widen()))
+                }
+
+                fn sin(self) -> Self {
+                    Self::narrow(self.widen().sin())
+                }
+
+                fn cos(self) -> Self {
+                    Self::narrow(self.widen().cos())
+                }
+
+                fn tan(self) -> Self {
+                    Self::narrow(self.widen().tan())
+                }
+
+                fn asin(self) -> Self {
+                    Self::narrow(self.widen().asin())
+                }
+
+                fn acos(self) -> Self {
+                    Self::narrow(self.widen().acos())
+                }
+
+                fn atan(self) -> Self {
+                    Self::narrow(self.widen().atan())
+                }
+
+                fn atan2(self,other: Self) -> Self {
+                    Self::narrow(self.widen().atan2(other.widen()))
+                }
+
+                fn sin_cos(self) -> (Self,Self) {
+                    let (s,c) = self.widen().sin_cos();
+                    (Self::narrow(s),Self::narrow(c))
+                }
+
+                fn exp_m1(self) -> Self {
+                    Self::narrow(self.widen().exp_m1())
+                }
+
+                fn ln_1p(self) -> Self {
+                    Self::narrow(self.widen().ln_1p())
+                }
+
+                fn sinh(self) -> Self {
+                    Self::narrow(self.widen().sinh())
+                }
+
+                fn cosh(self) -> Self {
+                    Self::narrow(self.widen().cosh())
+                }
+
+                fn tanh(self) -> Self {
+                    Self::narrow(self.widen().tanh())
+                }
+
+                fn asinh(self) -> Self {
+                    Self::narrow(self.widen().asinh())
+                }
+
+                fn acosh(self) -> Self {
+                    Self::narrow(self.widen().acosh())
+                }
+
+                fn atanh(self) -> Self {
+                    Self::narrow(self.widen().atanh())
+                }
+
+                fn inv(self) -> Self {
+                    Self::narrow(self.widen().inv())
+                }
+
+                fn to_degrees(self) -> Self {
+                    Self::narrow(self.widen().to_degrees())
+                }
+
+                fn to_radians(self) -> Self {
+                    Self::narrow(self.widen().to_radians())
+                }
+            }
+        )+
+    }
+}
+
+fixed_real_via_impl! { i8 i16 i32 }
+
+#[allow(non_camel_case_types)]
+pub type u84 = Fixed<u8,4>;
+
+/// UQ0.8
+#[allow(non_camel_case_types)]
+pub type u88 = Fixed<u8,8>;
 
 #[allow(non_camel_case_types)]
 pub type u168 = Fixed<u16,8>;
@@ -521,15 +1230,31 @@ pub type u6432 = Fixed<u64,32>;
 #[allow(non_camel_case_types)]
 pub type u12864 = Fixed<u128,64>;
 
+#[allow(non_camel_case_types)]
+pub type i84 = Fixed<i8,4>;
+
+/// -0.5 to 0.5 in steps of 1/256
 #[allow(non_camel_case_types)]
 pub type i88 = Fixed<i8,8>;
 
+/// Q0.7
+#[allow(non_camel_case_types)]
+pub type i87 = Fixed<i8,7>;
+
 #[allow(non_camel_case_types)]
 pub type i168 = Fixed<i16,8>;
 
+/// Q1.15
+#[allow(non_camel_case_types)]
+pub type i1615 = Fixed<i16,15>;
+
 #[allow(non_camel_case_types)]
 pub type i3216 = Fixed<i32,16>;
 
+/// Q2.30
+#[allow(non_camel_case_types)]
+pub type i3230 = Fixed<i32,30>;
+
 #[allow(non_camel_case_types)]
 pub type i6432 = Fixed<i64,32>;
 
@@ -0,0 +1,753 @@
+use crate::*;
+
+/// Plane of points `p` where `n.dot(p) + d` is zero.
+///
+/// The normal points to the positive side. Distances are only true distances if `n` is a unit vector.
+#[derive(Copy,Clone,Debug)]
+pub struct Plane<T> {
+    pub n: Vec3<T>,
+    pub d: T,
+}
+
+/// Half-line from origin `o` in direction `d`.
+///
+/// `d` does not need to be normalized, intersection parameters `t` are in units of `d`.
+#[derive(Copy,Clone,Debug)]
+pub struct Ray<T> {
+    pub o: Vec3<T>,
+    pub d: Vec3<T>,
+}
+
+/// Line segment from `a` to `b`.
+#[derive(Copy,Clone,Debug)]
+pub struct Segment<T> {
+    pub a: Vec3<T>,
+    pub b: Vec3<T>,
+}
+
+/// Triangle with corners `a`, `b` and `c`, front facing when counterclockwise.
+#[derive(Copy,Clone,Debug)]
+pub struct Triangle<T> {
+    pub a: Vec3<T>,
+    pub b: Vec3<T>,
+    pub c: Vec3<T>,
+}
+
+/// Sphere around `c` with radius `r`.
+#[derive(Copy,Clone,Debug)]
+pub struct Sphere<T> {
+    pub c: Vec3<T>,
+    pub r: T,
+}
+
+/// Axis-aligned box from corner `min` to corner `max`.
+///
+/// A box with `min` larger than `max` on any axis is empty, [`Aabb::EMPTY`] is the starting point for growing a box
+/// around points.
+#[derive(Copy,Clone,Debug)]
+pub struct Aabb<T> {
+    pub min: Vec3<T>,
+    pub max: Vec3<T>,
+}
+
+/// Oriented box around `c` with half extents `e` along the rows of `axes`.
+///
+/// The rows of `axes` are the unit local X, Y and Z axes in world space.
+#[derive(Copy,Clone,Debug)]
+pub struct Obb<T> {
+    pub c: Vec3<T>,
+    pub axes: Mat3x3<T>,
+    pub e: Vec3<T>,
+}
+
+/// View frustum, as six planes with unit normals pointing inwards.
+///
+/// The planes are in the order left, right, bottom, top, near, far.
+#[derive(Copy,Clone,Debug)]
+pub struct Frustum<T> {
+    pub planes: [Plane<T>; 6],
+}
+
+macro_rules! plane_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Plane<$t> {
+                /// Plane through `p` with normal `n`.
+                pub fn from_point_normal(p: Vec3<$t>,n: Vec3<$t>) -> Self {
+                    Plane { n,d: -n.dot(p), }
+                }
+
+                /// Plane through `a`, `b` and `c`, with the normal pointing to the side where they are counterclockwise.
+                pub fn from_points(a: Vec3<$t>,b: Vec3<$t>,c: Vec3<$t>) -> Self {
+                    Plane::<$t>::from_point_normal(a,(b - a).cross(c - a).normalize())
+                }
+
+                /// Scale to a unit normal, or return the plane itself if the normal has zero length.
+                pub fn normalize(self) -> Self {
+                    let l = self.n.norm();
+                    if l != <$t>::ZERO {
+                        Plane { n: self.n / l,d: self.d / l, }
+                    }
+                    else {
+                        self
+                    }
+                }
+
+                /// Signed distance from `p`, positive on the side the normal points to.
+                pub fn distance(&self,p: Vec3<$t>) -> $t {
+                    self.n.dot(p) + self.d
+                }
+
+                /// Projection of `p` onto the plane.
+                pub fn closest_point(&self,p: Vec3<$t>) -> Vec3<$t> {
+                    p - self.n * (self.distance(p) / self.n.dot(self.n))
+                }
+            }
+        )+
+    }
+}
+
+plane_impl! { f32 f64 }
+
+macro_rules! ray_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Ray<$t> {
+                pub fn at(&self,t: $t) -> Vec3<$t> {
+                    self.o + self.d * t
+                }
+
+                /// Closest point on the ray to `p`.
+                pub fn closest_point(&self,p: Vec3<$t>) -> Vec3<$t> {
+                    let t = (p - self.o).dot(self.d) / self.d.dot(self.d);
+                    self.at(Unsigned::max(t,<$t>::ZERO))
+                }
+
+                /// Parameter `t` where the ray crosses `plane`, from either side.
+                pub fn intersect_plane(&self,plane: &Plane<$t>) -> Option<$t> {
+                    let den = plane.n.dot(self.d);
+                    if den == <$t>::ZERO {
+                        return None;
+                    }
+                    let t = -plane.distance(self.o) / den;
+                    if t >= <$t>::ZERO {
+                        Some(t)
+                    }
+                    else {
+                        None
+                    }
+                }
+
+                /// Parameters `t` where the ray enters and leaves `aabb`, by the slab method.
+                ///
+                /// The entry is 0 if the ray starts inside the box.
+                pub fn intersect_aabb(&self,aabb: &Aabb<$t>) -> Option<($t,$t)> {
+                    let o = [self.o.x,self.o.y,self.o.z];
+                    let d = [self.d.x,self.d.y,self.d.z];
+                    let min = [aabb.min.x,aabb.min.y,aabb.min.z];
+                    let max = [aabb.max.x,aabb.max.y,aabb.max.z];
+                    let mut near = <$t>::ZERO;
+                    let mut far = <$t>::INFINITY;
+                    for i in 0..3 {
+                        if d[i] == <$t>::ZERO {
+                            // parallel to the slab, so either always or never between its planes
+                            if (o[i] < min[i]) || (o[i] > max[i]) {
+                                return None;
+                            }
+                        }
+                        else {
+                            let inv = <$t>::ONE / d[i];
+                            let t0 = (min[i] - o[i]) * inv;
+                            let t1 = (max[i] - o[i]) * inv;
+                            let (t0,t1) = if t0 > t1 { (t1,t0) } else { (t0,t1) };
+                            near = Unsigned::max(near,t0);
+                            far = Unsigned::min(far,t1);
+                            if near > far {
+                                return None;
+                            }
+                        }
+                    }
+                    Some((near,far))
+                }
+
+                /// Parameter `t` where the ray first hits `sphere`, or 0 if it starts inside.
+                pub fn intersect_sphere(&self,sphere: &Sphere<$t>) -> Option<$t> {
+                    let m = self.o - sphere.c;
+                    let a = self.d.dot(self.d);
+                    let b = m.dot(self.d);
+                    let c = m.dot(m) - sphere.r * sphere.r;
+                    if (c > <$t>::ZERO) && (b > <$t>::ZERO) {
+                        return None;
+                    }
+                    let disc = b * b - a * c;
+                    if disc < <$t>::ZERO {
+                        return None;
+                    }
+                    Some(Unsigned::max((-b - disc.sqrt()) / a,<$t>::ZERO))
+                }
+
+                /// Parameter `t` and barycentric coordinates `u` and `v` (of `b` and `c`) where the ray hits `triangle`.
+                ///
+                /// Möller and Trumbore, "Fast, Minimum Storage Ray/Triangle Intersection". Both sides are hit, rays in the
+                /// plane of the triangle are not.
+                pub fn intersect_triangle(&self,triangle: &Triangle<$t>) -> Option<($t,$t,$t)> {
+                    let e1 = triangle.b - triangle.a;
+                    let e2 = triangle.c - triangle.a;
+                    let p = self.d.cross(e2);
+                    let det = e1.dot(p);
+                    if det.abs() <= <$t>::EPSILON * e1.norm() * e2.norm() * self.d.norm() {
+                        return None;
+                    }
+                    let inv = <$t>::ONE / det;
+                    let s = self.o - triangle.a;
+                    let u = s.dot(p) * inv;
+                    if !(<$t>::ZERO..=<$t>::ONE).contains(&u) {
+                        return None;
+                    }
+                    let q = s.cross(e1);
+                    let v = self.d.dot(q) * inv;
+                    if (v < <$t>::ZERO) || (u + v > <$t>::ONE) {
+                        return None;
+                    }
+                    let t = e2.dot(q) * inv;
+                    if t >= <$t>::ZERO {
+                        Some((t,u,v))
+                    }
+                    else {
+                        None
+                    }
+                }
+            }
+        )+
+    }
+}
+
+ray_impl! { f32 f64 }
+
+macro_rules! segment_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Segment<$t> {
+                // ray along the segment, with the segment from 0 to 1
+                fn ray(&self) -> Ray<$t> {
+                    Ray { o: self.a,d: self.b - self.a, }
+                }
+
+                pub fn length(&self) -> $t {
+                    self.a.distance(self.b)
+                }
+
+                /// Point at parameter `t`, `a` at 0 and `b` at 1.
+                pub fn at(&self,t: $t) -> Vec3<$t> {
+                    self.a.lerp(self.b,t)
+                }
+
+                /// Closest point on the segment to `p`.
+                pub fn closest_point(&self,p: Vec3<$t>) -> Vec3<$t> {
+                    let ab = self.b - self.a;
+                    let l = ab.dot(ab);
+                    if l == <$t>::ZERO {
+                        return self.a;
+                    }
+                    self.at(Unsigned::clamp((p - self.a).dot(ab) / l,<$t>::ZERO,<$t>::ONE))
+                }
+
+                /// Closest points on `self` and on `other` to each other.
+                pub fn closest_points(&self,other: &Segment<$t>) -> (Vec3<$t>,Vec3<$t>) {
+                    // Ericson, "Real-Time Collision Detection", 5.1.9
+                    let d1 = self.b - self.a;
+                    let d2 = other.b - other.a;
+                    let r = self.a - other.a;
+                    let a = d1.dot(d1);
+                    let e = d2.dot(d2);
+                    let f = d2.dot(r);
+                    if (a == <$t>::ZERO) && (e == <$t>::ZERO) {
+                        return (self.a,other.a);
+                    }
+                    let (s,t) = if a == <$t>::ZERO {
+                        (<$t>::ZERO,Unsigned::clamp(f / e,<$t>::ZERO,<$t>::ONE))
+                    }
+                    else {
+                        let c = d1.dot(r);
+                        if e == <$t>::ZERO {
+                            (Unsigned::clamp(-c / a,<$t>::ZERO,<$t>::ONE),<$t>::ZERO)
+                        }
+                        else {
+                            let b = d1.dot(d2);
+                            let den = a * e - b * b;
+                            let s = if den != <$t>::ZERO { Unsigned::clamp((b * f - c * e) / den,<$t>::ZERO,<$t>::ONE) } else { <$t>::ZERO };
+                            let t = (b * s + f) / e;
+                            if t < <$t>::ZERO {
+                                (Unsigned::clamp(-c / a,<$t>::ZERO,<$t>::ONE),<$t>::ZERO)
+                            }
+                            else if t > <$t>::ONE {
+                                (Unsigned::clamp((b - c) / a,<$t>::ZERO,<$t>::ONE),<$t>::ONE)
+                            }
+                            else {
+                                (s,t)
+                            }
+                        }
+                    };
+                    (self.at(s),other.at(t))
+                }
+
+                /// Parameter `t` where the segment crosses `plane`.
+                pub fn intersect_plane(&self,plane: &Plane<$t>) -> Option<$t> {
+                    self.ray().intersect_plane(plane).filter(|t| *t <= <$t>::ONE)
+                }
+
+                /// Parameters `t` where the segment enters and leaves `aabb`, clipped to the segment.
+                pub fn intersect_aabb(&self,aabb: &Aabb<$t>) -> Option<($t,$t)> {
+                    match self.ray().intersect_aabb(aabb) {
+                        Some((near,far)) if near <= <$t>::ONE => Some((near,Unsigned::min(far,<$t>::ONE))),
+                        _ => None,
+                    }
+                }
+
+                /// Parameter `t` and barycentric coordinates `u` and `v` where the segment hits `triangle`.
+                pub fn intersect_triangle(&self,triangle: &Triangle<$t>) -> Option<($t,$t,$t)> {
+                    self.ray().intersect_triangle(triangle).filter(|(t,_,_)| *t <= <$t>::ONE)
+                }
+            }
+        )+
+    }
+}
+
+segment_impl! { f32 f64 }
+
+macro_rules! triangle_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Triangle<$t> {
+                /// Unit normal of the front face.
+                pub fn normal(&self) -> Vec3<$t> {
+                    (self.b - self.a).cross(self.c - self.a).normalize()
+                }
+
+                pub fn area(&self) -> $t {
+                    0.5 * (self.b - self.a).cross(self.c - self.a).norm()
+                }
+
+                pub fn plane(&self) -> Plane<$t> {
+                    Plane::<$t>::from_points(self.a,self.b,self.c)
+                }
+
+                /// Barycentric coordinates `(u,v,w)` of `p` projected onto the plane, such that `p = u * a + v * b + w * c`.
+                pub fn barycentric(&self,p: Vec3<$t>) -> ($t,$t,$t) {
+                    let v0 = self.b - self.a;
+                    let v1 = self.c - self.a;
+                    let v2 = p - self.a;
+                    let d00 = v0.dot(v0);
+                    let d01 = v0.dot(v1);
+                    let d11 = v1.dot(v1);
+                    let d20 = v2.dot(v0);
+                    let d21 = v2.dot(v1);
+                    let den = d00 * d11 - d01 * d01;
+                    let v = (d11 * d20 - d01 * d21) / den;
+                    let w = (d00 * d21 - d01 * d20) / den;
+                    (<$t>::ONE - v - w,v,w)
+                }
+
+                /// Closest point on the triangle to `p`.
+                pub fn closest_point(&self,p: Vec3<$t>) -> Vec3<$t> {
+                    // Ericson, "Real-Time Collision Detection", 5.1.5
+                    let ab = self.b - self.a;
+                    let ac = self.c - self.a;
+                    let ap = p - self.a;
+                    let d1 = ab.dot(ap);
+                    let d2 = ac.dot(ap);
+                    if (d1 <= <$t>::ZERO) && (d2 <= <$t>::ZERO) {
+                        return self.a;
+                    }
+                    let bp = p - self.b;
+                    let d3 = ab.dot(bp);
+                    let d4 = ac.dot(bp);
+                    if (d3 >= <$t>::ZERO) && (d4 <= d3) {
+                        return self.b;
+                    }
+                    let vc = d1 * d4 - d3 * d2;
+                    if (vc <= <$t>::ZERO) && (d1 >= <$t>::ZERO) && (d3 <= <$t>::ZERO) {
+                        return self.a + ab * (d1 / (d1 - d3));
+                    }
+                    let cp = p - self.c;
+                    let d5 = ab.dot(cp);
+                    let d6 = ac.dot(cp);
+                    if (d6 >= <$t>::ZERO) && (d5 <= d6) {
+                        return self.c;
+                    }
+                    let vb = d5 * d2 - d1 * d6;
+                    if (vb <= <$t>::ZERO) && (d2 >= <$t>::ZERO) && (d6 <= <$t>::ZERO) {
+                        return self.a + ac * (d2 / (d2 - d6));
+                    }
+                    let va = d3 * d6 - d5 * d4;
+                    if (va <= <$t>::ZERO) && (d4 - d3 >= <$t>::ZERO) && (d5 - d6 >= <$t>::ZERO) {
+                        return self.b + (self.c - self.b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+                    }
+                    let den = <$t>::ONE / (va + vb + vc);
+                    self.a + ab * (vb * den) + ac * (vc * den)
+                }
+
+                pub fn aabb(&self) -> Aabb<$t> {
+                    Aabb { min: self.a.min(self.b).min(self.c),max: self.a.max(self.b).max(self.c), }
+                }
+            }
+        )+
+    }
+}
+
+triangle_impl! { f32 f64 }
+
+macro_rules! sphere_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Sphere<$t> {
+                /// Smallest sphere around `aabb`.
+                pub fn from_aabb(aabb: &Aabb<$t>) -> Self {
+                    Sphere { c: aabb.center(),r: aabb.extent().norm(), }
+                }
+
+                pub fn contains_point(&self,p: Vec3<$t>) -> bool {
+                    (p - self.c).norm_sqr() <= self.r * self.r
+                }
+
+                /// Whether `other` is completely inside.
+                pub fn contains_sphere(&self,other: &Sphere<$t>) -> bool {
+                    (other.r <= self.r) && ((other.c - self.c).norm() <= self.r - other.r)
+                }
+
+                pub fn intersects_sphere(&self,other: &Sphere<$t>) -> bool {
+                    let r = self.r + other.r;
+                    (other.c - self.c).norm_sqr() <= r * r
+                }
+
+                pub fn intersects_aabb(&self,aabb: &Aabb<$t>) -> bool {
+                    aabb.distance_sqr(self.c) <= self.r * self.r
+                }
+
+                /// Closest point on or in the sphere to `p`.
+                pub fn closest_point(&self,p: Vec3<$t>) -> Vec3<$t> {
+                    let v = p - self.c;
+                    let d = v.norm();
+                    if d <= self.r {
+                        p
+                    }
+                    else {
+                        self.c + v * (self.r / d)
+                    }
+                }
+
+                /// Smallest sphere around `self` and `other`.
+                pub fn merge(&self,other: &Sphere<$t>) -> Sphere<$t> {
+                    let v = other.c - self.c;
+                    let d = v.norm();
+                    if d + other.r <= self.r {
+                        *self
+                    }
+                    else if d + self.r <= other.r {
+                        *other
+                    }
+                    else {
+                        let r = 0.5 * (d + self.r + other.r);
+                        Sphere { c: self.c + v * ((r - self.r) / d),r, }
+                    }
+                }
+            }
+        )+
+    }
+}
+
+sphere_impl! { f32 f64 }
+
+macro_rules! aabb_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Aabb<$t> {
+                /// Empty box, which grows to exactly the first point or box added to it.
+                pub const EMPTY: Aabb<$t> = Aabb {
+                    min: Vec3 { x: <$t>::INFINITY,y: <$t>::INFINITY,z: <$t>::INFINITY, },
+                    max: Vec3 { x: <$t>::NEG_INFINITY,y: <$t>::NEG_INFINITY,z: <$t>::NEG_INFINITY, },
+                };
+
+                /// Box around center `c` with half extents `e`.
+                pub fn from_center_extent(c: Vec3<$t>,e: Vec3<$t>) -> Self {
+                    Aabb { min: c - e,max: c + e, }
+                }
+
+                /// Smallest box around `points`, or [`Aabb::EMPTY`].
+                pub fn from_points(points: &[Vec3<$t>]) -> Self {
+                    points.iter().fold(Aabb::<$t>::EMPTY,|aabb,p| aabb.grow(*p))
+                }
+
+                pub fn is_empty(&self) -> bool {
+                    (self.min.x > self.max.x) || (self.min.y > self.max.y) || (self.min.z > self.max.z)
+                }
+
+                pub fn center(&self) -> Vec3<$t> {
+                    (self.min + self.max) * 0.5
+                }
+
+                /// Half the size.
+                pub fn extent(&self) -> Vec3<$t> {
+                    (self.max - self.min) * 0.5
+                }
+
+                pub fn size(&self) -> Vec3<$t> {
+                    self.max - self.min
+                }
+
+                pub fn volume(&self) -> $t {
+                    let s = self.size();
+                    s.x * s.y * s.z
+                }
+
+                pub fn surface_area(&self) -> $t {
+                    let s = self.size();
+                    2.0 * (s.x * s.y + s.x * s.z + s.y * s.z)
+                }
+
+                /// Smallest box around `self` and `p`.
+                pub fn grow(&self,p: Vec3<$t>) -> Aabb<$t> {
+                    Aabb { min: self.min.min(p),max: self.max.max(p), }
+                }
+
+                /// Smallest box around `self` and `other`.
+                pub fn union(&self,other: &Aabb<$t>) -> Aabb<$t> {
+                    Aabb { min: self.min.min(other.min),max: self.max.max(other.max), }
+                }
+
+                /// Overlap of `self` and `other`, which is empty if they do not intersect.
+                pub fn intersection(&self,other: &Aabb<$t>) -> Aabb<$t> {
+                    Aabb { min: self.min.max(other.min),max: self.max.min(other.max), }
+                }
+
+                pub fn contains_point(&self,p: Vec3<$t>) -> bool {
+                    (p.x >= self.min.x) && (p.y >= self.min.y) && (p.z >= self.min.z)
+                    && (p.x <= self.max.x) && (p.y <= self.max.y) && (p.z <= self.max.z)
+                }
+
+                /// Whether `other` is completely inside.
+                pub fn contains_aabb(&self,other: &Aabb<$t>) -> bool {
+                    self.contains_point(other.min) && self.contains_point(other.max)
+                }
+
+                pub fn intersects_aabb(&self,other: &Aabb<$t>) -> bool {
+                    (self.min.x <= other.max.x) && (self.min.y <= other.max.y) && (self.min.z <= other.max.z)
+                    && (other.min.x <= self.max.x) && (other.min.y <= self.max.y) && (other.min.z <= self.max.z)
+                }
+
+                pub fn intersects_sphere(&self,sphere: &Sphere<$t>) -> bool {
+                    sphere.intersects_aabb(self)
+                }
+
+                /// Closest point in the box to `p`.
+                pub fn closest_point(&self,p: Vec3<$t>) -> Vec3<$t> {
+                    p.clamp(self.min,self.max)
+                }
+
+                /// Squared distance from `p` to the box, 0 inside.
+                pub fn distance_sqr(&self,p: Vec3<$t>) -> $t {
+                    (self.closest_point(p) - p).norm_sqr()
+                }
+
+                /// Box around the corners after affine transformation `m`.
+                pub fn transform(&self,m: &Mat4x4<$t>) -> Aabb<$t> {
+                    // Arvo, "Transforming Axis-Aligned Bounding Boxes"
+                    let c = self.center();
+                    let e = self.extent();
+                    let c = Vec3 {
+                        x: m.x.x * c.x + m.x.y * c.y + m.x.z * c.z + m.x.w,
+                        y: m.y.x * c.x + m.y.y * c.y + m.y.z * c.z + m.y.w,
+                        z: m.z.x * c.x + m.z.y * c.y + m.z.z * c.z + m.z.w,
+                    };
+                    let e = Vec3 {
+                        x: m.x.x.abs() * e.x + m.x.y.abs() * e.y + m.x.z.abs() * e.z,
+                        y: m.y.x.abs() * e.x + m.y.y.abs() * e.y + m.y.z.abs() * e.z,
+                        z: m.z.x.abs() * e.x + m.z.y.abs() * e.y + m.z.z.abs() * e.z,
+                    };
+                    Aabb::<$t>::from_center_extent(c,e)
+                }
+
+                /// Rectangle in normalized device coordinates around the box projected by `m`.
+                ///
+                /// Returns `None` if any corner is on or behind the eye, where the projection is not bounded.
+                pub fn project(&self,m: &Mat4x4<$t>) -> Option<Rect<$t>> {
+                    let mut min = Vec2 { x: <$t>::INFINITY,y: <$t>::INFINITY, };
+                    let mut max = Vec2 { x: <$t>::NEG_INFINITY,y: <$t>::NEG_INFINITY, };
+                    for i in 0..8 {
+                        let p = Vec4 {
+                            x: if (i & 1) != 0 { self.max.x } else { self.min.x },
+                            y: if (i & 2) != 0 { self.max.y } else { self.min.y },
+                            z: if (i & 4) != 0 { self.max.z } else { self.min.z },
+                            w: <$t>::ONE,
+                        };
+                        let p = *m * p;
+                        if p.w <= <$t>::ZERO {
+                            return None;
+                        }
+                        let p = Vec2 { x: p.x / p.w,y: p.y / p.w, };
+                        min = min.min(p);
+                        max = max.max(p);
+                    }
+                    Some(Rect { o: min,s: max - min, })
+                }
+            }
+        )+
+    }
+}
+
+aabb_impl! { f32 f64 }
+
+macro_rules! obb_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Obb<$t> {
+                /// Box `aabb` after rigid transformation `m`.
+                pub fn from_aabb(aabb: &Aabb<$t>,m: &Mat4x4<$t>) -> Self {
+                    let c = aabb.center();
+                    Obb {
+                        c: Vec3 {
+                            x: m.x.x * c.x + m.x.y * c.y + m.x.z * c.z + m.x.w,
+                            y: m.y.x * c.x + m.y.y * c.y + m.y.z * c.z + m.y.w,
+                            z: m.z.x * c.x + m.z.y * c.y + m.z.z * c.z + m.z.w,
+                        },
+                        // columns of the rotation are the local axes
+                        axes: Mat3x3 {
+                            x: Vec3 { x: m.x.x,y: m.y.x,z: m.z.x, },
+                            y: Vec3 { x: m.x.y,y: m.y.y,z: m.z.y, },
+                            z: Vec3 { x: m.x.z,y: m.y.z,z: m.z.z, },
+                        },
+                        e: aabb.extent(),
+                    }
+                }
+
+                /// Position of `p` in the local frame of the box.
+                fn local(&self,p: Vec3<$t>) -> Vec3<$t> {
+                    self.axes * (p - self.c)
+                }
+
+                pub fn contains_point(&self,p: Vec3<$t>) -> bool {
+                    let l = self.local(p);
+                    (l.x.abs() <= self.e.x) && (l.y.abs() <= self.e.y) && (l.z.abs() <= self.e.z)
+                }
+
+                /// Closest point in the box to `p`.
+                pub fn closest_point(&self,p: Vec3<$t>) -> Vec3<$t> {
+                    let l = self.local(p).clamp(-self.e,self.e);
+                    self.c + self.axes.x * l.x + self.axes.y * l.y + self.axes.z * l.z
+                }
+
+                /// Axis-aligned box around the oriented box.
+                pub fn aabb(&self) -> Aabb<$t> {
+                    let a = self.axes;
+                    let e = Vec3 {
+                        x: a.x.x.abs() * self.e.x + a.y.x.abs() * self.e.y + a.z.x.abs() * self.e.z,
+                        y: a.x.y.abs() * self.e.x + a.y.y.abs() * self.e.y + a.z.y.abs() * self.e.z,
+                        z: a.x.z.abs() * self.e.x + a.y.z.abs() * self.e.y + a.z.z.abs() * self.e.z,
+                    };
+                    Aabb::<$t>::from_center_extent(self.c,e)
+                }
+
+                /// Half the length of the projection of the box on `axis`.
+                fn radius(&self,axis: Vec3<$t>) -> $t {
+                    self.e.x * self.axes.x.dot(axis).abs() + self.e.y * self.axes.y.dot(axis).abs() + self.e.z * self.axes.z.dot(axis).abs()
+                }
+
+                /// Separating axis test against the 15 candidate axes.
+                pub fn intersects_obb(&self,other: &Obb<$t>) -> bool {
+                    let a = [self.axes.x,self.axes.y,self.axes.z];
+                    let b = [other.axes.x,other.axes.y,other.axes.z];
+                    let t = other.c - self.c;
+                    let separated = |axis: Vec3<$t>| t.dot(axis).abs() > self.radius(axis) + other.radius(axis);
+                    if a.iter().chain(b.iter()).any(|axis| separated(*axis)) {
+                        return false;
+                    }
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            let axis = a[i].cross(b[j]);
+                            // skip the cross product of parallel axes, those cases are covered by the face axes
+                            if (axis.norm_sqr() > <$t>::EPSILON) && separated(axis) {
+                                return false;
+                            }
+                        }
+                    }
+                    true
+                }
+            }
+        )+
+    }
+}
+
+obb_impl! { f32 f64 }
+
+macro_rules! frustum_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Frustum<$t> {
+                /// Frustum of view projection matrix `m`, in the space `m` projects from.
+                ///
+                /// Works for Vulkan style clip space with depth from 0 to 1, as from [`Mat4x4::perspective`] and
+                /// [`Mat4x4::orthographic`]. The planes of a matrix with depth going to infinity, as from
+                /// [`Mat4x4::perspective_infinite_reverse`], have a zero normal and a positive distance, so they contain
+                /// everything.
+                pub fn from_matrix(m: &Mat4x4<$t>) -> Self {
+                    // Gribb and Hartmann, "Fast Extraction of Viewing Frustum Planes from the World-View-Projection Matrix"
+                    let plane = |v: Vec4<$t>| Plane { n: Vec3 { x: v.x,y: v.y,z: v.z, },d: v.w, }.normalize();
+                    Frustum {
+                        planes: [
+                            plane(m.w + m.x),
+                            plane(m.w - m.x),
+                            plane(m.w + m.y),
+                            plane(m.w - m.y),
+                            plane(m.z),
+                            plane(m.w - m.z),
+                        ],
+                    }
+                }
+
+                pub fn contains_point(&self,p: Vec3<$t>) -> bool {
+                    self.planes.iter().all(|plane| plane.distance(p) >= <$t>::ZERO)
+                }
+
+                /// Whether `sphere` is completely inside.
+                pub fn contains_sphere(&self,sphere: &Sphere<$t>) -> bool {
+                    self.planes.iter().all(|plane| plane.distance(sphere.c) >= sphere.r)
+                }
+
+                /// Whether `aabb` is completely inside.
+                pub fn contains_aabb(&self,aabb: &Aabb<$t>) -> bool {
+                    self.planes.iter().all(|plane| plane.distance(Frustum::<$t>::corner(aabb,-plane.n)) >= <$t>::ZERO)
+                }
+
+                /// Culling test, `false` if `sphere` is certainly outside.
+                ///
+                /// Conservative: spheres outside near the corners and edges of the frustum can still pass.
+                pub fn intersects_sphere(&self,sphere: &Sphere<$t>) -> bool {
+                    self.planes.iter().all(|plane| plane.distance(sphere.c) >= -sphere.r)
+                }
+
+                /// Culling test, `false` if `aabb` is certainly outside.
+                ///
+                /// Conservative: boxes outside near the corners and edges of the frustum can still pass.
+                pub fn intersects_aabb(&self,aabb: &Aabb<$t>) -> bool {
+                    self.planes.iter().all(|plane| plane.distance(Frustum::<$t>::corner(aabb,plane.n)) >= <$t>::ZERO)
+                }
+
+                /// Culling test, `false` if `obb` is certainly outside.
+                pub fn intersects_obb(&self,obb: &Obb<$t>) -> bool {
+                    self.planes.iter().all(|plane| plane.distance(obb.c) >= -obb.radius(plane.n))
+                }
+
+                // corner of `aabb` furthest along `n`
+                fn corner(aabb: &Aabb<$t>,n: Vec3<$t>) -> Vec3<$t> {
+                    Vec3 {
+                        x: if n.x >= <$t>::ZERO { aabb.max.x } else { aabb.min.x },
+                        y: if n.y >= <$t>::ZERO { aabb.max.y } else { aabb.min.y },
+                        z: if n.z >= <$t>::ZERO { aabb.max.z } else { aabb.min.z },
+                    }
+                }
+            }
+        )+
+    }
+}
+
+frustum_impl! { f32 f64 }
@@ -0,0 +1,607 @@
+use {
+    crate::*,
+    std::{
+        cmp::{
+            Ordering,
+            PartialEq,
+            PartialOrd,
+        },
+        fmt::{
+            Debug,
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            Rem,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            RemAssign,
+            Neg,
+        },
+        str::FromStr,
+    },
+};
+
+/// IEEE 754 binary16 half precision float
+///
+/// Storage type for GPU buffers: 1 sign bit, 5 exponent bits and 10 mantissa bits. Arithmetic is done in `f32` and
+/// rounded back, which gives the correctly rounded result.
+#[allow(non_camel_case_types)]
+#[derive(Copy,Clone,Default)]
+#[repr(transparent)]
+pub struct f16(u16);
+
+/// bfloat16 brain float
+///
+/// Storage type for GPU buffers: the top half of an `f32`, so 1 sign bit, 8 exponent bits and 7 mantissa bits.
+/// Arithmetic is done in `f32` and rounded back, which gives the correctly rounded result.
+#[allow(non_camel_case_types)]
+#[derive(Copy,Clone,Default)]
+#[repr(transparent)]
+pub struct bf16(u16);
+
+// round to nearest even
+fn f32_to_f16_bits(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xFF) as i32;
+    let mantissa = bits & 0x7FFFFF;
+
+    // infinity and NaN, keep NaN quiet
+    if exponent == 0xFF {
+        return if mantissa == 0 { sign | 0x7C00 } else { sign | 0x7E00 | ((mantissa >> 13) as u16) };
+    }
+
+    let exponent = exponent - 127 + 15;
+    if exponent >= 0x1F {
+        return sign | 0x7C00;
+    }
+    let (result,rest,halfway) = if exponent <= 0 {
+        // subnormal, values below half the smallest subnormal round to zero
+        if exponent < -10 {
+            return sign;
+        }
+        let mantissa = mantissa | 0x800000;
+        let shift = (14 - exponent) as u32;
+        ((mantissa >> shift) as u16,mantissa & ((1 << shift) - 1),1 << (shift - 1))
+    }
+    else {
+        (((exponent as u16) << 10) | ((mantissa >> 13) as u16),mantissa & 0x1FFF,0x1000)
+    };
+
+    // a carry out of the mantissa correctly moves to the next exponent, or infinity
+    if (rest > halfway) || ((rest == halfway) && ((result & 1) != 0)) {
+        sign | (result + 1)
+    }
+    else {
+        sign | result
+    }
+}
+
+fn f16_bits_to_f32(bits: u16) -> f32 {
+    let sign = ((bits & 0x8000) as u32) << 16;
+    let exponent = ((bits >> 10) & 0x1F) as u32;
+    let mantissa = (bits & 0x3FF) as u32;
+    if exponent == 0x1F {
+        f32::from_bits(sign | 0x7F800000 | (mantissa << 13))
+    }
+    else if exponent == 0 {
+        // zero or subnormal, exact in f32
+        let value = (mantissa as f32) * f32::from_bits(0x33800000);
+        if sign != 0 { -value } else { value }
+    }
+    else {
+        f32::from_bits(sign | ((exponent + 112) << 23) | (mantissa << 13))
+    }
+}
+
+// round to nearest even
+fn f32_to_bf16_bits(value: f32) -> u16 {
+    let bits = value.to_bits();
+    if value.is_nan() {
+        ((bits >> 16) as u16) | 0x0040
+    }
+    else {
+        ((bits + 0x7FFF + ((bits >> 16) & 1)) >> 16) as u16
+    }
+}
+
+fn bf16_bits_to_f32(bits: u16) -> f32 {
+    f32::from_bits((bits as u32) << 16)
+}
+
+macro_rules! half_impl {
+    ($($t:ident $from:ident $to:ident)+) => {
+        $(
+            impl $t {
+                pub const fn from_bits(bits: u16) -> Self {
+                    $t(bits)
+                }
+
+                pub const fn to_bits(self) -> u16 {
+                    self.0
+                }
+
+                /// Round `value` to the nearest representable value, ties to even.
+                pub fn from_f32(value: f32) -> Self {
+                    $t($from(value))
+                }
+
+                /// Exact value as `f32`.
+                pub fn to_f32(self) -> f32 {
+                    $to(self.0)
+                }
+
+                /// Exact value as `f64`.
+                pub fn to_f64(self) -> f64 {
+                    $to(self.0) as f64
+                }
+
+                /// Convert `src` into `dst`, rounding to nearest even.
+                pub fn from_f32_slice(dst: &mut [$t],src: &[f32]) {
+                    assert!(dst.len() == src.len(),concat!(stringify!($t),"::from_f32_slice: length mismatch"));
+                    for (d,s) in dst.iter_mut().zip(src.iter()) {
+                        *d = $t($from(*s));
+                    }
+                }
+
+                /// Convert `src` into `dst`.
+                pub fn to_f32_slice(dst: &mut [f32],src: &[$t]) {
+                    assert!(dst.len() == src.len(),concat!(stringify!($t),"::to_f32_slice: length mismatch"));
+                    for (d,s) in dst.iter_mut().zip(src.iter()) {
+                        *d = $to(s.0);
+                    }
+                }
+
+                /// Convert `values` to a new vector, rounding to nearest even.
+                pub fn from_f32_vec(values: &[f32]) -> Vec<$t> {
+                    values.iter().map(|value| $t($from(*value))).collect()
+                }
+
+                /// Convert `values` to a new vector.
+                pub fn to_f32_vec(values: &[$t]) -> Vec<f32> {
+                    values.iter().map(|value| $to(value.0)).collect()
+                }
+
+                pub fn is_sign_negative(self) -> bool {
+                    (self.0 & 0x8000) != 0
+                }
+            }
+
+            impl Display for $t {
+                fn fmt(&self,f: &mut Formatter) -> Result {
+                    Display::fmt(&self.to_f32(),f)
+                }
+            }
+
+            impl Debug for $t {
+                fn fmt(&self,f: &mut Formatter) -> Result {
+                    Debug::fmt(&self.to_f32(),f)
+                }
+            }
+
+            impl FromStr for $t {
+                type Err = String;
+
+                /// Parse as `f32` and round.
+                fn from_str(s: &str) -> std::result::Result<Self,String> {
+                    let value = s.parse::<f32>().map_err(|error| format!("{}::from_str: {}",stringify!($t),error))?;
+                    Ok($t::from_f32(value))
+                }
+            }
+
+            impl From<$t> for f32 {
+                fn from(value: $t) -> Self {
+                    value.to_f32()
+                }
+            }
+
+            impl From<$t> for f64 {
+                fn from(value: $t) -> Self {
+                    value.to_f64()
+                }
+            }
+
+            impl From<f32> for $t {
+                fn from(value: f32) -> Self {
+                    $t::from_f32(value)
+                }
+            }
+
+            impl PartialEq for $t {
+                fn eq(&self,other: &Self) -> bool {
+                    self.to_f32() == other.to_f32()
+                }
+            }
+
+            impl PartialOrd for $t {
+                fn partial_cmp(&self,other: &Self) -> Option<Ordering> {
+                    self.to_f32().partial_cmp(&other.to_f32())
+                }
+            }
+
+            // half + half
+            impl Add<$t> for $t {
+                type Output = $t;
+                fn add(self,other: $t) -> Self::Output {
+                    $t::from_f32(self.to_f32() + other.to_f32())
+                }
+            }
+
+            // half += half
+            impl AddAssign<$t> for $t {
+                fn add_assign(&mut self,other: $t) {
+                    *self = *self + other;
+                }
+            }
+
+            // half - half
+            impl Sub<$t> for $t {
+                type Output = $t;
+                fn sub(self,other: $t) -> Self::Output {
+                    $t::from_f32(self.to_f32() - other.to_f32())
+                }
+            }
+
+            // half -= half
+            impl SubAssign<$t> for $t {
+                fn sub_assign(&mut self,other: $t) {
+                    *self = *self - other;
+                }
+            }
+
+            // half * half
+            impl Mul<$t> for $t {
+                type Output = $t;
+                fn mul(self,other: $t) -> Self::Output {
+                    $t::from_f32(self.to_f32() * other.to_f32())
+                }
+            }
+
+            // half *= half
+            impl MulAssign<$t> for $t {
+                fn mul_assign(&mut self,other: $t) {
+                    *self = *self * other;
+                }
+            }
+
+            // half / half
+            impl Div<$t> for $t {
+                type Output = $t;
+                fn div(self,other: $t) -> Self::Output {
+                    $t::from_f32(self.to_f32() / other.to_f32())
+                }
+            }
+
+            // half /= half
+            impl DivAssign<$t> for $t {
+                fn div_assign(&mut self,other: $t) {
+                    *self = *self / other;
+                }
+            }
+
+            // half % half
+            impl Rem<$t> for $t {
+                type Output = $t;
+                fn rem(self,other: $t) -> Self::Output {
+                    $t::from_f32(self.to_f32() % other.to_f32())
+                }
+            }
+
+            // half %= half
+            impl RemAssign<$t> for $t {
+                fn rem_assign(&mut self,other: $t) {
+                    *self = *self % other;
+                }
+            }
+
+            // -half
+            impl Neg for $t {
+                type Output = $t;
+                fn neg(self) -> Self::Output {
+                    $t(self.0 ^ 0x8000)
+                }
+            }
+
+            impl Unsigned for $t {
+                const MIN: Self = $t(<$t as Unsigned>::MAX.0 | 0x8000);
+                const MAX: Self = $t(<$t as Float>::INFINITY.0 - 1);
+
+                fn div_euclid(self,other: Self) -> Self {
+                    $t::from_f32(self.to_f32().div_euclid(other.to_f32()))
+                }
+
+                fn rem_euclid(self,other: Self) -> Self {
+                    $t::from_f32(self.to_f32().rem_euclid(other.to_f32()))
+                }
+
+                fn min(self,other: Self) -> Self {
+                    if other < self {
+                        other
+                    }
+                    else {
+                        self
+                    }
+                }
+
+                fn max(self,other: Self) -> Self {
+                    if other > self {
+                        other
+                    }
+                    else {
+                        self
+                    }
+                }
+
+                fn clamp(self,min: Self,max: Self) -> Self {
+                    if max < self {
+                        max
+                    }
+                    else if min > self {
+                        min
+                    }
+                    else {
+                        self
+                    }
+                }
+
+                /// Fused in `f32`, rounded once.
+                fn mul_add(self,b: Self,c: Self) -> Self {
+                    $t::from_f32(self.to_f32().mul_add(b.to_f32(),c.to_f32()))
+                }
+
+                fn powi(self,n: i32) -> Self {
+                    $t::from_f32(self.to_f32().powi(n))
+                }
+
+                fn checked_add(self,other: Self) -> Option<Self> {
+                    let result = self + other;
+                    if result.is_finite() || !(self.is_finite() && other.is_finite()) { Some(result) } else { None }
+                }
+
+                fn checked_sub(self,other: Self) -> Option<Self> {
+                    let result = self - other;
+                    if result.is_finite() || !(self.is_finite() && other.is_finite()) { Some(result) } else { None }
+                }
+
+                fn checked_mul(self,other: Self) -> Option<Self> {
+                    let result = self * other;
+                    if result.is_finite() || !(self.is_finite() && other.is_finite()) { Some(result) } else { None }
+                }
+
+                fn checked_div(self,other: Self) -> Option<Self> {
+                    let result = self / other;
+                    if result.is_finite() || !(self.is_finite() && other.is_finite()) { Some(result) } else { None }
+                }
+
+                fn saturating_add(self,other: Self) -> Self {
+                    let result = self + other;
+                    if result.is_infinite() && self.is_finite() && other.is_finite() {
+                        if result > Self::ZERO { <Self as Unsigned>::MAX } else { <Self as Unsigned>::MIN }
+                    }
+                    else {
+                        result
+                    }
+                }
+
+                fn saturating_sub(self,other: Self) -> Self {
+                    let result = self - other;
+                    if result.is_infinite() && self.is_finite() && other.is_finite() {
+                        if result > Self::ZERO { <Self as Unsigned>::MAX } else { <Self as Unsigned>::MIN }
+                    }
+                    else {
+                        result
+                    }
+                }
+
+                fn saturating_mul(self,other: Self) -> Self {
+                    let result = self * other;
+                    if result.is_infinite() && self.is_finite() && other.is_finite() {
+                        if result > Self::ZERO { <Self as Unsigned>::MAX } else { <Self as Unsigned>::MIN }
+                    }
+                    else {
+                        result
+                    }
+                }
+
+                fn saturating_div(self,other: Self) -> Self {
+                    let result = self / other;
+                    if result.is_infinite() && self.is_finite() && other.is_finite() {
+                        if result > Self::ZERO { <Self as Unsigned>::MAX } else { <Self as Unsigned>::MIN }
+                    }
+                    else {
+                        result
+                    }
+                }
+
+                fn wrapping_add(self,other: Self) -> Self {
+                    self + other
+                }
+
+                fn wrapping_sub(self,other: Self) -> Self {
+                    self - other
+                }
+
+                fn wrapping_mul(self,other: Self) -> Self {
+                    self * other
+                }
+
+                fn wrapping_div(self,other: Self) -> Self {
+                    self / other
+                }
+            }
+
+            impl Signed for $t {
+                fn abs(self) -> Self {
+                    $t(self.0 & 0x7FFF)
+                }
+
+                fn signum(self) -> Self {
+                    if self.is_nan() {
+                        self
+                    }
+                    else {
+                        $t(<$t>::ONE.0 | (self.0 & 0x8000))
+                    }
+                }
+
+                fn is_negative(self) -> bool {
+                    self < Self::ZERO
+                }
+
+                fn copysign(self,sign: Self) -> Self {
+                    $t((self.0 & 0x7FFF) | (sign.0 & 0x8000))
+                }
+            }
+
+            impl Real for $t {
+                const PI: Self = <$t>::PI;
+                fn floor(self) -> Self { $t::from_f32(self.to_f32().floor()) }
+                fn ceil(self) -> Self { $t::from_f32(self.to_f32().ceil()) }
+                fn round(self) -> Self { $t::from_f32(self.to_f32().round()) }
+                fn trunc(self) -> Self { $t::from_f32(self.to_f32().trunc()) }
+                fn fract(self) -> Self { $t::from_f32(self.to_f32().fract()) }
+                fn powf(self,n: Self) -> Self { $t::from_f32(self.to_f32().powf(n.to_f32())) }
+                fn sqrt(self) -> Self { $t::from_f32(self.to_f32().sqrt()) }
+                fn exp(self) -> Self { $t::from_f32(self.to_f32().exp()) }
+                fn exp2(self) -> Self { $t::from_f32(self.to_f32().exp2()) }
+                fn ln(self) -> Self { $t::from_f32(self.to_f32().ln()) }
+                fn log(self,base: Self) -> Self { $t::from_f32(self.to_f32().log(base.to_f32())) }
+                fn log2(self) -> Self { $t::from_f32(self.to_f32().log2()) }
+                fn log10(self) -> Self { $t::from_f32(self.to_f32().log10()) }
+                fn cbrt(self) -> Self { $t::from_f32(self.to_f32().cbrt()) }
+                fn hypot(self,other: Self) -> Self { $t::from_f32(self.to_f32().hypot(other.to_f32())) }
+                fn sin(self) -> Self { $t::from_f32(self.to_f32().sin()) }
+                fn cos(self) -> Self { $t::from_f32(self.to_f32().cos()) }
+                fn tan(self) -> Self { $t::from_f32(self.to_f32().tan()) }
+                fn asin(self) -> Self { $t::from_f32(self.to_f32().asin()) }
+                fn acos(self) -> Self { $t::from_f32(self.to_f32().acos()) }
+                fn atan(self) -> Self { $t::from_f32(self.to_f32().atan()) }
+                fn atan2(self,other: Self) -> Self { $t::from_f32(self.to_f32().atan2(other.to_f32())) }
+                fn sin_cos(self) -> (Self,Self) { let (s,c) = self.to_f32().sin_cos(); ($t::from_f32(s),$t::from_f32(c)) }
+                fn exp_m1(self) -> Self { $t::from_f32(self.to_f32().exp_m1()) }
+                fn ln_1p(self) -> Self { $t::from_f32(self.to_f32().ln_1p()) }
+                fn sinh(self) -> Self { $t::from_f32(self.to_f32().sinh()) }
+                fn cosh(self) -> Self { $t::from_f32(self.to_f32().cosh()) }
+                fn tanh(self) -> Self { $t::from_f32(self.to_f32().tanh()) }
+                fn asinh(self) -> Self { $t::from_f32(self.to_f32().asinh()) }
+                fn acosh(self) -> Self { $t::from_f32(self.to_f32().acosh()) }
+                fn atanh(self) -> Self { $t::from_f32(self.to_f32().atanh()) }
+                fn inv(self) -> Self { $t::from_f32(1.0 / self.to_f32()) }
+                fn to_degrees(self) -> Self { $t::from_f32(self.to_f32().to_degrees()) }
+                fn to_radians(self) -> Self { $t::from_f32(self.to_f32().to_radians()) }
+            }
+
+            impl Cast<f32> for $t {
+                fn checked_cast(self) -> Option<f32> {
+                    Some(self.to_f32())
+                }
+
+                fn saturating_cast(self) -> f32 {
+                    self.to_f32()
+                }
+
+                fn lossy_cast(self) -> f32 {
+                    self.to_f32()
+                }
+            }
+
+            impl Cast<f64> for $t {
+                fn checked_cast(self) -> Option<f64> {
+                    Some(self.to_f64())
+                }
+
+                fn saturating_cast(self) -> f64 {
+                    self.to_f64()
+                }
+
+                fn lossy_cast(self) -> f64 {
+                    self.to_f64()
+                }
+            }
+
+            impl Cast<$t> for f32 {
+                /// Round to nearest even, or `None` if a finite value overflows to infinity.
+                fn checked_cast(self) -> Option<$t> {
+                    let value = $t::from_f32(self);
+                    if value.is_infinite() && self.is_finite() { None } else { Some(value) }
+                }
+
+                fn saturating_cast(self) -> $t {
+                    let value = $t::from_f32(self);
+                    if value.is_infinite() && self.is_finite() {
+                        if self > 0.0 { <$t as Unsigned>::MAX } else { <$t as Unsigned>::MIN }
+                    }
+                    else {
+                        value
+                    }
+                }
+
+                fn lossy_cast(self) -> $t {
+                    $t::from_f32(self)
+                }
+            }
+        )+
+    }
+}
+
+half_impl! {
+    f16 f32_to_f16_bits f16_bits_to_f32
+    bf16 f32_to_bf16_bits bf16_bits_to_f32
+}
+
+macro_rules! half_float_impl {
+    ($($t:ident { $digits:expr,$mantissa_digits:expr,$epsilon:expr,$min_positive:expr,$min_exp:expr,$max_exp:expr,$min_10_exp:expr,$max_10_exp:expr,$nan:expr,$infinity:expr,$one:expr,$pi:expr })+) => {
+        $(
+            impl $t {
+                const PI: Self = $t($pi);
+            }
+
+            impl Zero for $t {
+                const ZERO: Self = $t(0);
+            }
+
+            impl One for $t {
+                const ONE: Self = $t($one);
+            }
+
+            impl Float for $t {
+                const DIGITS: u32 = $digits;
+                const MANTISSA_DIGITS: u32 = $mantissa_digits;
+                const EPSILON: Self = $t($epsilon);
+                const MIN_POSITIVE: Self = $t($min_positive);
+                const MIN_EXP: i32 = $min_exp;
+                const MAX_EXP: i32 = $max_exp;
+                const MIN_10_EXP: i32 = $min_10_exp;
+                const MAX_10_EXP: i32 = $max_10_exp;
+                const NAN: Self = $t($nan);
+                const INFINITY: Self = $t($infinity);
+                const NEG_INFINITY: Self = $t($infinity | 0x8000);
+                const RADIX: u32 = 2;
+                fn is_nan(self) -> bool { (self.0 & 0x7FFF) > $infinity }
+                fn is_infinite(self) -> bool { (self.0 & 0x7FFF) == $infinity }
+                fn is_finite(self) -> bool { (self.0 & $infinity) != $infinity }
+                fn is_subnormal(self) -> bool { ((self.0 & $infinity) == 0) && ((self.0 & 0x7FFF) != 0) }
+                fn is_normal(self) -> bool { ((self.0 & $infinity) != 0) && ((self.0 & $infinity) != $infinity) }
+                fn total_cmp(&self,other: &Self) -> Ordering {
+                    // flip the magnitude bits of negative values, so the bits compare as signed integers
+                    let a = self.0 as i16;
+                    let b = other.0 as i16;
+                    (a ^ ((((a >> 15) as u16) >> 1) as i16)).cmp(&(b ^ ((((b >> 15) as u16) >> 1) as i16)))
+                }
+            }
+        )+
+    }
+}
+
+half_float_impl! {
+    f16 { 3,11,0x1400,0x0400,-13,16,-4,4,0x7E00,0x7C00,0x3C00,0x4248 }
+    bf16 { 2,8,0x3C00,0x0080,-125,128,-37,38,0x7FC0,0x7F80,0x3F80,0x4049 }
+}
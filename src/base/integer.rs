@@ -0,0 +1,159 @@
+use {
+    crate::*,
+    std::convert::TryFrom,
+};
+
+/// Integer-only helpers, mostly for sizing dispatches and aligning buffers.
+pub trait Integer: Unsigned {
+    /// Greatest common divisor, never negative. `gcd(0,0)` is 0.
+    fn gcd(self,other: Self) -> Self;
+
+    /// Least common multiple, never negative. `lcm(0,x)` is 0.
+    fn lcm(self,other: Self) -> Self;
+
+    /// Integer square root, rounded down. Panics on negative numbers.
+    fn isqrt(self) -> Self;
+
+    /// Base 2 logarithm, rounded down. Panics if `self <= 0`.
+    fn ilog2(self) -> u32;
+
+    /// Base 10 logarithm, rounded down. Panics if `self <= 0`.
+    fn ilog10(self) -> u32;
+
+    /// Smallest power of two greater than or equal to `self`. Anything below 1 gives 1.
+    fn next_power_of_two(self) -> Self;
+
+    /// Division rounded towards positive infinity.
+    fn div_ceil(self,other: Self) -> Self;
+
+    /// Smallest multiple of `other` greater than or equal to `self` (or less than or equal to, if `other` is negative).
+    fn next_multiple_of(self,other: Self) -> Self;
+}
+
+// forward to the std methods by path, which needs Rust 1.84 for isqrt, on older toolchains the call would resolve
+// to this trait and recurse, hence rust-version in Cargo.toml
+macro_rules! integer_unsigned_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Integer for $t {
+                fn gcd(self,other: Self) -> Self {
+                    let mut a = self;
+                    let mut b = other;
+                    while b != 0 {
+                        let r = a % b;
+                        a = b;
+                        b = r;
+                    }
+                    a
+                }
+
+                fn lcm(self,other: Self) -> Self {
+                    if (self == 0) || (other == 0) {
+                        0
+                    }
+                    else {
+                        (self / Integer::gcd(self,other)) * other
+                    }
+                }
+
+                fn isqrt(self) -> Self {
+                    <$t>::isqrt(self)
+                }
+
+                fn ilog2(self) -> u32 {
+                    <$t>::ilog2(self)
+                }
+
+                fn ilog10(self) -> u32 {
+                    <$t>::ilog10(self)
+                }
+
+                fn next_power_of_two(self) -> Self {
+                    <$t>::next_power_of_two(self)
+                }
+
+                fn div_ceil(self,other: Self) -> Self {
+                    <$t>::div_ceil(self,other)
+                }
+
+                fn next_multiple_of(self,other: Self) -> Self {
+                    <$t>::next_multiple_of(self,other)
+                }
+            }
+        )+
+    }
+}
+
+integer_unsigned_impl! { u8 u16 u32 u64 u128 usize }
+
+macro_rules! integer_signed_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Integer for $t {
+                fn gcd(self,other: Self) -> Self {
+                    let g = Integer::gcd(self.unsigned_abs(),other.unsigned_abs());
+                    <$t>::try_from(g).expect("Integer::gcd: overflow")
+                }
+
+                fn lcm(self,other: Self) -> Self {
+                    if (self == 0) || (other == 0) {
+                        0
+                    }
+                    else {
+                        ((self / Integer::gcd(self,other)) * other).abs()
+                    }
+                }
+
+                fn isqrt(self) -> Self {
+                    <$t>::isqrt(self)
+                }
+
+                fn ilog2(self) -> u32 {
+                    <$t>::ilog2(self)
+                }
+
+                fn ilog10(self) -> u32 {
+                    <$t>::ilog10(self)
+                }
+
+                fn next_power_of_two(self) -> Self {
+                    if self <= 1 {
+                        1
+                    }
+                    else {
+                        let shift = <$t>::BITS - (self - 1).leading_zeros();
+                        assert!(shift < <$t>::BITS - 1,"Integer::next_power_of_two: overflow");
+                        1 << shift
+                    }
+                }
+
+                fn div_ceil(self,other: Self) -> Self {
+                    let q = self / other;
+                    let r = self % other;
+                    if ((r > 0) && (other > 0)) || ((r < 0) && (other < 0)) {
+                        q + 1
+                    }
+                    else {
+                        q
+                    }
+                }
+
+                fn next_multiple_of(self,other: Self) -> Self {
+                    if other == -1 {
+                        return self;
+                    }
+                    let r = self % other;
+                    let m = if ((r > 0) && (other < 0)) || ((r < 0) && (other > 0)) { r + other } else { r };
+                    if m == 0 {
+                        self
+                    }
+                    else {
+                        self + (other - m)
+                    }
+                }
+            }
+        )+
+    }
+}
+
+integer_signed_impl! { i8 i16 i32 i64 i128 isize }
@@ -1,6 +1,743 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+            Index,
+            IndexMut,
+        },
+    },
+};
+
+/// Dynamically sized matrix.
+///
+/// Stores `r` rows of `c` columns each in `v`, row after row.
 #[derive(Clone,Debug)]
 pub struct Mat<T> {
     pub r: usize,
     pub c: usize,
     pub v: Vec<T>,
 }
+
+/// LU decomposition with partial pivoting, see [`Mat::lu`].
+#[derive(Clone,Debug)]
+pub struct Lu<T> {
+    /// unit lower triangle L below the diagonal, upper triangle U on and above it
+    pub lu: Mat<T>,
+    /// row permutation, row `i` of LU is row `p[i]` of the original matrix
+    pub p: Vec<usize>,
+    /// sign of the permutation
+    pub sign: T,
+}
+
+/// Householder QR decomposition, see [`Mat::qr`].
+#[derive(Clone,Debug)]
+pub struct Qr<T> {
+    /// Householder vectors on and below the diagonal, strict upper triangle of R above it
+    pub qr: Mat<T>,
+    /// diagonal of R
+    pub rdiag: Vec<T>,
+}
+
+/// Cholesky decomposition, see [`Mat::cholesky`].
+#[derive(Clone,Debug)]
+pub struct Cholesky<T> {
+    /// lower triangle L, such that the matrix is L * L^T
+    pub l: Mat<T>,
+}
+
+macro_rules! mat_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Mat<$t> {
+                pub fn zero(r: usize,c: usize) -> Self {
+                    Mat {
+                        r,
+                        c,
+                        v: vec![<$t>::ZERO; r * c],
+                    }
+                }
+
+                pub fn identity(n: usize) -> Self {
+                    let mut m = Mat::<$t>::zero(n,n);
+                    for i in 0..n {
+                        m[(i,i)] = <$t>::ONE;
+                    }
+                    m
+                }
+
+                pub fn from_vec(r: usize,c: usize,v: Vec<$t>) -> Self {
+                    assert_eq!(v.len(),r * c,"Mat::from_vec: {} elements do not fit {}x{}",v.len(),r,c);
+                    Mat { r,c,v, }
+                }
+
+                pub fn row(&self,i: usize) -> &[$t] {
+                    &self.v[i * self.c..(i + 1) * self.c]
+                }
+
+                pub fn transpose(&self) -> Mat<$t> {
+                    let mut m = Mat::<$t>::zero(self.c,self.r);
+                    for i in 0..self.r {
+                        for j in 0..self.c {
+                            m[(j,i)] = self[(i,j)];
+                        }
+                    }
+                    m
+                }
+
+                /// Multiply with column vector `v`.
+                pub fn mul_vec(&self,v: &[$t]) -> Vec<$t> {
+                    assert_eq!(self.c,v.len(),"Mat::mul_vec: {}x{} matrix times {} vector",self.r,self.c,v.len());
+                    (0..self.r).map(|i| {
+                        let mut s = <$t>::ZERO;
+                        for j in 0..self.c {
+                            s += self[(i,j)] * v[j];
+                        }
+                        s
+                    }).collect()
+                }
+            }
+
+            impl Index<(usize,usize)> for Mat<$t> {
+                type Output = $t;
+                fn index(&self,index: (usize,usize)) -> &$t {
+                    &self.v[index.0 * self.c + index.1]
+                }
+            }
+
+            impl IndexMut<(usize,usize)> for Mat<$t> {
+                fn index_mut(&mut self,index: (usize,usize)) -> &mut $t {
+                    &mut self.v[index.0 * self.c + index.1]
+                }
+            }
+
+            impl PartialEq for Mat<$t> {
+                fn eq(&self,other: &Self) -> bool {
+                    (self.r == other.r) && (self.c == other.c) && (self.v == other.v)
+                }
+            }
+
+            impl Display for Mat<$t> {
+                fn fmt(&self,f: &mut Formatter) -> Result {
+                    write!(f,"[")?;
+                    for i in 0..self.r {
+                        if i > 0 {
+                            write!(f,",")?;
+                        }
+                        write!(f,"(")?;
+                        for j in 0..self.c {
+                            if j > 0 {
+                                write!(f,",")?;
+                            }
+                            write!(f,"{}",self[(i,j)])?;
+                        }
+                        write!(f,")")?;
+                    }
+                    write!(f,"]")
+                }
+            }
+
+            // matrix + matrix
+            impl Add<&Mat<$t>> for &Mat<$t> {
+                type Output = Mat<$t>;
+                fn add(self,other: &Mat<$t>) -> Self::Output {
+                    assert!((self.r == other.r) && (self.c == other.c),"Mat::add: {}x{} plus {}x{}",self.r,self.c,other.r,other.c);
+                    Mat {
+                        r: self.r,
+                        c: self.c,
+                        v: self.v.iter().zip(other.v.iter()).map(|(a,b)| *a + *b).collect(),
+                    }
+                }
+            }
+
+            impl Add<Mat<$t>> for Mat<$t> {
+                type Output = Mat<$t>;
+                fn add(self,other: Mat<$t>) -> Self::Output {
+                    &self + &other
+                }
+            }
+
+            // matrix += matrix
+            impl AddAssign<&Mat<$t>> for Mat<$t> {
+                fn add_assign(&mut self,other: &Mat<$t>) {
+                    assert!((self.r == other.r) && (self.c == other.c),"Mat::add_assign: {}x{} plus {}x{}",self.r,self.c,other.r,other.c);
+                    self.v.iter_mut().zip(other.v.iter()).for_each(|(a,b)| *a += *b);
+                }
+            }
+
+            // matrix - matrix
+            impl Sub<&Mat<$t>> for &Mat<$t> {
+                type Output = Mat<$t>;
+                fn sub(self,other: &Mat<$t>) -> Self::Output {
+                    assert!((self.r == other.r) && (self.c == other.c),"Mat::sub: {}x{} minus {}x{}",self.r,self.c,other.r,other.c);
+                    Mat {
+                        r: self.r,
+                        c: self.c,
+                        v: self.v.iter().zip(other.v.iter()).map(|(a,b)| *a - *b).collect(),
+                    }
+                }
+            }
+
+            impl Sub<Mat<$t>> for Mat<$t> {
+                type Output = Mat<$t>;
+                fn sub(self,other: Mat<$t>) -> Self::Output {
+                    &self - &other
+                }
+            }
+
+            // matrix -= matrix
+            impl SubAssign<&Mat<$t>> for Mat<$t> {
+                fn sub_assign(&mut self,other: &Mat<$t>) {
+                    assert!((self.r == other.r) && (self.c == other.c),"Mat::sub_assign: {}x{} minus {}x{}",self.r,self.c,other.r,other.c);
+                    self.v.iter_mut().zip(other.v.iter()).for_each(|(a,b)| *a -= *b);
+                }
+            }
+
+            // scalar * matrix
+            impl Mul<Mat<$t>> for $t {
+                type Output = Mat<$t>;
+                fn mul(self,mut other: Mat<$t>) -> Self::Output {
+                    other.v.iter_mut().for_each(|a| *a *= self);
+                    other
+                }
+            }
+
+            // matrix * scalar
+            impl Mul<$t> for Mat<$t> {
+                type Output = Mat<$t>;
+                fn mul(mut self,other: $t) -> Self::Output {
+                    self.v.iter_mut().for_each(|a| *a *= other);
+                    self
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<&Mat<$t>> for &Mat<$t> {
+                type Output = Mat<$t>;
+                fn mul(self,other: &Mat<$t>) -> Self::Output {
+                    assert_eq!(self.c,other.r,"Mat::mul: {}x{} times {}x{}",self.r,self.c,other.r,other.c);
+                    let mut m = Mat::<$t>::zero(self.r,other.c);
+                    for i in 0..self.r {
+                        for k in 0..self.c {
+                            let a = self[(i,k)];
+                            for j in 0..other.c {
+                                m[(i,j)] += a * other[(k,j)];
+                            }
+                        }
+                    }
+                    m
+                }
+            }
+
+            impl Mul<Mat<$t>> for Mat<$t> {
+                type Output = Mat<$t>;
+                fn mul(self,other: Mat<$t>) -> Self::Output {
+                    &self * &other
+                }
+            }
+
+            // matrix *= scalar
+            impl MulAssign<$t> for Mat<$t> {
+                fn mul_assign(&mut self,other: $t) {
+                    self.v.iter_mut().for_each(|a| *a *= other);
+                }
+            }
+
+            // matrix / scalar
+            impl Div<$t> for Mat<$t> {
+                type Output = Mat<$t>;
+                fn div(mut self,other: $t) -> Self::Output {
+                    self.v.iter_mut().for_each(|a| *a /= other);
+                    self
+                }
+            }
+
+            // matrix /= scalar
+            impl DivAssign<$t> for Mat<$t> {
+                fn div_assign(&mut self,other: $t) {
+                    self.v.iter_mut().for_each(|a| *a /= other);
+                }
+            }
+
+            // -matrix
+            impl Neg for Mat<$t> {
+                type Output = Mat<$t>;
+                fn neg(mut self) -> Self::Output {
+                    self.v.iter_mut().for_each(|a| *a = -*a);
+                    self
+                }
+            }
+        )+
+    }
+}
+
+mat_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
+
+macro_rules! mat_real_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Mat<$t> {
+                /// LU decomposition with partial pivoting, or `None` if the matrix is singular.
+                pub fn lu(&self) -> Option<Lu<$t>> {
+                    assert_eq!(self.r,self.c,"Mat::lu: {}x{} matrix is not square",self.r,self.c);
+                    let n = self.r;
+                    let mut lu = self.clone();
+                    let mut p: Vec<usize> = (0..n).collect();
+                    let mut sign = <$t>::ONE;
+                    for k in 0..n {
+                        let mut m = k;
+                        for i in k + 1..n {
+                            if lu[(i,k)].abs() > lu[(m,k)].abs() {
+                                m = i;
+                            }
+                        }
+                        if lu[(m,k)] == <$t>::ZERO {
+                            return None;
+                        }
+                        if m != k {
+                            for j in 0..n {
+                                lu.v.swap(k * n + j,m * n + j);
+                            }
+                            p.swap(k,m);
+                            sign = -sign;
+                        }
+                        for i in k + 1..n {
+                            let f = lu[(i,k)] / lu[(k,k)];
+                            lu[(i,k)] = f;
+                            for j in k + 1..n {
+                                let a = lu[(k,j)];
+                                lu[(i,j)] -= f * a;
+                            }
+                        }
+                    }
+                    Some(Lu { lu,p,sign, })
+                }
+
+                /// Householder QR decomposition of a matrix with at least as many rows as columns.
+                pub fn qr(&self) -> Qr<$t> {
+                    assert!(self.r >= self.c,"Mat::qr: {}x{} matrix has fewer rows than columns",self.r,self.c);
+                    let m = self.r;
+                    let n = self.c;
+                    let mut qr = self.clone();
+                    let mut rdiag = vec![<$t>::ZERO; n];
+                    for k in 0..n {
+                        let mut norm = <$t>::ZERO;
+                        for i in k..m {
+                            norm = norm.hypot(qr[(i,k)]);
+                        }
+                        if norm != <$t>::ZERO {
+                            if qr[(k,k)] < <$t>::ZERO {
+                                norm = -norm;
+                            }
+                            for i in k..m {
+                                qr[(i,k)] /= norm;
+                            }
+                            qr[(k,k)] += <$t>::ONE;
+                            for j in k + 1..n {
+                                let mut s = <$t>::ZERO;
+                                for i in k..m {
+                                    s += qr[(i,k)] * qr[(i,j)];
+                                }
+                                let s = -s / qr[(k,k)];
+                                for i in k..m {
+                                    let a = qr[(i,k)];
+                                    qr[(i,j)] += s * a;
+                                }
+                            }
+                        }
+                        rdiag[k] = -norm;
+                    }
+                    Qr { qr,rdiag, }
+                }
+
+                /// Cholesky decomposition of a symmetric matrix, or `None` if it is not positive definite.
+                pub fn cholesky(&self) -> Option<Cholesky<$t>> {
+                    assert_eq!(self.r,self.c,"Mat::cholesky: {}x{} matrix is not square",self.r,self.c);
+                    let n = self.r;
+                    let mut l = Mat::<$t>::zero(n,n);
+                    for j in 0..n {
+                        let mut d = self[(j,j)];
+                        for k in 0..j {
+                            d -= l[(j,k)] * l[(j,k)];
+                        }
+                        if d <= <$t>::ZERO {
+                            return None;
+                        }
+                        let d = d.sqrt();
+                        l[(j,j)] = d;
+                        for i in j + 1..n {
+                            let mut s = self[(i,j)];
+                            for k in 0..j {
+                                s -= l[(i,k)] * l[(j,k)];
+                            }
+                            l[(i,j)] = s / d;
+                        }
+                    }
+                    Some(Cholesky { l, })
+                }
+
+                /// Solve `self * x = b`, or in the least squares sense if the matrix has more rows than columns.
+                pub fn solve(&self,b: &[$t]) -> Option<Vec<$t>> {
+                    if self.r == self.c {
+                        self.lu().map(|lu| lu.solve(b))
+                    }
+                    else {
+                        self.qr().solve(b)
+                    }
+                }
+
+                pub fn det(&self) -> $t {
+                    match self.lu() {
+                        Some(lu) => lu.det(),
+                        None => <$t>::ZERO,
+                    }
+                }
+
+                pub fn inv(&self) -> Option<Mat<$t>> {
+                    self.lu().map(|lu| lu.inv())
+                }
+
+                /// Eigenvalues and eigenvectors of a symmetric matrix, by cyclic Jacobi rotation.
+                ///
+                /// Eigenvalues are sorted from large to small, the eigenvectors are the corresponding columns of the returned matrix.
+                pub fn symmetric_eigen(&self) -> (Vec<$t>,Mat<$t>) {
+                    assert_eq!(self.r,self.c,"Mat::symmetric_eigen: {}x{} matrix is not square",self.r,self.c);
+                    let n = self.r;
+                    let mut a = self.clone();
+                    let mut v = Mat::<$t>::identity(n);
+                    for _ in 0..64 {
+                        let mut off = <$t>::ZERO;
+                        let mut norm = <$t>::ZERO;
+                        for i in 0..n {
+                            for j in 0..n {
+                                let e = a[(i,j)] * a[(i,j)];
+                                norm += e;
+                                if i != j {
+                                    off += e;
+                                }
+                            }
+                        }
+                        if off <= <$t>::EPSILON * <$t>::EPSILON * norm {
+                            break;
+                        }
+                        for p in 0..n {
+                            for q in p + 1..n {
+                                let apq = a[(p,q)];
+                                if apq == <$t>::ZERO {
+                                    continue;
+                                }
+                                let theta = (a[(q,q)] - a[(p,p)]) / (2.0 * apq);
+                                let t = theta.signum() / (theta.abs() + (theta * theta + <$t>::ONE).sqrt());
+                                let c = <$t>::ONE / (t * t + <$t>::ONE).sqrt();
+                                let s = t * c;
+                                for k in 0..n {
+                                    let akp = a[(k,p)];
+                                    let akq = a[(k,q)];
+                                    a[(k,p)] = c * akp - s * akq;
+                                    a[(k,q)] = s * akp + c * akq;
+                                }
+                                for k in 0..n {
+                                    let apk = a[(p,k)];
+                                    let aqk = a[(q,k)];
+                                    a[(p,k)] = c * apk - s * aqk;
+                                    a[(q,k)] = s * apk + c * aqk;
+                                }
+                                for k in 0..n {
+                                    let vkp = v[(k,p)];
+                                    let vkq = v[(k,q)];
+                                    v[(k,p)] = c * vkp - s * vkq;
+                                    v[(k,q)] = s * vkp + c * vkq;
+                                }
+                            }
+                        }
+                    }
+                    let mut order: Vec<usize> = (0..n).collect();
+                    order.sort_by(|i,j| a[(*j,*j)].partial_cmp(&a[(*i,*i)]).unwrap_or(std::cmp::Ordering::Equal));
+                    let values = order.iter().map(|i| a[(*i,*i)]).collect();
+                    let mut vectors = Mat::<$t>::zero(n,n);
+                    for (j,oj) in order.iter().enumerate() {
+                        for i in 0..n {
+                            vectors[(i,j)] = v[(i,*oj)];
+                        }
+                    }
+                    (values,vectors)
+                }
+
+                /// Singular value decomposition, by one-sided Jacobi rotation.
+                ///
+                /// Returns `(u,s,v)` such that the matrix is `u * diag(s) * v^T`. For an m x n matrix, `u` is m x k and `v` is n x k
+                /// with orthonormal columns, where k is the smaller of m and n. Singular values are sorted from large to small.
+                pub fn svd(&self) -> (Mat<$t>,Vec<$t>,Mat<$t>) {
+                    if self.r < self.c {
+                        let (u,s,v) = self.transpose().svd();
+                        return (v,s,u);
+                    }
+                    let m = self.r;
+                    let n = self.c;
+                    if n == 0 {
+                        return (Mat::<$t>::zero(m,0),Vec::new(),Mat::<$t>::zero(0,0));
+                    }
+                    let mut u = self.clone();
+                    let mut v = Mat::<$t>::identity(n);
+                    for _ in 0..64 {
+                        let mut rotated = false;
+                        for p in 0..n {
+                            for q in p + 1..n {
+                                let mut alpha = <$t>::ZERO;
+                                let mut beta = <$t>::ZERO;
+                                let mut gamma = <$t>::ZERO;
+                                for k in 0..m {
+                                    alpha += u[(k,p)] * u[(k,p)];
+                                    beta += u[(k,q)] * u[(k,q)];
+                                    gamma += u[(k,p)] * u[(k,q)];
+                                }
+                                if gamma.abs() <= <$t>::EPSILON * (alpha * beta).sqrt() {
+                                    continue;
+                                }
+                                rotated = true;
+                                let zeta = (beta - alpha) / (2.0 * gamma);
+                                let t = zeta.signum() / (zeta.abs() + (zeta * zeta + <$t>::ONE).sqrt());
+                                let c = <$t>::ONE / (t * t + <$t>::ONE).sqrt();
+                                let s = t * c;
+                                for k in 0..m {
+                                    let ukp = u[(k,p)];
+                                    let ukq = u[(k,q)];
+                                    u[(k,p)] = c * ukp - s * ukq;
+                                    u[(k,q)] = s * ukp + c * ukq;
+                                }
+                                for k in 0..n {
+                                    let vkp = v[(k,p)];
+                                    let vkq = v[(k,q)];
+                                    v[(k,p)] = c * vkp - s * vkq;
+                                    v[(k,q)] = s * vkp + c * vkq;
+                                }
+                            }
+                        }
+                        if !rotated {
+                            break;
+                        }
+                    }
+                    let norms: Vec<$t> = (0..n).map(|j| {
+                        let mut s = <$t>::ZERO;
+                        for k in 0..m {
+                            s += u[(k,j)] * u[(k,j)];
+                        }
+                        s.sqrt()
+                    }).collect();
+                    let mut order: Vec<usize> = (0..n).collect();
+                    order.sort_by(|i,j| norms[*j].partial_cmp(&norms[*i]).unwrap_or(std::cmp::Ordering::Equal));
+                    let s: Vec<$t> = order.iter().map(|i| norms[*i]).collect();
+                    let mut su = Mat::<$t>::zero(m,n);
+                    let mut sv = Mat::<$t>::zero(n,n);
+                    let tiny = <$t>::EPSILON * s[0];
+                    for (j,oj) in order.iter().enumerate() {
+                        for k in 0..n {
+                            sv[(k,j)] = v[(k,*oj)];
+                        }
+                        if s[j] > tiny {
+                            for k in 0..m {
+                                su[(k,j)] = u[(k,*oj)] / s[j];
+                            }
+                        }
+                        else {
+                            // null space, complete u with the basis vector that is most orthogonal to the columns so far
+                            let mut best = (<$t>::ZERO,vec![<$t>::ZERO; m]);
+                            for e in 0..m {
+                                let mut w = vec![<$t>::ZERO; m];
+                                w[e] = <$t>::ONE;
+                                for i in 0..j {
+                                    let d = su[(e,i)];
+                                    for k in 0..m {
+                                        w[k] -= d * su[(k,i)];
+                                    }
+                                }
+                                let l = w.iter().fold(<$t>::ZERO,|l,x| l + *x * *x).sqrt();
+                                if l > best.0 {
+                                    best = (l,w);
+                                }
+                            }
+                            for k in 0..m {
+                                su[(k,j)] = best.1[k] / best.0;
+                            }
+                        }
+                    }
+                    (su,s,sv)
+                }
+            }
+
+            impl Lu<$t> {
+                pub fn solve(&self,b: &[$t]) -> Vec<$t> {
+                    let n = self.lu.r;
+                    assert_eq!(b.len(),n,"Lu::solve: {} vector for {}x{} matrix",b.len(),n,n);
+                    let mut x: Vec<$t> = self.p.iter().map(|i| b[*i]).collect();
+                    for i in 0..n {
+                        for k in 0..i {
+                            x[i] -= self.lu[(i,k)] * x[k];
+                        }
+                    }
+                    for i in (0..n).rev() {
+                        for k in i + 1..n {
+                            x[i] -= self.lu[(i,k)] * x[k];
+                        }
+                        x[i] /= self.lu[(i,i)];
+                    }
+                    x
+                }
+
+                pub fn det(&self) -> $t {
+                    let mut d = self.sign;
+                    for i in 0..self.lu.r {
+                        d *= self.lu[(i,i)];
+                    }
+                    d
+                }
+
+                pub fn inv(&self) -> Mat<$t> {
+                    let n = self.lu.r;
+                    let mut m = Mat::<$t>::zero(n,n);
+                    let mut e = vec![<$t>::ZERO; n];
+                    for j in 0..n {
+                        e[j] = <$t>::ONE;
+                        let x = self.solve(&e);
+                        for i in 0..n {
+                            m[(i,j)] = x[i];
+                        }
+                        e[j] = <$t>::ZERO;
+                    }
+                    m
+                }
+            }
+
+            impl Qr<$t> {
+                pub fn is_full_rank(&self) -> bool {
+                    self.rdiag.iter().all(|d| *d != <$t>::ZERO)
+                }
+
+                /// Orthogonal factor Q, with as many columns as the original matrix.
+                pub fn q(&self) -> Mat<$t> {
+                    let m = self.qr.r;
+                    let n = self.qr.c;
+                    let mut q = Mat::<$t>::zero(m,n);
+                    for k in (0..n).rev() {
+                        q[(k,k)] = <$t>::ONE;
+                        for j in k..n {
+                            if self.qr[(k,k)] != <$t>::ZERO {
+                                let mut s = <$t>::ZERO;
+                                for i in k..m {
+                                    s += self.qr[(i,k)] * q[(i,j)];
+                                }
+                                let s = -s / self.qr[(k,k)];
+                                for i in k..m {
+                                    q[(i,j)] += s * self.qr[(i,k)];
+                                }
+                            }
+                        }
+                    }
+                    q
+                }
+
+                /// Upper triangular factor R.
+                pub fn r(&self) -> Mat<$t> {
+                    let n = self.qr.c;
+                    let mut r = Mat::<$t>::zero(n,n);
+                    for i in 0..n {
+                        r[(i,i)] = self.rdiag[i];
+                        for j in i + 1..n {
+                            r[(i,j)] = self.qr[(i,j)];
+                        }
+                    }
+                    r
+                }
+
+                /// Least squares solution of `Q * R * x = b`, or `None` if R is singular.
+                pub fn solve(&self,b: &[$t]) -> Option<Vec<$t>> {
+                    let m = self.qr.r;
+                    let n = self.qr.c;
+                    assert_eq!(b.len(),m,"Qr::solve: {} vector for {}x{} matrix",b.len(),m,n);
+                    if !self.is_full_rank() {
+                        return None;
+                    }
+                    let mut y = b.to_vec();
+                    for k in 0..n {
+                        let mut s = <$t>::ZERO;
+                        for i in k..m {
+                            s += self.qr[(i,k)] * y[i];
+                        }
+                        let s = -s / self.qr[(k,k)];
+                        for i in k..m {
+                            y[i] += s * self.qr[(i,k)];
+                        }
+                    }
+                    for k in (0..n).rev() {
+                        y[k] /= self.rdiag[k];
+                        for i in 0..k {
+                            y[i] -= y[k] * self.qr[(i,k)];
+                        }
+                    }
+                    y.truncate(n);
+                    Some(y)
+                }
+            }
+
+            impl Cholesky<$t> {
+                pub fn solve(&self,b: &[$t]) -> Vec<$t> {
+                    let n = self.l.r;
+                    assert_eq!(b.len(),n,"Cholesky::solve: {} vector for {}x{} matrix",b.len(),n,n);
+                    let mut x = b.to_vec();
+                    for i in 0..n {
+                        for k in 0..i {
+                            x[i] -= self.l[(i,k)] * x[k];
+                        }
+                        x[i] /= self.l[(i,i)];
+                    }
+                    for i in (0..n).rev() {
+                        for k in i + 1..n {
+                            x[i] -= self.l[(k,i)] * x[k];
+                        }
+                        x[i] /= self.l[(i,i)];
+                    }
+                    x
+                }
+
+                pub fn det(&self) -> $t {
+                    let mut d = <$t>::ONE;
+                    for i in 0..self.l.r {
+                        d *= self.l[(i,i)];
+                    }
+                    d * d
+                }
+
+                pub fn inv(&self) -> Mat<$t> {
+                    let n = self.l.r;
+                    let mut m = Mat::<$t>::zero(n,n);
+                    let mut e = vec![<$t>::ZERO; n];
+                    for j in 0..n {
+                        e[j] = <$t>::ONE;
+                        let x = self.solve(&e);
+                        for i in 0..n {
+                            m[(i,j)] = x[i];
+                        }
+                        e[j] = <$t>::ZERO;
+                    }
+                    m
+                }
+            }
+        )+
+    }
+}
+
+mat_real_impl! { f32 f64 }
@@ -175,6 +175,46 @@ macro_rules! mat2x2_impl {
                 }
             }
 
+            // matrix * matrix
+            impl Mul<Mat2x3<$t>> for Mat2x2<$t> {
+                type Output = Mat2x3<$t>;
+                fn mul(self,other: Mat2x3<$t>) -> Self::Output {
+                    Mat2x3 {
+                        x: Vec3 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y,
+                            z: self.x.x * other.x.z + self.x.y * other.y.z,
+                        },
+                        y: Vec3 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y,
+                            z: self.y.x * other.x.z + self.y.y * other.y.z,
+                        },
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat2x4<$t>> for Mat2x2<$t> {
+                type Output = Mat2x4<$t>;
+                fn mul(self,other: Mat2x4<$t>) -> Self::Output {
+                    Mat2x4 {
+                        x: Vec4 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y,
+                            z: self.x.x * other.x.z + self.x.y * other.y.z,
+                            w: self.x.x * other.x.w + self.x.y * other.y.w,
+                        },
+                        y: Vec4 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y,
+                            z: self.y.x * other.x.z + self.y.y * other.y.z,
+                            w: self.y.x * other.x.w + self.y.y * other.y.w,
+                        },
+                    }
+                }
+            }
+
             // matrix *= scalar
             impl MulAssign<$t> for Mat2x2<$t> {
                 fn mul_assign(&mut self,other: $t) {
@@ -226,17 +266,37 @@ macro_rules! mat2x2_impl {
                     }
                 }
             }
+
+            impl From<Mat2x2<$t>> for Mat<$t> {
+                fn from(value: Mat2x2<$t>) -> Self {
+                    Mat::<$t>::from_vec(2,2,vec![
+                        value.x.x,value.x.y,
+                        value.y.x,value.y.y,
+                    ])
+                }
+            }
+
+            impl From<Mat<$t>> for Mat2x2<$t> {
+                fn from(value: Mat<$t>) -> Self {
+                    assert!((value.r == 2) && (value.c == 2),"Mat2x2::from: {}x{} matrix",value.r,value.c);
+                    Mat2x2 {
+                        x: Vec2 { x: value[(0,0)],y: value[(0,1)], },
+                        y: Vec2 { x: value[(1,0)],y: value[(1,1)], },
+                    }
+                }
+            }
         )+
     }
 }
 
-mat2x2_impl! { isize i8 i16 i32 i64 i128 f32 f64 }
+mat2x2_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
 
 macro_rules! mat2x2_real_impl {
     ($($t:ty)+) => {
         $(
             impl Mat2x2<$t> {
-                pub fn inv(self) -> Self {
+                /// Inverse, or `None` if the absolute value of the determinant is not above `epsilon`.
+                pub fn try_inv(self,epsilon: $t) -> Option<Self> {
                     let a = self.x.x;
                     let b = self.y.x;
                     let c = self.x.y;
@@ -244,15 +304,31 @@ macro_rules! mat2x2_real_impl {
                     let aa = d;
                     let ab = c;
                     let det = a * aa - b * ab;
-                    if det == 0.0 {
-                        return self;
+                    if det.abs() <= epsilon {
+                        return None;
                     }
                     let ac = b;
                     let ad = a;
-                    Mat2x2 {
-                        x: Vec2 { x: aa,y: -ac, },
-                        y: Vec2 { x: -ab,y: ad, },
-                    } / det
+                    Some(Mat2x2 {
+                        x: Vec2 { x: aa,y: -ab, },
+                        y: Vec2 { x: -ac,y: ad, },
+                    } / det)
+                }
+
+                pub fn inv(self) -> Self {
+                    self.try_inv(<$t>::ZERO).unwrap_or(self)
+                }
+
+                /// Eigenvalues and eigenvectors of a symmetric matrix, see [`Mat::symmetric_eigen`].
+                pub fn symmetric_eigen(self) -> (Vec2<$t>,Mat2x2<$t>) {
+                    let (v,vectors) = Mat::<$t>::from(self).symmetric_eigen();
+                    (Vec2 { x: v[0],y: v[1], },Mat2x2::<$t>::from(vectors))
+                }
+
+                /// Singular value decomposition `u * diag(s) * v^T`, see [`Mat::svd`].
+                pub fn svd(self) -> (Mat2x2<$t>,Vec2<$t>,Mat2x2<$t>) {
+                    let (u,s,v) = Mat::<$t>::from(self).svd();
+                    (Mat2x2::<$t>::from(u),Vec2 { x: s[0],y: s[1], },Mat2x2::<$t>::from(v))
                 }
             }
 
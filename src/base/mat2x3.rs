@@ -0,0 +1,256 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+        },
+    },
+};
+
+/// Matrix with 2 rows and 3 columns.
+#[derive(Copy,Clone,Debug)]
+pub struct Mat2x3<T> {
+    pub x: Vec3<T>,
+    pub y: Vec3<T>,
+}
+
+macro_rules! mat2x3_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Mat2x3<$t> {
+                pub fn from_mv(m: Mat2x2<$t>,v: Vec2<$t>) -> Mat2x3<$t> {
+                    Mat2x3 {
+                        x: Vec3 {
+                            x: m.x.x,
+                            y: m.x.y,
+                            z: v.x,
+                        },
+                        y: Vec3 {
+                            x: m.y.x,
+                            y: m.y.y,
+                            z: v.y,
+                        },
+                    }
+                }
+
+                pub fn transpose(self) -> Mat3x2<$t> {
+                    Mat3x2 {
+                        x: Vec2 {
+                            x: self.x.x,
+                            y: self.y.x,
+                        },
+                        y: Vec2 {
+                            x: self.x.y,
+                            y: self.y.y,
+                        },
+                        z: Vec2 {
+                            x: self.x.z,
+                            y: self.y.z,
+                        },
+                    }
+                }
+            }
+
+            impl Zero for Mat2x3<$t> {
+                const ZERO: Mat2x3<$t> = Mat2x3 {
+                    x: Vec3::ZERO,
+                    y: Vec3::ZERO,
+                };
+            }
+
+            impl PartialEq for Mat2x3<$t> {
+                fn eq(&self,other: &Self) -> bool {
+                    (self.x == other.x) && (self.y == other.y)
+                }
+            }
+
+            impl Display for Mat2x3<$t> {
+                fn fmt(&self,f: &mut Formatter) -> Result {
+                    write!(f,"[{},{}]",self.x,self.y)
+                }
+            }
+
+            // matrix + matrix
+            impl Add<Mat2x3<$t>> for Mat2x3<$t> {
+                type Output = Self;
+                fn add(self,other: Self) -> Self::Output {
+                    Mat2x3 {
+                        x: self.x + other.x,
+                        y: self.y + other.y,
+                    }
+                }
+            }
+
+            // matrix += matrix
+            impl AddAssign<Mat2x3<$t>> for Mat2x3<$t> {
+                fn add_assign(&mut self,other: Self) {
+                    self.x += other.x;
+                    self.y += other.y;
+                }
+            }
+
+            // matrix - matrix
+            impl Sub<Mat2x3<$t>> for Mat2x3<$t> {
+                type Output = Self;
+                fn sub(self,other: Self) -> Self::Output {
+                    Mat2x3 {
+                        x: self.x - other.x,
+                        y: self.y - other.y,
+                    }
+                }
+            }
+
+            // matrix -= matrix
+            impl SubAssign<Mat2x3<$t>> for Mat2x3<$t> {
+                fn sub_assign(&mut self,other: Self) {
+                    self.x -= other.x;
+                    self.y -= other.y;
+                }
+            }
+
+            // scalar * matrix
+            impl Mul<Mat2x3<$t>> for $t {
+                type Output = Mat2x3<$t>;
+                fn mul(self,other: Mat2x3<$t>) -> Self::Output {
+                    Mat2x3 {
+                        x: self * other.x,
+                        y: self * other.y,
+                    }
+                }
+            }
+
+            // matrix * scalar
+            impl Mul<$t> for Mat2x3<$t> {
+                type Output = Mat2x3<$t>;
+                fn mul(self,other: $t) -> Self::Output {
+                    Mat2x3 {
+                        x: self.x * other,
+                        y: self.y * other,
+                    }
+                }
+            }
+
+            // matrix * vector
+            impl Mul<Vec3<$t>> for Mat2x3<$t> {
+                type Output = Vec2<$t>;
+                fn mul(self,other: Vec3<$t>) -> Self::Output {
+                    Vec2 {
+                        x: self.x.x * other.x + self.x.y * other.y + self.x.z * other.z,
+                        y: self.y.x * other.x + self.y.y * other.y + self.y.z * other.z,
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat3x2<$t>> for Mat2x3<$t> {
+                type Output = Mat2x2<$t>;
+                fn mul(self,other: Mat3x2<$t>) -> Self::Output {
+                    Mat2x2 {
+                        x: Vec2 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y,
+                        },
+                        y: Vec2 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y,
+                        },
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat3x3<$t>> for Mat2x3<$t> {
+                type Output = Mat2x3<$t>;
+                fn mul(self,other: Mat3x3<$t>) -> Self::Output {
+                    Mat2x3 {
+                        x: Vec3 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y,
+                            z: self.x.x * other.x.z + self.x.y * other.y.z + self.x.z * other.z.z,
+                        },
+                        y: Vec3 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y,
+                            z: self.y.x * other.x.z + self.y.y * other.y.z + self.y.z * other.z.z,
+                        },
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat3x4<$t>> for Mat2x3<$t> {
+                type Output = Mat2x4<$t>;
+                fn mul(self,other: Mat3x4<$t>) -> Self::Output {
+                    Mat2x4 {
+                        x: Vec4 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y,
+                            z: self.x.x * other.x.z + self.x.y * other.y.z + self.x.z * other.z.z,
+                            w: self.x.x * other.x.w + self.x.y * other.y.w + self.x.z * other.z.w,
+                        },
+                        y: Vec4 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y,
+                            z: self.y.x * other.x.z + self.y.y * other.y.z + self.y.z * other.z.z,
+                            w: self.y.x * other.x.w + self.y.y * other.y.w + self.y.z * other.z.w,
+                        },
+                    }
+                }
+            }
+
+            // matrix *= scalar
+            impl MulAssign<$t> for Mat2x3<$t> {
+                fn mul_assign(&mut self,other: $t) {
+                    self.x *= other;
+                    self.y *= other;
+                }
+            }
+
+            // matrix / scalar
+            impl Div<$t> for Mat2x3<$t> {
+                type Output = Mat2x3<$t>;
+                fn div(self,other: $t) -> Self::Output {
+                    Mat2x3 {
+                        x: self.x / other,
+                        y: self.y / other,
+                    }
+                }
+            }
+
+            // matrix /= scalar
+            impl DivAssign<$t> for Mat2x3<$t> {
+                fn div_assign(&mut self,other: $t) {
+                    self.x /= other;
+                    self.y /= other;
+                }
+            }
+
+            // -matrix
+            impl Neg for Mat2x3<$t> {
+                type Output = Mat2x3<$t>;
+                fn neg(self) -> Self::Output {
+                    Mat2x3 {
+                        x: -self.x,
+                        y: -self.y,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+mat2x3_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
@@ -0,0 +1,267 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+        },
+    },
+};
+
+/// Matrix with 3 rows and 2 columns.
+#[derive(Copy,Clone,Debug)]
+pub struct Mat3x2<T> {
+    pub x: Vec2<T>,
+    pub y: Vec2<T>,
+    pub z: Vec2<T>,
+}
+
+macro_rules! mat3x2_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Mat3x2<$t> {
+                pub fn transpose(self) -> Mat2x3<$t> {
+                    Mat2x3 {
+                        x: Vec3 {
+                            x: self.x.x,
+                            y: self.y.x,
+                            z: self.z.x,
+                        },
+                        y: Vec3 {
+                            x: self.x.y,
+                            y: self.y.y,
+                            z: self.z.y,
+                        },
+                    }
+                }
+            }
+
+            impl Zero for Mat3x2<$t> {
+                const ZERO: Mat3x2<$t> = Mat3x2 {
+                    x: Vec2::ZERO,
+                    y: Vec2::ZERO,
+                    z: Vec2::ZERO,
+                };
+            }
+
+            impl PartialEq for Mat3x2<$t> {
+                fn eq(&self,other: &Self) -> bool {
+                    (self.x == other.x) && (self.y == other.y) && (self.z == other.z)
+                }
+            }
+
+            impl Display for Mat3x2<$t> {
+                fn fmt(&self,f: &mut Formatter) -> Result {
+                    write!(f,"[{},{},{}]",self.x,self.y,self.z)
+                }
+            }
+
+            // matrix + matrix
+            impl Add<Mat3x2<$t>> for Mat3x2<$t> {
+                type Output = Self;
+                fn add(self,other: Self) -> Self::Output {
+                    Mat3x2 {
+                        x: self.x + other.x,
+                        y: self.y + other.y,
+                        z: self.z + other.z,
+                    }
+                }
+            }
+
+            // matrix += matrix
+            impl AddAssign<Mat3x2<$t>> for Mat3x2<$t> {
+                fn add_assign(&mut self,other: Self) {
+                    self.x += other.x;
+                    self.y += other.y;
+                    self.z += other.z;
+                }
+            }
+
+            // matrix - matrix
+            impl Sub<Mat3x2<$t>> for Mat3x2<$t> {
+                type Output = Self;
+                fn sub(self,other: Self) -> Self::Output {
+                    Mat3x2 {
+                        x: self.x - other.x,
+                        y: self.y - other.y,
+                        z: self.z - other.z,
+                    }
+                }
+            }
+
+            // matrix -= matrix
+            impl SubAssign<Mat3x2<$t>> for Mat3x2<$t> {
+                fn sub_assign(&mut self,other: Self) {
+                    self.x -= other.x;
+                    self.y -= other.y;
+                    self.z -= other.z;
+                }
+            }
+
+            // scalar * matrix
+            impl Mul<Mat3x2<$t>> for $t {
+                type Output = Mat3x2<$t>;
+                fn mul(self,other: Mat3x2<$t>) -> Self::Output {
+                    Mat3x2 {
+                        x: self * other.x,
+                        y: self * other.y,
+                        z: self * other.z,
+                    }
+                }
+            }
+
+            // matrix * scalar
+            impl Mul<$t> for Mat3x2<$t> {
+                type Output = Mat3x2<$t>;
+                fn mul(self,other: $t) -> Self::Output {
+                    Mat3x2 {
+                        x: self.x * other,
+                        y: self.y * other,
+                        z: self.z * other,
+                    }
+                }
+            }
+
+            // matrix * vector
+            impl Mul<Vec2<$t>> for Mat3x2<$t> {
+                type Output = Vec3<$t>;
+                fn mul(self,other: Vec2<$t>) -> Self::Output {
+                    Vec3 {
+                        x: self.x.x * other.x + self.x.y * other.y,
+                        y: self.y.x * other.x + self.y.y * other.y,
+                        z: self.z.x * other.x + self.z.y * other.y,
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat2x2<$t>> for Mat3x2<$t> {
+                type Output = Mat3x2<$t>;
+                fn mul(self,other: Mat2x2<$t>) -> Self::Output {
+                    Mat3x2 {
+                        x: Vec2 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y,
+                        },
+                        y: Vec2 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y,
+                        },
+                        z: Vec2 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y,
+                        },
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat2x3<$t>> for Mat3x2<$t> {
+                type Output = Mat3x3<$t>;
+                fn mul(self,other: Mat2x3<$t>) -> Self::Output {
+                    Mat3x3 {
+                        x: Vec3 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y,
+                            z: self.x.x * other.x.z + self.x.y * other.y.z,
+                        },
+                        y: Vec3 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y,
+                            z: self.y.x * other.x.z + self.y.y * other.y.z,
+                        },
+                        z: Vec3 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y,
+                            z: self.z.x * other.x.z + self.z.y * other.y.z,
+                        },
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat2x4<$t>> for Mat3x2<$t> {
+                type Output = Mat3x4<$t>;
+                fn mul(self,other: Mat2x4<$t>) -> Self::Output {
+                    Mat3x4 {
+                        x: Vec4 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y,
+                            z: self.x.x * other.x.z + self.x.y * other.y.z,
+                            w: self.x.x * other.x.w + self.x.y * other.y.w,
+                        },
+                        y: Vec4 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y,
+                            z: self.y.x * other.x.z + self.y.y * other.y.z,
+                            w: self.y.x * other.x.w + self.y.y * other.y.w,
+                        },
+                        z: Vec4 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y,
+                            z: self.z.x * other.x.z + self.z.y * other.y.z,
+                            w: self.z.x * other.x.w + self.z.y * other.y.w,
+                        },
+                    }
+                }
+            }
+
+            // matrix *= scalar
+            impl MulAssign<$t> for Mat3x2<$t> {
+                fn mul_assign(&mut self,other: $t) {
+                    self.x *= other;
+                    self.y *= other;
+                    self.z *= other;
+                }
+            }
+
+            // matrix / scalar
+            impl Div<$t> for Mat3x2<$t> {
+                type Output = Mat3x2<$t>;
+                fn div(self,other: $t) -> Self::Output {
+                    Mat3x2 {
+                        x: self.x / other,
+                        y: self.y / other,
+                        z: self.z / other,
+                    }
+                }
+            }
+
+            // matrix /= scalar
+            impl DivAssign<$t> for Mat3x2<$t> {
+                fn div_assign(&mut self,other: $t) {
+                    self.x /= other;
+                    self.y /= other;
+                    self.z /= other;
+                }
+            }
+
+            // -matrix
+            impl Neg for Mat3x2<$t> {
+                type Output = Mat3x2<$t>;
+                fn neg(self) -> Self::Output {
+                    Mat3x2 {
+                        x: -self.x,
+                        y: -self.y,
+                        z: -self.z,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+mat3x2_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
@@ -37,21 +37,38 @@ macro_rules! mat3x3_impl {
                         x: Vec3 {
                             x: m.x.x,
                             y: m.x.y,
-                            z: <$t>::ZERO,
+                            z: v.x,
                         },
                         y: Vec3 {
                             x: m.y.x,
                             y: m.y.y,
-                            z: <$t>::ZERO,
+                            z: v.y,
                         },
                         z: Vec3 {
-                            x: v.x,
-                            y: v.y,
+                            x: <$t>::ZERO,
+                            y: <$t>::ZERO,
                             z: <$t>::ONE,
                         },
                     }
                 }
 
+                /// 2D translation in homogeneous coordinates.
+                pub fn from_translation_2d(t: Vec2<$t>) -> Mat3x3<$t> {
+                    Mat3x3 {
+                        x: Vec3 { x: <$t>::ONE,y: <$t>::ZERO,z: t.x, },
+                        y: Vec3 { x: <$t>::ZERO,y: <$t>::ONE,z: t.y, },
+                        z: Vec3 { x: <$t>::ZERO,y: <$t>::ZERO,z: <$t>::ONE, },
+                    }
+                }
+
+                pub fn from_scale(s: Vec3<$t>) -> Mat3x3<$t> {
+                    Mat3x3 {
+                        x: Vec3 { x: s.x,y: <$t>::ZERO,z: <$t>::ZERO, },
+                        y: Vec3 { x: <$t>::ZERO,y: s.y,z: <$t>::ZERO, },
+                        z: Vec3 { x: <$t>::ZERO,y: <$t>::ZERO,z: s.z, },
+                    }
+                }
+
                 pub fn transpose(self) -> Mat3x3<$t> {
                     Mat3x3 {
                         x: Vec3 {
@@ -231,6 +248,54 @@ macro_rules! mat3x3_impl {
                 }
             }
 
+            // matrix * matrix
+            impl Mul<Mat3x2<$t>> for Mat3x3<$t> {
+                type Output = Mat3x2<$t>;
+                fn mul(self,other: Mat3x2<$t>) -> Self::Output {
+                    Mat3x2 {
+                        x: Vec2 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y,
+                        },
+                        y: Vec2 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y,
+                        },
+                        z: Vec2 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y,
+                        },
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat3x4<$t>> for Mat3x3<$t> {
+                type Output = Mat3x4<$t>;
+                fn mul(self,other: Mat3x4<$t>) -> Self::Output {
+                    Mat3x4 {
+                        x: Vec4 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y,
+                            z: self.x.x * other.x.z + self.x.y * other.y.z + self.x.z * other.z.z,
+                            w: self.x.x * other.x.w + self.x.y * other.y.w + self.x.z * other.z.w,
+                        },
+                        y: Vec4 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y,
+                            z: self.y.x * other.x.z + self.y.y * other.y.z + self.y.z * other.z.z,
+                            w: self.y.x * other.x.w + self.y.y * other.y.w + self.y.z * other.z.w,
+                        },
+                        z: Vec4 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y,
+                            z: self.z.x * other.x.z + self.z.y * other.y.z + self.z.z * other.z.z,
+                            w: self.z.x * other.x.w + self.z.y * other.y.w + self.z.z * other.z.w,
+                        },
+                    }
+                }
+            }
+
             // matrix *= scalar
             impl MulAssign<$t> for Mat3x3<$t> {
                 fn mul_assign(&mut self,other: $t) {
@@ -296,17 +361,63 @@ macro_rules! mat3x3_impl {
                     }
                 }
             }
+
+            impl From<Mat3x3<$t>> for Mat<$t> {
+                fn from(value: Mat3x3<$t>) -> Self {
+                    Mat::<$t>::from_vec(3,3,vec![
+                        value.x.x,value.x.y,value.x.z,
+                        value.y.x,value.y.y,value.y.z,
+                        value.z.x,value.z.y,value.z.z,
+                    ])
+                }
+            }
+
+            impl From<Mat<$t>> for Mat3x3<$t> {
+                fn from(value: Mat<$t>) -> Self {
+                    assert!((value.r == 3) && (value.c == 3),"Mat3x3::from: {}x{} matrix",value.r,value.c);
+                    Mat3x3 {
+                        x: Vec3 { x: value[(0,0)],y: value[(0,1)],z: value[(0,2)], },
+                        y: Vec3 { x: value[(1,0)],y: value[(1,1)],z: value[(1,2)], },
+                        z: Vec3 { x: value[(2,0)],y: value[(2,1)],z: value[(2,2)], },
+                    }
+                }
+            }
         )+
     }
 }
 
-mat3x3_impl! { isize i8 i16 i32 i64 i128 f32 f64 }
+mat3x3_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
 
 macro_rules! mat3x3_real_impl {
     ($($t:ty)+) => {
         $(
             impl Mat3x3<$t> {
-                pub fn inv(self) -> Self {
+                /// Rotation counterclockwise by `angle` radians about `axis`.
+                pub fn from_axis_angle(axis: Vec3<$t>,angle: $t) -> Mat3x3<$t> {
+                    let axis = axis / axis.dot(axis).sqrt();
+                    let (s,c) = angle.sin_cos();
+                    let d = <$t>::ONE - c;
+                    Mat3x3 {
+                        x: Vec3 {
+                            x: c + axis.x * axis.x * d,
+                            y: axis.x * axis.y * d - axis.z * s,
+                            z: axis.x * axis.z * d + axis.y * s,
+                        },
+                        y: Vec3 {
+                            x: axis.y * axis.x * d + axis.z * s,
+                            y: c + axis.y * axis.y * d,
+                            z: axis.y * axis.z * d - axis.x * s,
+                        },
+                        z: Vec3 {
+                            x: axis.z * axis.x * d - axis.y * s,
+                            y: axis.z * axis.y * d + axis.x * s,
+                            z: c + axis.z * axis.z * d,
+                        },
+                    }
+                }
+
+                /// Inverse, or `None` if the absolute value of the determinant is not above `epsilon`.
+                pub fn try_inv(self,epsilon: $t) -> Option<Self> {
                     let a = self.x.x;
                     let d = self.x.y;
                     let g = self.x.z;
@@ -320,8 +431,8 @@ macro_rules! mat3x3_real_impl {
                     let ab = d * i - f * g;
                     let ac = d * h - e * g;
                     let det = a * aa - b * ab + c * ac;
-                    if det == 0.0 {
-                        return self;
+                    if det.abs() <= epsilon {
+                        return None;
                     }
                     let ad = b * i - c * h;
                     let ae = a * i - c * g;
@@ -329,11 +440,47 @@ macro_rules! mat3x3_real_impl {
                     let ag = b * f - c * e;
                     let ah = a * f - c * d;
                     let ai = a * e - b * d; 
-                    Mat3x3 {
-                        x: Vec3 { x: aa,y: -ad,z: ag, },
-                        y: Vec3 { x: -ab,y: ae,z: -ah, },
-                        z: Vec3 { x: ac,y: -af,z: ai, },
-                    } / det
+                    Some(Mat3x3 {
+                        x: Vec3 { x: aa,y: -ab,z: ac, },
+                        y: Vec3 { x: -ad,y: ae,z: -af, },
+                        z: Vec3 { x: ag,y: -ah,z: ai, },
+                    } / det)
+                }
+
+                pub fn inv(self) -> Self {
+                    self.try_inv(<$t>::ZERO).unwrap_or(self)
+                }
+
+                /// Eigenvalues and eigenvectors of a symmetric matrix, see [`Mat::symmetric_eigen`].
+                pub fn symmetric_eigen(self) -> (Vec3<$t>,Mat3x3<$t>) {
+                    let (v,vectors) = Mat::<$t>::from(self).symmetric_eigen();
+                    (Vec3 { x: v[0],y: v[1],z: v[2], },Mat3x3::<$t>::from(vectors))
+                }
+
+                /// Singular value decomposition `u * diag(s) * v^T`, see [`Mat::svd`].
+                pub fn svd(self) -> (Mat3x3<$t>,Vec3<$t>,Mat3x3<$t>) {
+                    let (u,s,v) = Mat::<$t>::from(self).svd();
+                    (Mat3x3::<$t>::from(u),Vec3 { x: s[0],y: s[1],z: s[2], },Mat3x3::<$t>::from(v))
+                }
+
+                /// Polar decomposition into rotation `r` and symmetric stretch `s`, such that the matrix is `r * s`.
+                ///
+                /// `r` is always a proper rotation, a reflection in the matrix ends up in `s`.
+                pub fn polar(self) -> (Mat3x3<$t>,Mat3x3<$t>) {
+                    let (mut u,mut s,v) = self.svd();
+                    if (u * v.transpose()).det() < <$t>::ZERO {
+                        u.x.z = -u.x.z;
+                        u.y.z = -u.y.z;
+                        u.z.z = -u.z.z;
+                        s.z = -s.z;
+                    }
+                    let vt = v.transpose();
+                    let d = Mat3x3 {
+                        x: Vec3 { x: s.x,y: <$t>::ZERO,z: <$t>::ZERO, },
+                        y: Vec3 { x: <$t>::ZERO,y: s.y,z: <$t>::ZERO, },
+                        z: Vec3 { x: <$t>::ZERO,y: <$t>::ZERO,z: s.z, },
+                    };
+                    (u * vt,v * d * vt)
                 }
             }
 
@@ -0,0 +1,300 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+        },
+    },
+};
+
+/// Matrix with 3 rows and 4 columns.
+#[derive(Copy,Clone,Debug)]
+pub struct Mat3x4<T> {
+    pub x: Vec4<T>,
+    pub y: Vec4<T>,
+    pub z: Vec4<T>,
+}
+
+macro_rules! mat3x4_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Mat3x4<$t> {
+                pub fn from_mv(m: Mat3x3<$t>,v: Vec3<$t>) -> Mat3x4<$t> {
+                    Mat3x4 {
+                        x: Vec4 {
+                            x: m.x.x,
+                            y: m.x.y,
+                            z: m.x.z,
+                            w: v.x,
+                        },
+                        y: Vec4 {
+                            x: m.y.x,
+                            y: m.y.y,
+                            z: m.y.z,
+                            w: v.y,
+                        },
+                        z: Vec4 {
+                            x: m.z.x,
+                            y: m.z.y,
+                            z: m.z.z,
+                            w: v.z,
+                        },
+                    }
+                }
+
+                pub fn transpose(self) -> Mat4x3<$t> {
+                    Mat4x3 {
+                        x: Vec3 {
+                            x: self.x.x,
+                            y: self.y.x,
+                            z: self.z.x,
+                        },
+                        y: Vec3 {
+                            x: self.x.y,
+                            y: self.y.y,
+                            z: self.z.y,
+                        },
+                        z: Vec3 {
+                            x: self.x.z,
+                            y: self.y.z,
+                            z: self.z.z,
+                        },
+                        w: Vec3 {
+                            x: self.x.w,
+                            y: self.y.w,
+                            z: self.z.w,
+                        },
+                    }
+                }
+            }
+
+            impl Zero for Mat3x4<$t> {
+                const ZERO: Mat3x4<$t> = Mat3x4 {
+                    x: Vec4::ZERO,
+                    y: Vec4::ZERO,
+                    z: Vec4::ZERO,
+                };
+            }
+
+            impl PartialEq for Mat3x4<$t> {
+                fn eq(&self,other: &Self) -> bool {
+                    (self.x == other.x) && (self.y == other.y) && (self.z == other.z)
+                }
+            }
+
+            impl Display for Mat3x4<$t> {
+                fn fmt(&self,f: &mut Formatter) -> Result {
+                    write!(f,"[{},{},{}]",self.x,self.y,self.z)
+                }
+            }
+
+            // matrix + matrix
+            impl Add<Mat3x4<$t>> for Mat3x4<$t> {
+                type Output = Self;
+                fn add(self,other: Self) -> Self::Output {
+                    Mat3x4 {
+                        x: self.x + other.x,
+                        y: self.y + other.y,
+                        z: self.z + other.z,
+                    }
+                }
+            }
+
+            // matrix += matrix
+            impl AddAssign<Mat3x4<$t>> for Mat3x4<$t> {
+                fn add_assign(&mut self,other: Self) {
+                    self.x += other.x;
+                    self.y += other.y;
+                    self.z += other.z;
+                }
+            }
+
+            // matrix - matrix
+            impl Sub<Mat3x4<$t>> for Mat3x4<$t> {
+                type Output = Self;
+                fn sub(self,other: Self) -> Self::Output {
+                    Mat3x4 {
+                        x: self.x - other.x,
+                        y: self.y - other.y,
+                        z: self.z - other.z,
+                    }
+                }
+            }
+
+            // matrix -= matrix
+            impl SubAssign<Mat3x4<$t>> for Mat3x4<$t> {
+                fn sub_assign(&mut self,other: Self) {
+                    self.x -= other.x;
+                    self.y -= other.y;
+                    self.z -= other.z;
+                }
+            }
+
+            // scalar * matrix
+            impl Mul<Mat3x4<$t>> for $t {
+                type Output = Mat3x4<$t>;
+                fn mul(self,other: Mat3x4<$t>) -> Self::Output {
+                    Mat3x4 {
+                        x: self * other.x,
+                        y: self * other.y,
+                        z: self * other.z,
+                    }
+                }
+            }
+
+            // matrix * scalar
+            impl Mul<$t> for Mat3x4<$t> {
+                type Output = Mat3x4<$t>;
+                fn mul(self,other: $t) -> Self::Output {
+                    Mat3x4 {
+                        x: self.x * other,
+                        y: self.y * other,
+                        z: self.z * other,
+                    }
+                }
+            }
+
+            // matrix * vector
+            impl Mul<Vec4<$t>> for Mat3x4<$t> {
+                type Output = Vec3<$t>;
+                fn mul(self,other: Vec4<$t>) -> Self::Output {
+                    Vec3 {
+                        x: self.x.x * other.x + self.x.y * other.y + self.x.z * other.z + self.x.w * other.w,
+                        y: self.y.x * other.x + self.y.y * other.y + self.y.z * other.z + self.y.w * other.w,
+                        z: self.z.x * other.x + self.z.y * other.y + self.z.z * other.z + self.z.w * other.w,
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat4x2<$t>> for Mat3x4<$t> {
+                type Output = Mat3x2<$t>;
+                fn mul(self,other: Mat4x2<$t>) -> Self::Output {
+                    Mat3x2 {
+                        x: Vec2 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x + self.x.w * other.w.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y + self.x.w * other.w.y,
+                        },
+                        y: Vec2 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x + self.y.w * other.w.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y + self.y.w * other.w.y,
+                        },
+                        z: Vec2 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x + self.z.w * other.w.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y + self.z.w * other.w.y,
+                        },
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat4x3<$t>> for Mat3x4<$t> {
+                type Output = Mat3x3<$t>;
+                fn mul(self,other: Mat4x3<$t>) -> Self::Output {
+                    Mat3x3 {
+                        x: Vec3 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x + self.x.w * other.w.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y + self.x.w * other.w.y,
+                            z: self.x.x * other.x.z + self.x.y * other.y.z + self.x.z * other.z.z + self.x.w * other.w.z,
+                        },
+                        y: Vec3 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x + self.y.w * other.w.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y + self.y.w * other.w.y,
+                            z: self.y.x * other.x.z + self.y.y * other.y.z + self.y.z * other.z.z + self.y.w * other.w.z,
+                        },
+                        z: Vec3 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x + self.z.w * other.w.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y + self.z.w * other.w.y,
+                            z: self.z.x * other.x.z + self.z.y * other.y.z + self.z.z * other.z.z + self.z.w * other.w.z,
+                        },
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat4x4<$t>> for Mat3x4<$t> {
+                type Output = Mat3x4<$t>;
+                fn mul(self,other: Mat4x4<$t>) -> Self::Output {
+                    Mat3x4 {
+                        x: Vec4 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x + self.x.w * other.w.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y + self.x.w * other.w.y,
+                            z: self.x.x * other.x.z + self.x.y * other.y.z + self.x.z * other.z.z + self.x.w * other.w.z,
+                            w: self.x.x * other.x.w + self.x.y * other.y.w + self.x.z * other.z.w + self.x.w * other.w.w,
+                        },
+                        y: Vec4 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x + self.y.w * other.w.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y + self.y.w * other.w.y,
+                            z: self.y.x * other.x.z + self.y.y * other.y.z + self.y.z * other.z.z + self.y.w * other.w.z,
+                            w: self.y.x * other.x.w + self.y.y * other.y.w + self.y.z * other.z.w + self.y.w * other.w.w,
+                        },
+                        z: Vec4 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x + self.z.w * other.w.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y + self.z.w * other.w.y,
+                            z: self.z.x * other.x.z + self.z.y * other.y.z + self.z.z * other.z.z + self.z.w * other.w.z,
+                            w: self.z.x * other.x.w + self.z.y * other.y.w + self.z.z * other.z.w + self.z.w * other.w.w,
+                        },
+                    }
+                }
+            }
+
+            // matrix *= scalar
+            impl MulAssign<$t> for Mat3x4<$t> {
+                fn mul_assign(&mut self,other: $t) {
+                    self.x *= other;
+                    self.y *= other;
+                    self.z *= other;
+                }
+            }
+
+            // matrix / scalar
+            impl Div<$t> for Mat3x4<$t> {
+                type Output = Mat3x4<$t>;
+                fn div(self,other: $t) -> Self::Output {
+                    Mat3x4 {
+                        x: self.x / other,
+                        y: self.y / other,
+                        z: self.z / other,
+                    }
+                }
+            }
+
+            // matrix /= scalar
+            impl DivAssign<$t> for Mat3x4<$t> {
+                fn div_assign(&mut self,other: $t) {
+                    self.x /= other;
+                    self.y /= other;
+                    self.z /= other;
+                }
+            }
+
+            // -matrix
+            impl Neg for Mat3x4<$t> {
+                type Output = Mat3x4<$t>;
+                fn neg(self) -> Self::Output {
+                    Mat3x4 {
+                        x: -self.x,
+                        y: -self.y,
+                        z: -self.z,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+mat3x4_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
@@ -0,0 +1,303 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+        },
+    },
+};
+
+/// Matrix with 4 rows and 3 columns.
+#[derive(Copy,Clone,Debug)]
+pub struct Mat4x3<T> {
+    pub x: Vec3<T>,
+    pub y: Vec3<T>,
+    pub z: Vec3<T>,
+    pub w: Vec3<T>,
+}
+
+macro_rules! mat4x3_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Mat4x3<$t> {
+                pub fn transpose(self) -> Mat3x4<$t> {
+                    Mat3x4 {
+                        x: Vec4 {
+                            x: self.x.x,
+                            y: self.y.x,
+                            z: self.z.x,
+                            w: self.w.x,
+                        },
+                        y: Vec4 {
+                            x: self.x.y,
+                            y: self.y.y,
+                            z: self.z.y,
+                            w: self.w.y,
+                        },
+                        z: Vec4 {
+                            x: self.x.z,
+                            y: self.y.z,
+                            z: self.z.z,
+                            w: self.w.z,
+                        },
+                    }
+                }
+            }
+
+            impl Zero for Mat4x3<$t> {
+                const ZERO: Mat4x3<$t> = Mat4x3 {
+                    x: Vec3::ZERO,
+                    y: Vec3::ZERO,
+                    z: Vec3::ZERO,
+                    w: Vec3::ZERO,
+                };
+            }
+
+            impl PartialEq for Mat4x3<$t> {
+                fn eq(&self,other: &Self) -> bool {
+                    (self.x == other.x) && (self.y == other.y) && (self.z == other.z) && (self.w == other.w)
+                }
+            }
+
+            impl Display for Mat4x3<$t> {
+                fn fmt(&self,f: &mut Formatter) -> Result {
+                    write!(f,"[{},{},{},{}]",self.x,self.y,self.z,self.w)
+                }
+            }
+
+            // matrix + matrix
+            impl Add<Mat4x3<$t>> for Mat4x3<$t> {
+                type Output = Self;
+                fn add(self,other: Self) -> Self::Output {
+                    Mat4x3 {
+                        x: self.x + other.x,
+                        y: self.y + other.y,
+                        z: self.z + other.z,
+                        w: self.w + other.w,
+                    }
+                }
+            }
+
+            // matrix += matrix
+            impl AddAssign<Mat4x3<$t>> for Mat4x3<$t> {
+                fn add_assign(&mut self,other: Self) {
+                    self.x += other.x;
+                    self.y += other.y;
+                    self.z += other.z;
+                    self.w += other.w;
+                }
+            }
+
+            // matrix - matrix
+            impl Sub<Mat4x3<$t>> for Mat4x3<$t> {
+                type Output = Self;
+                fn sub(self,other: Self) -> Self::Output {
+                    Mat4x3 {
+                        x: self.x - other.x,
+                        y: self.y - other.y,
+                        z: self.z - other.z,
+                        w: self.w - other.w,
+                    }
+                }
+            }
+
+            // matrix -= matrix
+            impl SubAssign<Mat4x3<$t>> for Mat4x3<$t> {
+                fn sub_assign(&mut self,other: Self) {
+                    self.x -= other.x;
+                    self.y -= other.y;
+                    self.z -= other.z;
+                    self.w -= other.w;
+                }
+            }
+
+            // scalar * matrix
+            impl Mul<Mat4x3<$t>> for $t {
+                type Output = Mat4x3<$t>;
+                fn mul(self,other: Mat4x3<$t>) -> Self::Output {
+                    Mat4x3 {
+                        x: self * other.x,
+                        y: self * other.y,
+                        z: self * other.z,
+                        w: self * other.w,
+                    }
+                }
+            }
+
+            // matrix * scalar
+            impl Mul<$t> for Mat4x3<$t> {
+                type Output = Mat4x3<$t>;
+                fn mul(self,other: $t) -> Self::Output {
+                    Mat4x3 {
+                        x: self.x * other,
+                        y: self.y * other,
+                        z: self.z * other,
+                        w: self.w * other,
+                    }
+                }
+            }
+
+            // matrix * vector
+            impl Mul<Vec3<$t>> for Mat4x3<$t> {
+                type Output = Vec4<$t>;
+                fn mul(self,other: Vec3<$t>) -> Self::Output {
+                    Vec4 {
+                        x: self.x.x * other.x + self.x.y * other.y + self.x.z * other.z,
+                        y: self.y.x * other.x + self.y.y * other.y + self.y.z * other.z,
+                        z: self.z.x * other.x + self.z.y * other.y + self.z.z * other.z,
+                        w: self.w.x * other.x + self.w.y * other.y + self.w.z * other.z,
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat3x2<$t>> for Mat4x3<$t> {
+                type Output = Mat4x2<$t>;
+                fn mul(self,other: Mat3x2<$t>) -> Self::Output {
+                    Mat4x2 {
+                        x: Vec2 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y,
+                        },
+                        y: Vec2 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y,
+                        },
+                        z: Vec2 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y,
+                        },
+                        w: Vec2 {
+                            x: self.w.x * other.x.x + self.w.y * other.y.x + self.w.z * other.z.x,
+                            y: self.w.x * other.x.y + self.w.y * other.y.y + self.w.z * other.z.y,
+                        },
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat3x3<$t>> for Mat4x3<$t> {
+                type Output = Mat4x3<$t>;
+                fn mul(self,other: Mat3x3<$t>) -> Self::Output {
+                    Mat4x3 {
+                        x: Vec3 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y,
+                            z: self.x.x * other.x.z + self.x.y * other.y.z + self.x.z * other.z.z,
+                        },
+                        y: Vec3 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y,
+                            z: self.y.x * other.x.z + self.y.y * other.y.z + self.y.z * other.z.z,
+                        },
+                        z: Vec3 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y,
+                            z: self.z.x * other.x.z + self.z.y * other.y.z + self.z.z * other.z.z,
+                        },
+                        w: Vec3 {
+                            x: self.w.x * other.x.x + self.w.y * other.y.x + self.w.z * other.z.x,
+                            y: self.w.x * other.x.y + self.w.y * other.y.y + self.w.z * other.z.y,
+                            z: self.w.x * other.x.z + self.w.y * other.y.z + self.w.z * other.z.z,
+                        },
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat3x4<$t>> for Mat4x3<$t> {
+                type Output = Mat4x4<$t>;
+                fn mul(self,other: Mat3x4<$t>) -> Self::Output {
+                    Mat4x4 {
+                        x: Vec4 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y,
+                            z: self.x.x * other.x.z + self.x.y * other.y.z + self.x.z * other.z.z,
+                            w: self.x.x * other.x.w + self.x.y * other.y.w + self.x.z * other.z.w,
+                        },
+                        y: Vec4 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y,
+                            z: self.y.x * other.x.z + self.y.y * other.y.z + self.y.z * other.z.z,
+                            w: self.y.x * other.x.w + self.y.y * other.y.w + self.y.z * other.z.w,
+                        },
+                        z: Vec4 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y,
+                            z: self.z.x * other.x.z + self.z.y * other.y.z + self.z.z * other.z.z,
+                            w: self.z.x * other.x.w + self.z.y * other.y.w + self.z.z * other.z.w,
+                        },
+                        w: Vec4 {
+                            x: self.w.x * other.x.x + self.w.y * other.y.x + self.w.z * other.z.x,
+                            y: self.w.x * other.x.y + self.w.y * other.y.y + self.w.z * other.z.y,
+                            z: self.w.x * other.x.z + self.w.y * other.y.z + self.w.z * other.z.z,
+                            w: self.w.x * other.x.w + self.w.y * other.y.w + self.w.z * other.z.w,
+                        },
+                    }
+                }
+            }
+
+            // matrix *= scalar
+            impl MulAssign<$t> for Mat4x3<$t> {
+                fn mul_assign(&mut self,other: $t) {
+                    self.x *= other;
+                    self.y *= other;
+                    self.z *= other;
+                    self.w *= other;
+                }
+            }
+
+            // matrix / scalar
+            impl Div<$t> for Mat4x3<$t> {
+                type Output = Mat4x3<$t>;
+                fn div(self,other: $t) -> Self::Output {
+                    Mat4x3 {
+                        x: self.x / other,
+                        y: self.y / other,
+                        z: self.z / other,
+                        w: self.w / other,
+                    }
+                }
+            }
+
+            // matrix /= scalar
+            impl DivAssign<$t> for Mat4x3<$t> {
+                fn div_assign(&mut self,other: $t) {
+                    self.x /= other;
+                    self.y /= other;
+                    self.z /= other;
+                    self.w /= other;
+                }
+            }
+
+            // -matrix
+            impl Neg for Mat4x3<$t> {
+                type Output = Mat4x3<$t>;
+                fn neg(self) -> Self::Output {
+                    Mat4x3 {
+                        x: -self.x,
+                        y: -self.y,
+                        z: -self.z,
+                        w: -self.w,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+mat4x3_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
@@ -39,29 +39,37 @@ macro_rules! mat4x4_impl {
                             x: m.x.x,
                             y: m.x.y,
                             z: m.x.z,
-                            w: <$t>::ZERO,
+                            w: v.x,
                         },
                         y: Vec4 {
                             x: m.y.x,
                             y: m.y.y,
                             z: m.y.z,
-                            w: <$t>::ZERO,
+                            w: v.y,
                         },
                         z: Vec4 {
                             x: m.z.x,
                             y: m.z.y,
                             z: m.z.z,
-                            w: <$t>::ZERO,
+                            w: v.z,
                         },
                         w: Vec4 {
-                            x: v.x,
-                            y: v.y,
-                            z: v.z,
+                            x: <$t>::ZERO,
+                            y: <$t>::ZERO,
+                            z: <$t>::ZERO,
                             w: <$t>::ONE,
                         },
                     }
                 }
 
+                pub fn from_translation(t: Vec3<$t>) -> Mat4x4<$t> {
+                    Mat4x4::<$t>::from_mv(Mat3x3::ONE,t)
+                }
+
+                pub fn from_scale(s: Vec3<$t>) -> Mat4x4<$t> {
+                    Mat4x4::<$t>::from_mv(Mat3x3::<$t>::from_scale(s),Vec3::ZERO)
+                }
+
                 pub fn transpose(self) -> Mat4x4<$t> {
                     Mat4x4 {
                         x: Vec4 {
@@ -252,6 +260,60 @@ macro_rules! mat4x4_impl {
                 }
             }
 
+            // matrix * matrix
+            impl Mul<Mat4x2<$t>> for Mat4x4<$t> {
+                type Output = Mat4x2<$t>;
+                fn mul(self,other: Mat4x2<$t>) -> Self::Output {
+                    Mat4x2 {
+                        x: Vec2 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x + self.x.w * other.w.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y + self.x.w * other.w.y,
+                        },
+                        y: Vec2 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x + self.y.w * other.w.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y + self.y.w * other.w.y,
+                        },
+                        z: Vec2 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x + self.z.w * other.w.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y + self.z.w * other.w.y,
+                        },
+                        w: Vec2 {
+                            x: self.w.x * other.x.x + self.w.y * other.y.x + self.w.z * other.z.x + self.w.w * other.w.x,
+                            y: self.w.x * other.x.y + self.w.y * other.y.y + self.w.z * other.z.y + self.w.w * other.w.y,
+                        },
+                    }
+                }
+            }
+
+            // matrix * matrix
+            impl Mul<Mat4x3<$t>> for Mat4x4<$t> {
+                type Output = Mat4x3<$t>;
+                fn mul(self,other: Mat4x3<$t>) -> Self::Output {
+                    Mat4x3 {
+                        x: Vec3 {
+                            x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x + self.x.w * other.w.x,
+                            y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y + self.x.w * other.w.y,
+                            z: self.x.x * other.x.z + self.x.y * other.y.z + self.x.z * other.z.z + self.x.w * other.w.z,
+                        },
+                        y: Vec3 {
+                            x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x + self.y.w * other.w.x,
+                            y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y + self.y.w * other.w.y,
+                            z: self.y.x * other.x.z + self.y.y * other.y.z + self.y.z * other.z.z + self.y.w * other.w.z,
+                        },
+                        z: Vec3 {
+                            x: self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x + self.z.w * other.w.x,
+                            y: self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y + self.z.w * other.w.y,
+                            z: self.z.x * other.x.z + self.z.y * other.y.z + self.z.z * other.z.z + self.z.w * other.w.z,
+                        },
+                        w: Vec3 {
+                            x: self.w.x * other.x.x + self.w.y * other.y.x + self.w.z * other.z.x + self.w.w * other.w.x,
+                            y: self.w.x * other.x.y + self.w.y * other.y.y + self.w.z * other.z.y + self.w.w * other.w.y,
+                            z: self.w.x * other.x.z + self.w.y * other.y.z + self.w.z * other.z.z + self.w.w * other.w.z,
+                        },
+                    }
+                }
+            }
+
             // matrix *= scalar
             impl MulAssign<$t> for Mat4x4<$t> {
                 fn mul_assign(&mut self,other: $t) {
@@ -330,24 +392,24 @@ macro_rules! mat4x4_impl {
                             x: <$t>::ONE,
                             y: <$t>::ZERO,
                             z: <$t>::ZERO,
-                            w: <$t>::ZERO,
+                            w: value.x,
                         },
                         y: Vec4 {
                             x: <$t>::ZERO,
                             y: <$t>::ONE,
                             z: <$t>::ZERO,
-                            w: <$t>::ZERO,
+                            w: value.y,
                         },
                         z: Vec4 {
                             x: <$t>::ZERO,
                             y: <$t>::ZERO,
                             z: <$t>::ONE,
-                            w: <$t>::ZERO,
+                            w: value.z,
                         },
                         w: Vec4 {
-                            x: value.x,
-                            y: value.y,
-                            z: value.z,
+                            x: <$t>::ZERO,
+                            y: <$t>::ZERO,
+                            z: <$t>::ZERO,
                             w: <$t>::ONE,
                         },
                     }
@@ -371,19 +433,19 @@ macro_rules! mat4x4_impl {
                     Mat4x4 {
                         x: Vec4 {
                             x: <$t>::ONE - yy2 - zz2,
-                            y: xy2 + wz2,
-                            z: xz2 - wy2,
+                            y: xy2 - wz2,
+                            z: xz2 + wy2,
                             w: <$t>::ZERO,
                         },
                         y: Vec4 {
-                            x: xy2 - wz2,
+                            x: xy2 + wz2,
                             y: <$t>::ONE - xx2 - zz2,
-                            z: yz2 + wx2,
+                            z: yz2 - wx2,
                             w: <$t>::ZERO,
                         },
                         z: Vec4 {
-                            x: xz2 + wy2,
-                            y: yz2 - wx2,
+                            x: xz2 - wy2,
+                            y: yz2 + wx2,
                             z: <$t>::ONE - xx2 - yy2,
                             w: <$t>::ZERO,
                         },
@@ -396,17 +458,114 @@ macro_rules! mat4x4_impl {
                     }
                 }
             }
+
+            impl From<Mat4x4<$t>> for Mat<$t> {
+                fn from(value: Mat4x4<$t>) -> Self {
+                    Mat::<$t>::from_vec(4,4,vec![
+                        value.x.x,value.x.y,value.x.z,value.x.w,
+                        value.y.x,value.y.y,value.y.z,value.y.w,
+                        value.z.x,value.z.y,value.z.z,value.z.w,
+                        value.w.x,value.w.y,value.w.z,value.w.w,
+                    ])
+                }
+            }
+
+            impl From<Mat<$t>> for Mat4x4<$t> {
+                fn from(value: Mat<$t>) -> Self {
+                    assert!((value.r == 4) && (value.c == 4),"Mat4x4::from: {}x{} matrix",value.r,value.c);
+                    Mat4x4 {
+                        x: Vec4 { x: value[(0,0)],y: value[(0,1)],z: value[(0,2)],w: value[(0,3)], },
+                        y: Vec4 { x: value[(1,0)],y: value[(1,1)],z: value[(1,2)],w: value[(1,3)], },
+                        z: Vec4 { x: value[(2,0)],y: value[(2,1)],z: value[(2,2)],w: value[(2,3)], },
+                        w: Vec4 { x: value[(3,0)],y: value[(3,1)],z: value[(3,2)],w: value[(3,3)], },
+                    }
+                }
+            }
         )+
     }
 }
 
-mat4x4_impl! { isize i8 i16 i32 i64 i128 f32 f64 }
+mat4x4_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
 
 macro_rules! mat4x4_real_impl {
     ($($t:ty)+) => {
         $(
             impl Mat4x4<$t> {
-                pub fn inv(self) -> Self {
+                /// Rotation counterclockwise by `angle` radians about `axis`.
+                pub fn from_axis_angle(axis: Vec3<$t>,angle: $t) -> Mat4x4<$t> {
+                    Mat4x4::<$t>::from_mv(Mat3x3::<$t>::from_axis_angle(axis,angle),Vec3::ZERO)
+                }
+
+                /// Perspective projection for Vulkan.
+                ///
+                /// Maps right-handed view space, looking down -Z with Y up, to clip space with Y down and depth 0 at `near`
+                /// to 1 at `far`. `fovy` is the vertical field of view in radians, `aspect` is width / height.
+                pub fn perspective(fovy: $t,aspect: $t,near: $t,far: $t) -> Mat4x4<$t> {
+                    let f = <$t>::ONE / (0.5 * fovy).tan();
+                    let d = <$t>::ONE / (near - far);
+                    Mat4x4 {
+                        x: Vec4 { x: f / aspect,y: <$t>::ZERO,z: <$t>::ZERO,w: <$t>::ZERO, },
+                        y: Vec4 { x: <$t>::ZERO,y: -f,z: <$t>::ZERO,w: <$t>::ZERO, },
+                        z: Vec4 { x: <$t>::ZERO,y: <$t>::ZERO,z: far * d,w: near * far * d, },
+                        w: Vec4 { x: <$t>::ZERO,y: <$t>::ZERO,z: -<$t>::ONE,w: <$t>::ZERO, },
+                    }
+                }
+
+                /// Perspective projection for Vulkan with reversed depth and the far plane at infinity.
+                ///
+                /// Like [`Mat4x4::perspective`], but depth is 1 at `near` and goes to 0 at infinity.
+                pub fn perspective_infinite_reverse(fovy: $t,aspect: $t,near: $t) -> Mat4x4<$t> {
+                    let f = <$t>::ONE / (0.5 * fovy).tan();
+                    Mat4x4 {
+                        x: Vec4 { x: f / aspect,y: <$t>::ZERO,z: <$t>::ZERO,w: <$t>::ZERO, },
+                        y: Vec4 { x: <$t>::ZERO,y: -f,z: <$t>::ZERO,w: <$t>::ZERO, },
+                        z: Vec4 { x: <$t>::ZERO,y: <$t>::ZERO,z: <$t>::ZERO,w: near, },
+                        w: Vec4 { x: <$t>::ZERO,y: <$t>::ZERO,z: -<$t>::ONE,w: <$t>::ZERO, },
+                    }
+                }
+
+                /// Orthographic projection for Vulkan.
+                ///
+                /// Maps the view space box to clip space with Y down and depth 0 at `near` to 1 at `far`, looking down -Z.
+                pub fn orthographic(left: $t,right: $t,bottom: $t,top: $t,near: $t,far: $t) -> Mat4x4<$t> {
+                    let w = <$t>::ONE / (right - left);
+                    let h = <$t>::ONE / (top - bottom);
+                    let d = <$t>::ONE / (far - near);
+                    Mat4x4 {
+                        x: Vec4 { x: 2.0 * w,y: <$t>::ZERO,z: <$t>::ZERO,w: -(right + left) * w, },
+                        y: Vec4 { x: <$t>::ZERO,y: -2.0 * h,z: <$t>::ZERO,w: (top + bottom) * h, },
+                        z: Vec4 { x: <$t>::ZERO,y: <$t>::ZERO,z: -d,w: -near * d, },
+                        w: Vec4 { x: <$t>::ZERO,y: <$t>::ZERO,z: <$t>::ZERO,w: <$t>::ONE, },
+                    }
+                }
+
+                /// View matrix for a camera at `eye` looking at `target`, with `up` pointing roughly up.
+                ///
+                /// The result maps world space to right-handed view space, looking down -Z with Y up.
+                pub fn look_at(eye: Vec3<$t>,target: Vec3<$t>,up: Vec3<$t>) -> Mat4x4<$t> {
+                    let f = target - eye;
+                    let f = f / f.dot(f).sqrt();
+                    let s = Vec3 {
+                        x: f.y * up.z - f.z * up.y,
+                        y: f.z * up.x - f.x * up.z,
+                        z: f.x * up.y - f.y * up.x,
+                    };
+                    let s = s / s.dot(s).sqrt();
+                    let u = Vec3 {
+                        x: s.y * f.z - s.z * f.y,
+                        y: s.z * f.x - s.x * f.z,
+                        z: s.x * f.y - s.y * f.x,
+                    };
+                    Mat4x4 {
+                        x: Vec4 { x: s.x,y: s.y,z: s.z,w: -s.dot(eye), },
+                        y: Vec4 { x: u.x,y: u.y,z: u.z,w: -u.dot(eye), },
+                        z: Vec4 { x: -f.x,y: -f.y,z: -f.z,w: f.dot(eye), },
+                        w: Vec4 { x: <$t>::ZERO,y: <$t>::ZERO,z: <$t>::ZERO,w: <$t>::ONE, },
+                    }
+                }
+
+                /// Inverse, or `None` if the absolute value of the determinant is not above `epsilon`.
+                pub fn try_inv(self,epsilon: $t) -> Option<Self> {
                     let a = self.x.x;
                     let e = self.x.y;
                     let i = self.x.z;
@@ -429,15 +588,15 @@ macro_rules! mat4x4_real_impl {
                     let iplm = i * p - l * m;
                     let iokm = i * o - k * m;
                     let injm = i * n - j * m;
-                    let aa = f * kplo - g * jpln + k * iokm;
+                    let aa = f * kplo - g * jpln + h * jokn;
                     let ab = e * kplo - g * iplm + h * iokm;
                     let ac = e * jpln - f * iplm + h * injm;
                     let ad = e * jokn - f * iokm + g * injm;
                     let det = a * aa - b * ab + c * ac - d * ad;
-                    if det == 0.0 {
-                        return self;
+                    if det.abs() <= epsilon {
+                        return None;
                     }
-                    let ae = e * kplo - c * jpln + d * jokn;
+                    let ae = b * kplo - c * jpln + d * jokn;
                     let af = a * kplo - c * iplm + d * iokm;
                     let ag = a * jpln - b * iplm + d * injm;
                     let ah = a * jokn - b * iokm + c * injm;
@@ -455,12 +614,50 @@ macro_rules! mat4x4_real_impl {
                     let an = i * chdg - k * ahde + l * agce;
                     let ao = i * bhdf - j * ahde + l * afbe;
                     let ap = i * bgcf - j * agce + k * afbe;
-                    Mat4x4 {
-                        x: Vec4 { x: aa,y: -ae,z: ai,w: -am, },
-                        y: Vec4 { x: -ab,y: af,z: -aj,w: an, },
-                        z: Vec4 { x: ac,y: -ag,z: ak,w: -ao, },
-                        w: Vec4 { x: -ad,y: ah,z: -al,w: ap, },
-                    } / det
+                    Some(Mat4x4 {
+                        x: Vec4 { x: aa,y: -ab,z: ac,w: -ad, },
+                        y: Vec4 { x: -ae,y: af,z: -ag,w: ah, },
+                        z: Vec4 { x: ai,y: -aj,z: ak,w: -al, },
+                        w: Vec4 { x: -am,y: an,z: -ao,w: ap, },
+                    } / det)
+                }
+
+                pub fn inv(self) -> Self {
+                    self.try_inv(<$t>::ZERO).unwrap_or(self)
+                }
+
+                /// Inverse of an affine transformation, where the bottom row is (0,0,0,1).
+                pub fn inv_affine(self) -> Self {
+                    let m = Mat3x3 {
+                        x: Vec3 { x: self.x.x,y: self.x.y,z: self.x.z, },
+                        y: Vec3 { x: self.y.x,y: self.y.y,z: self.y.z, },
+                        z: Vec3 { x: self.z.x,y: self.z.y,z: self.z.z, },
+                    }.inv();
+                    let t = Vec3 { x: self.x.w,y: self.y.w,z: self.z.w, };
+                    Mat4x4::<$t>::from_mv(m,-(m * t))
+                }
+
+                /// Inverse of a rigid transformation, where the upper left 3x3 is a rotation and the bottom row is (0,0,0,1).
+                pub fn inv_rigid(self) -> Self {
+                    let m = Mat3x3 {
+                        x: Vec3 { x: self.x.x,y: self.y.x,z: self.z.x, },
+                        y: Vec3 { x: self.x.y,y: self.y.y,z: self.z.y, },
+                        z: Vec3 { x: self.x.z,y: self.y.z,z: self.z.z, },
+                    };
+                    let t = Vec3 { x: self.x.w,y: self.y.w,z: self.z.w, };
+                    Mat4x4::<$t>::from_mv(m,-(m * t))
+                }
+
+                /// Eigenvalues and eigenvectors of a symmetric matrix, see [`Mat::symmetric_eigen`].
+                pub fn symmetric_eigen(self) -> (Vec4<$t>,Mat4x4<$t>) {
+                    let (v,vectors) = Mat::<$t>::from(self).symmetric_eigen();
+                    (Vec4 { x: v[0],y: v[1],z: v[2],w: v[3], },Mat4x4::<$t>::from(vectors))
+                }
+
+                /// Singular value decomposition `u * diag(s) * v^T`, see [`Mat::svd`].
+                pub fn svd(self) -> (Mat4x4<$t>,Vec4<$t>,Mat4x4<$t>) {
+                    let (u,s,v) = Mat::<$t>::from(self).svd();
+                    (Mat4x4::<$t>::from(u),Vec4 { x: s[0],y: s[1],z: s[2],w: s[3], },Mat4x4::<$t>::from(v))
                 }
             }
 
@@ -12,6 +12,15 @@ pub use unsigned::*;
 mod signed;
 pub use signed::*;
 
+mod integer;
+pub use integer::*;
+
+mod biguint;
+pub use biguint::*;
+
+mod bigint;
+pub use bigint::*;
+
 mod rational;
 pub use rational::*;
 
@@ -21,12 +30,24 @@ pub use real::*;
 mod float;
 pub use float::*;
 
+mod half;
+pub use half::*;
+
 mod fixed;
 pub use fixed::*;
 
+mod cast;
+pub use cast::*;
+
+mod approx;
+pub use approx::*;
+
 mod complex;
 pub use complex::*;
 
+mod fft;
+pub use fft::*;
+
 mod quaternion;
 pub use quaternion::*;
 
@@ -39,32 +60,34 @@ pub use vec3::*;
 mod vec4;
 pub use vec4::*;
 
+mod swizzle;
+
 mod mat;
 pub use mat::*;
 
 mod mat2x2;
 pub use mat2x2::*;
 
-//mod mat2x3;
-//pub use mat2x3::*;
+mod mat2x3;
+pub use mat2x3::*;
 
-//mod mat2x4;
-//pub use mat2x4::*;
+mod mat2x4;
+pub use mat2x4::*;
 
-//mod mat3x2;
-//pub use mat3x2::*;
+mod mat3x2;
+pub use mat3x2::*;
 
 mod mat3x3;
 pub use mat3x3::*;
 
-//mod mat3x4;
-//pub use mat3x4::*;
+mod mat3x4;
+pub use mat3x4::*;
 
-//mod mat4x2;
-//pub use mat4x2::*;
+mod mat4x2;
+pub use mat4x2::*;
 
-//mod mat4x3;
-//pub use mat4x3::*;
+mod mat4x3;
+pub use mat4x3::*;
 
 mod mat4x4;
 pub use mat4x4::*;
@@ -78,8 +101,26 @@ pub use multivec3::*;
 mod multivec4;
 pub use multivec4::*;
 
+mod rotor;
+pub use rotor::*;
+
+mod motor;
+pub use motor::*;
+
 mod rect;
 pub use rect::*;
 
+mod rect3;
+pub use rect3::*;
+
+mod geometry;
+pub use geometry::*;
+
+mod bvh;
+pub use bvh::*;
+
 mod pose;
 pub use pose::*;
+
+mod dualquaternion;
+pub use dualquaternion::*;
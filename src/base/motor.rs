@@ -0,0 +1,352 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Mul,
+            MulAssign,
+            Neg,
+        },
+    },
+};
+
+/// 3D motor template for projective geometric algebra.
+///
+/// A motor is the even-grade part of the projective geometric algebra of 3D space, where the extra basis vector `w` squares
+/// to zero. It consists of a [`Rotor`] (`r`, `xy`, `xz` and `yz`), three ideal bivectors `xw`, `yw` and `zw` that carry the
+/// translation, and the pseudoscalar `xyzw`. A unit motor describes any rigid body motion (a screw motion), and motors
+/// compose by the geometric product.
+#[derive(Copy,Clone,Debug)]
+pub struct Motor<T> {
+    pub r: T,
+    pub xy: T,
+    pub xz: T,
+    pub yz: T,
+    pub xw: T,
+    pub yw: T,
+    pub zw: T,
+    pub xyzw: T,
+}
+
+macro_rules! motor_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Motor<$t> {
+                /// Create motor that first rotates by `rotor` and then translates by `translation`.
+                pub fn new(rotor: Rotor<$t>,translation: Vec3<$t>) -> Self {
+                    let t = 0.5 * translation;
+                    Motor {
+                        r: rotor.r,
+                        xy: rotor.xy,
+                        xz: rotor.xz,
+                        yz: rotor.yz,
+                        xw: rotor.r * t.x - rotor.xy * t.y - rotor.xz * t.z,
+                        yw: rotor.r * t.y + rotor.xy * t.x - rotor.yz * t.z,
+                        zw: rotor.r * t.z + rotor.xz * t.x + rotor.yz * t.y,
+                        xyzw: rotor.xy * t.z - rotor.xz * t.y + rotor.yz * t.x,
+                    }
+                }
+
+                pub fn from_translation(translation: Vec3<$t>) -> Self {
+                    Motor::<$t>::new(Rotor::ONE,translation)
+                }
+
+                /// Create motor that rotates counterclockwise by `angle` radians around `axis` through the origin.
+                pub fn from_axis_angle(axis: Vec3<$t>,angle: $t) -> Self {
+                    Motor::<$t>::from(Rotor::<$t>::from_axis_angle(axis,angle))
+                }
+
+                /// Create motor that rotates the direction of `a` onto the direction of `b` along the shortest arc.
+                pub fn from_vectors(a: Vec3<$t>,b: Vec3<$t>) -> Self {
+                    Motor::<$t>::from(Rotor::<$t>::from_vectors(a,b))
+                }
+
+                /// Rotation part of the motor.
+                pub fn rotor(self) -> Rotor<$t> {
+                    Rotor {
+                        r: self.r,
+                        xy: self.xy,
+                        xz: self.xz,
+                        yz: self.yz,
+                    }
+                }
+
+                /// Translation part of the motor, applied after the rotation.
+                pub fn translation(self) -> Vec3<$t> {
+                    let f = 2.0 / self.rotor().norm_sqr();
+                    Vec3 {
+                        x: (self.r * self.xw + self.xy * self.yw + self.xz * self.zw + self.yz * self.xyzw) * f,
+                        y: (self.r * self.yw - self.xy * self.xw + self.yz * self.zw - self.xz * self.xyzw) * f,
+                        z: (self.r * self.zw - self.xz * self.xw - self.yz * self.yw + self.xy * self.xyzw) * f,
+                    }
+                }
+
+                pub fn reverse(self) -> Self {
+                    Motor {
+                        r: self.r,
+                        xy: -self.xy,
+                        xz: -self.xz,
+                        yz: -self.yz,
+                        xw: -self.xw,
+                        yw: -self.yw,
+                        zw: -self.zw,
+                        xyzw: self.xyzw,
+                    }
+                }
+
+                pub fn norm(self) -> $t {
+                    self.rotor().norm()
+                }
+
+                /// Normalize the motor, so that it times its reverse is one.
+                pub fn normalize(self) -> Self {
+                    // self * self.reverse() = a + b xyzw, multiply by its inverse square root
+                    let a = self.rotor().norm_sqr();
+                    let b = 2.0 * (self.r * self.xyzw - self.xw * self.yz - self.xy * self.zw + self.xz * self.yw);
+                    let s = <$t>::ONE / a.sqrt();
+                    let p = -0.5 * b * s / a;
+                    self.scale_dual(s,p)
+                }
+
+                pub fn inv(self) -> Self {
+                    let a = self.rotor().norm_sqr();
+                    let b = 2.0 * (self.r * self.xyzw - self.xw * self.yz - self.xy * self.zw + self.xz * self.yw);
+                    self.reverse().scale_dual(<$t>::ONE / a,-b / (a * a))
+                }
+
+                /// Exponential, the motor generated by the bivector parts of `self`.
+                pub fn exp(self) -> Self {
+                    let l = self.xy * self.xy + self.xz * self.xz + self.yz * self.yz;
+                    if l == <$t>::ZERO {
+                        return Motor {
+                            r: <$t>::ONE,
+                            xy: <$t>::ZERO,
+                            xz: <$t>::ZERO,
+                            yz: <$t>::ZERO,
+                            xw: self.xw,
+                            yw: self.yw,
+                            zw: self.zw,
+                            xyzw: <$t>::ZERO,
+                        };
+                    }
+                    let m = self.xw * self.yz + self.xy * self.zw - self.xz * self.yw;
+                    let a = l.sqrt();
+                    let (s,c) = a.sin_cos();
+                    let s = s / a;
+                    let t = m / l * (c - s);
+                    Motor {
+                        r: c,
+                        xy: s * self.xy,
+                        xz: s * self.xz,
+                        yz: s * self.yz,
+                        xw: s * self.xw + t * self.yz,
+                        yw: s * self.yw - t * self.xz,
+                        zw: s * self.zw + t * self.xy,
+                        xyzw: m * s,
+                    }
+                }
+
+                /// Logarithm of a unit motor, as a motor with only bivector parts.
+                pub fn ln(self) -> Self {
+                    let l = self.xy * self.xy + self.xz * self.xz + self.yz * self.yz;
+                    if l <= <$t>::EPSILON * <$t>::EPSILON {
+                        return Motor {
+                            r: <$t>::ZERO,
+                            xy: <$t>::ZERO,
+                            xz: <$t>::ZERO,
+                            yz: <$t>::ZERO,
+                            xw: self.xw / self.r,
+                            yw: self.yw / self.r,
+                            zw: self.zw / self.r,
+                            xyzw: <$t>::ZERO,
+                        };
+                    }
+                    let a = l.sqrt().atan2(self.r);
+                    let s = l.sqrt() / a;
+                    let xy = self.xy / s;
+                    let xz = self.xz / s;
+                    let yz = self.yz / s;
+                    let m = self.xyzw / s;
+                    let t = m / (a * a) * (self.r - s);
+                    Motor {
+                        r: <$t>::ZERO,
+                        xy,
+                        xz,
+                        yz,
+                        xw: (self.xw - t * yz) / s,
+                        yw: (self.yw + t * xz) / s,
+                        zw: (self.zw - t * xy) / s,
+                        xyzw: <$t>::ZERO,
+                    }
+                }
+
+                /// Screw linear interpolation, moves at constant speed along the screw motion from `self` to `other`.
+                pub fn sclerp(self,other: Self,t: $t) -> Self {
+                    let d = self.reverse() * other;
+                    let d = if d.r < <$t>::ZERO { -d } else { d };
+                    self * (d.ln() * t).exp()
+                }
+
+                // multiply by the scalar s plus the pseudoscalar p, which commutes with everything and squares to zero
+                fn scale_dual(self,s: $t,p: $t) -> Self {
+                    Motor {
+                        r: s * self.r,
+                        xy: s * self.xy,
+                        xz: s * self.xz,
+                        yz: s * self.yz,
+                        xw: s * self.xw - p * self.yz,
+                        yw: s * self.yw + p * self.xz,
+                        zw: s * self.zw - p * self.xy,
+                        xyzw: s * self.xyzw + p * self.r,
+                    }
+                }
+            }
+
+            impl Display for Motor<$t> {
+                fn fmt(&self,f: &mut Formatter) -> Result {
+                    write!(f,"({}, {},{},{}, {},{},{}, {})",
+                        self.r,
+                        self.xy,self.xz,self.yz,
+                        self.xw,self.yw,self.zw,
+                        self.xyzw
+                    )
+                }
+            }
+
+            impl PartialEq for Motor<$t> {
+                fn eq(&self,other: &Self) -> bool {
+                    (self.r == other.r)
+                    && (self.xy == other.xy) && (self.xz == other.xz) && (self.yz == other.yz)
+                    && (self.xw == other.xw) && (self.yw == other.yw) && (self.zw == other.zw)
+                    && (self.xyzw == other.xyzw)
+                }
+            }
+
+            impl One for Motor<$t> {
+                const ONE: Motor<$t> = Motor {
+                    r: <$t>::ONE,
+                    xy: <$t>::ZERO,xz: <$t>::ZERO,yz: <$t>::ZERO,
+                    xw: <$t>::ZERO,yw: <$t>::ZERO,zw: <$t>::ZERO,
+                    xyzw: <$t>::ZERO,
+                };
+            }
+
+            // motor * scalar
+            impl Mul<$t> for Motor<$t> {
+                type Output = Motor<$t>;
+                fn mul(self,other: $t) -> Self::Output {
+                    Motor {
+                        r: self.r * other,
+                        xy: self.xy * other,
+                        xz: self.xz * other,
+                        yz: self.yz * other,
+                        xw: self.xw * other,
+                        yw: self.yw * other,
+                        zw: self.zw * other,
+                        xyzw: self.xyzw * other,
+                    }
+                }
+            }
+
+            // motor * point
+            impl Mul<Vec3<$t>> for Motor<$t> {
+                type Output = Vec3<$t>;
+                fn mul(self,other: Vec3<$t>) -> Self::Output {
+                    self.rotor() * other + self.translation()
+                }
+            }
+
+            // motor * motor
+            impl Mul<Motor<$t>> for Motor<$t> {
+                type Output = Motor<$t>;
+                fn mul(self,other: Motor<$t>) -> Self::Output {
+                    Motor {
+                        r: self.r * other.r - self.xy * other.xy - self.xz * other.xz - self.yz * other.yz,
+                        xy: self.r * other.xy + self.xy * other.r - self.xz * other.yz + self.yz * other.xz,
+                        xz: self.r * other.xz + self.xy * other.yz + self.xz * other.r - self.yz * other.xy,
+                        yz: self.r * other.yz - self.xy * other.xz + self.xz * other.xy + self.yz * other.r,
+                        xw: self.r * other.xw + self.xw * other.r + self.xy * other.yw - self.yw * other.xy + self.xz * other.zw - self.zw * other.xz - self.yz * other.xyzw - self.xyzw * other.yz,
+                        yw: self.r * other.yw + self.yw * other.r - self.xy * other.xw + self.xw * other.xy + self.yz * other.zw - self.zw * other.yz + self.xz * other.xyzw + self.xyzw * other.xz,
+                        zw: self.r * other.zw + self.zw * other.r - self.xz * other.xw + self.xw * other.xz - self.yz * other.yw + self.yw * other.yz - self.xy * other.xyzw - self.xyzw * other.xy,
+                        xyzw: self.r * other.xyzw + self.xyzw * other.r + self.xy * other.zw + self.zw * other.xy - self.xz * other.yw - self.yw * other.xz + self.yz * other.xw + self.xw * other.yz,
+                    }
+                }
+            }
+
+            // motor *= motor
+            impl MulAssign<Motor<$t>> for Motor<$t> {
+                fn mul_assign(&mut self,other: Motor<$t>) {
+                    *self = *self * other;
+                }
+            }
+
+            // -motor
+            impl Neg for Motor<$t> {
+                type Output = Motor<$t>;
+                fn neg(self) -> Self::Output {
+                    Motor {
+                        r: -self.r,
+                        xy: -self.xy,
+                        xz: -self.xz,
+                        yz: -self.yz,
+                        xw: -self.xw,
+                        yw: -self.yw,
+                        zw: -self.zw,
+                        xyzw: -self.xyzw,
+                    }
+                }
+            }
+
+            impl From<Rotor<$t>> for Motor<$t> {
+                fn from(value: Rotor<$t>) -> Self {
+                    Motor::<$t>::new(value,Vec3::ZERO)
+                }
+            }
+
+            impl From<Quaternion<$t>> for Motor<$t> {
+                fn from(value: Quaternion<$t>) -> Self {
+                    Motor::<$t>::from(Rotor::from(value))
+                }
+            }
+
+            /// Only the rotation part is kept.
+            impl From<Motor<$t>> for Quaternion<$t> {
+                fn from(value: Motor<$t>) -> Self {
+                    Quaternion::from(value.rotor())
+                }
+            }
+
+            impl From<Mat3x3<$t>> for Motor<$t> {
+                fn from(value: Mat3x3<$t>) -> Self {
+                    Motor::<$t>::from(Rotor::from(value))
+                }
+            }
+
+            /// Only the rotation part is kept.
+            impl From<Motor<$t>> for Mat3x3<$t> {
+                fn from(value: Motor<$t>) -> Self {
+                    Mat3x3::from(value.rotor().normalize())
+                }
+            }
+
+            impl From<Motor<$t>> for Mat4x4<$t> {
+                fn from(value: Motor<$t>) -> Self {
+                    Mat4x4::<$t>::from_mv(Mat3x3::from(value.rotor().normalize()),value.translation())
+                }
+            }
+
+            impl From<Mat4x4<$t>> for Motor<$t> {
+                fn from(value: Mat4x4<$t>) -> Self {
+                    Motor::<$t>::new(Rotor::from(value),Vec3 { x: value.x.w,y: value.y.w,z: value.z.w, })
+                }
+            }
+        )+
+    }
+}
+
+motor_impl! { f32 f64 }
@@ -8,6 +8,17 @@ use {
             Formatter,
             Result,
         },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+        },
     },
 };
 
@@ -27,7 +38,91 @@ macro_rules! multivec2_impl {
     ($($t:ty)+) => {
         $(
             impl MultiVec2<$t> {
+                /// Reverse, reverses the order of the vectors in each blade.
+                pub fn reverse(self) -> Self {
+                    MultiVec2 {
+                        r: self.r,
+                        x: self.x,
+                        y: self.y,
+                        xy: -self.xy,
+                    }
+                }
+
+                /// Grade involution, flips the sign of the odd grades.
+                pub fn involute(self) -> Self {
+                    MultiVec2 {
+                        r: self.r,
+                        x: -self.x,
+                        y: -self.y,
+                        xy: self.xy,
+                    }
+                }
+
+                /// Clifford conjugate, the combination of [`reverse`](Self::reverse) and [`involute`](Self::involute).
+                pub fn conj(self) -> Self {
+                    MultiVec2 {
+                        r: self.r,
+                        x: -self.x,
+                        y: -self.y,
+                        xy: -self.xy,
+                    }
+                }
+
+                /// Grade projection, keeps only the parts of grade `k`.
+                pub fn grade(self,k: usize) -> Self {
+                    match k {
+                        0 => MultiVec2 { r: self.r,..Self::ZERO },
+                        1 => MultiVec2 { x: self.x,y: self.y,..Self::ZERO },
+                        2 => MultiVec2 { xy: self.xy,..Self::ZERO },
+                        _ => Self::ZERO,
+                    }
+                }
 
+                /// Dual, the multivector multiplied by the inverse of the pseudoscalar `xy`.
+                pub fn dual(self) -> Self {
+                    MultiVec2 {
+                        r: self.xy,
+                        x: self.y,
+                        y: -self.x,
+                        xy: -self.r,
+                    }
+                }
+
+                /// Outer (wedge) product.
+                pub fn outer(self,other: Self) -> Self {
+                    MultiVec2 {
+                        r: self.r * other.r,
+                        x: self.r * other.x + self.x * other.r,
+                        y: self.r * other.y + self.y * other.r,
+                        xy: self.r * other.xy + self.x * other.y - self.y * other.x + self.xy * other.r,
+                    }
+                }
+
+                /// Inner product, as left contraction.
+                pub fn inner(self,other: Self) -> Self {
+                    MultiVec2 {
+                        r: self.r * other.r + self.x * other.x + self.y * other.y - self.xy * other.xy,
+                        x: self.r * other.x - self.y * other.xy,
+                        y: self.r * other.y + self.x * other.xy,
+                        xy: self.r * other.xy,
+                    }
+                }
+
+                /// Squared norm, the scalar part of the multivector times its reverse.
+                pub fn norm_sqr(self) -> $t {
+                    self.r * self.r + self.x * self.x + self.y * self.y + self.xy * self.xy
+                }
+
+                /// Norm, the square root of [`norm_sqr`](Self::norm_sqr).
+                pub fn norm(self) -> $t {
+                    self.norm_sqr().sqrt()
+                }
+
+                /// Inverse, so that `self * self.inv()` is one. Not finite when the multivector has no inverse.
+                pub fn inv(self) -> Self {
+                    let c = self.conj();
+                    c / (self * c).r
+                }
             }
 
             impl Display for MultiVec2<$t> {
@@ -56,31 +151,172 @@ macro_rules! multivec2_impl {
                 };
             }
 
+            impl One for MultiVec2<$t> {
+                const ONE: MultiVec2<$t> = MultiVec2 {
+                    r: <$t>::ONE,
+                    x: <$t>::ZERO,y: <$t>::ZERO,
+                    xy: <$t>::ZERO,
+                };
+            }
+
             // multivector + multivector
+            impl Add<MultiVec2<$t>> for MultiVec2<$t> {
+                type Output = MultiVec2<$t>;
+                fn add(self,other: Self) -> Self::Output {
+                    MultiVec2 {
+                        r: self.r + other.r,
+                        x: self.x + other.x,
+                        y: self.y + other.y,
+                        xy: self.xy + other.xy,
+                    }
+                }
+            }
 
             // multivector += multivector
+            impl AddAssign<MultiVec2<$t>> for MultiVec2<$t> {
+                fn add_assign(&mut self,other: Self) {
+
+                    self.r += other.r;
+                    self.x += other.x;
+                    self.y += other.y;
+                    self.xy += other.xy;
+                }
+            }
 
             // multivector - multivector
+            impl Sub<MultiVec2<$t>> for MultiVec2<$t> {
+                type Output = MultiVec2<$t>;
+                fn sub(self,other: Self) -> Self::Output {
+                    MultiVec2 {
+                        r: self.r - other.r,
+                        x: self.x - other.x,
+                        y: self.y - other.y,
+                        xy: self.xy - other.xy,
+                    }
+                }
+            }
 
             // multivector -= multivector
-            
+            impl SubAssign<MultiVec2<$t>> for MultiVec2<$t> {
+                fn sub_assign(&mut self,other: Self) {
+
+                    self.r -= other.r;
+                    self.x -= other.x;
+                    self.y -= other.y;
+                    self.xy -= other.xy;
+                }
+            }
+
             // multivector * scalar
+            impl Mul<$t> for MultiVec2<$t> {
+                type Output = MultiVec2<$t>;
+                fn mul(self,other: $t) -> Self::Output {
+                    MultiVec2 {
+                        r: self.r * other,
+                        x: self.x * other,
+                        y: self.y * other,
+                        xy: self.xy * other,
+                    }
+                }
+            }
 
             // scalar * multivector
+            impl Mul<MultiVec2<$t>> for $t {
+                type Output = MultiVec2<$t>;
+                fn mul(self,other: MultiVec2<$t>) -> Self::Output {
+                    MultiVec2 {
+                        r: self * other.r,
+                        x: self * other.x,
+                        y: self * other.y,
+                        xy: self * other.xy,
+                    }
+                }
+            }
 
             // multivector * multivector
+            impl Mul<MultiVec2<$t>> for MultiVec2<$t> {
+                type Output = MultiVec2<$t>;
+                fn mul(self,other: Self) -> Self::Output {
+                    MultiVec2 {
+                        r: self.r * other.r + self.x * other.x + self.y * other.y - self.xy * other.xy,
+                        x: self.r * other.x + self.x * other.r - self.y * other.xy + self.xy * other.y,
+                        y: self.r * other.y + self.x * other.xy + self.y * other.r - self.xy * other.x,
+                        xy: self.r * other.xy + self.x * other.y - self.y * other.x + self.xy * other.r,
+                    }
+                }
+            }
 
             // multivector *= scalar
+            impl MulAssign<$t> for MultiVec2<$t> {
+                fn mul_assign(&mut self,other: $t) {
+
+                    self.r *= other;
+                    self.x *= other;
+                    self.y *= other;
+                    self.xy *= other;
+                }
+            }
 
             // multivector *= multivector
+            impl MulAssign<MultiVec2<$t>> for MultiVec2<$t> {
+                fn mul_assign(&mut self,other: Self) {
+                    *self = *self * other;
+                }
+            }
 
             // multivector / scalar
+            impl Div<$t> for MultiVec2<$t> {
+                type Output = MultiVec2<$t>;
+                fn div(self,other: $t) -> Self::Output {
+                    MultiVec2 {
+                        r: self.r / other,
+                        x: self.x / other,
+                        y: self.y / other,
+                        xy: self.xy / other,
+                    }
+                }
+            }
 
             // multivector / multivector
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            impl Div<MultiVec2<$t>> for MultiVec2<$t> {
+                type Output = MultiVec2<$t>;
+                fn div(self,other: Self) -> Self::Output {
+                    self * other.inv()
+                }
+            }
 
             // multivector /= scalar
+            impl DivAssign<$t> for MultiVec2<$t> {
+                fn div_assign(&mut self,other: $t) {
+
+                    self.r /= other;
+                    self.x /= other;
+                    self.y /= other;
+                    self.xy /= other;
+                }
+            }
 
             // multivector /= multivector
+            #[allow(clippy::suspicious_op_assign_impl)]
+            impl DivAssign<MultiVec2<$t>> for MultiVec2<$t> {
+                fn div_assign(&mut self,other: Self) {
+                    *self *= other.inv();
+                }
+            }
+
+            // -multivector
+            impl Neg for MultiVec2<$t> {
+                type Output = MultiVec2<$t>;
+                fn neg(self) -> Self::Output {
+                    MultiVec2 {
+                        r: -self.r,
+                        x: -self.x,
+                        y: -self.y,
+                        xy: -self.xy,
+                    }
+                }
+            }
         )+
     }
 }
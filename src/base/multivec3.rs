@@ -8,6 +8,17 @@ use {
             Formatter,
             Result,
         },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+        },
     },
 };
 
@@ -37,7 +48,119 @@ macro_rules! multivec3_impl {
     ($($t:ty)+) => {
         $(
             impl MultiVec3<$t> {
+                /// Reverse, reverses the order of the vectors in each blade.
+                pub fn reverse(self) -> Self {
+                    MultiVec3 {
+                        r: self.r,
+                        x: self.x,
+                        y: self.y,
+                        z: self.z,
+                        xy: -self.xy,
+                        xz: -self.xz,
+                        yz: -self.yz,
+                        xyz: -self.xyz,
+                    }
+                }
+
+                /// Grade involution, flips the sign of the odd grades.
+                pub fn involute(self) -> Self {
+                    MultiVec3 {
+                        r: self.r,
+                        x: -self.x,
+                        y: -self.y,
+                        z: -self.z,
+                        xy: self.xy,
+                        xz: self.xz,
+                        yz: self.yz,
+                        xyz: -self.xyz,
+                    }
+                }
+
+                /// Clifford conjugate, the combination of [`reverse`](Self::reverse) and [`involute`](Self::involute).
+                pub fn conj(self) -> Self {
+                    MultiVec3 {
+                        r: self.r,
+                        x: -self.x,
+                        y: -self.y,
+                        z: -self.z,
+                        xy: -self.xy,
+                        xz: -self.xz,
+                        yz: -self.yz,
+                        xyz: self.xyz,
+                    }
+                }
+
+                /// Grade projection, keeps only the parts of grade `k`.
+                pub fn grade(self,k: usize) -> Self {
+                    match k {
+                        0 => MultiVec3 { r: self.r,..Self::ZERO },
+                        1 => MultiVec3 { x: self.x,y: self.y,z: self.z,..Self::ZERO },
+                        2 => MultiVec3 { xy: self.xy,xz: self.xz,yz: self.yz,..Self::ZERO },
+                        3 => MultiVec3 { xyz: self.xyz,..Self::ZERO },
+                        _ => Self::ZERO,
+                    }
+                }
+
+                /// Dual, the multivector multiplied by the inverse of the pseudoscalar `xyz`.
+                pub fn dual(self) -> Self {
+                    MultiVec3 {
+                        r: self.xyz,
+                        x: self.yz,
+                        y: -self.xz,
+                        z: self.xy,
+                        xy: -self.z,
+                        xz: self.y,
+                        yz: -self.x,
+                        xyz: -self.r,
+                    }
+                }
+
+                /// Outer (wedge) product.
+                pub fn outer(self,other: Self) -> Self {
+                    MultiVec3 {
+                        r: self.r * other.r,
+                        x: self.r * other.x + self.x * other.r,
+                        y: self.r * other.y + self.y * other.r,
+                        z: self.r * other.z + self.z * other.r,
+                        xy: self.r * other.xy + self.x * other.y - self.y * other.x + self.xy * other.r,
+                        xz: self.r * other.xz + self.x * other.z - self.z * other.x + self.xz * other.r,
+                        yz: self.r * other.yz + self.y * other.z - self.z * other.y + self.yz * other.r,
+                        xyz: self.r * other.xyz + self.x * other.yz - self.y * other.xz + self.z * other.xy + self.xy * other.z - self.xz * other.y + self.yz * other.x + self.xyz * other.r,
+                    }
+                }
+
+                /// Inner product, as left contraction.
+                pub fn inner(self,other: Self) -> Self {
+                    MultiVec3 {
+                        r: self.r * other.r + self.x * other.x + self.y * other.y + self.z * other.z - self.xy * other.xy - self.xz * other.xz - self.yz * other.yz - self.xyz * other.xyz,
+                        x: self.r * other.x - self.y * other.xy - self.z * other.xz - self.yz * other.xyz,
+                        y: self.r * other.y + self.x * other.xy - self.z * other.yz + self.xz * other.xyz,
+                        z: self.r * other.z + self.x * other.xz + self.y * other.yz - self.xy * other.xyz,
+                        xy: self.r * other.xy + self.z * other.xyz,
+                        xz: self.r * other.xz - self.y * other.xyz,
+                        yz: self.r * other.yz + self.x * other.xyz,
+                        xyz: self.r * other.xyz,
+                    }
+                }
+
+                /// Squared norm, the scalar part of the multivector times its reverse.
+                pub fn norm_sqr(self) -> $t {
+                    self.r * self.r + self.x * self.x + self.y * self.y + self.z * self.z + self.xy * self.xy + self.xz * self.xz + self.yz * self.yz + self.xyz * self.xyz
+                }
+
+                /// Norm, the square root of [`norm_sqr`](Self::norm_sqr).
+                pub fn norm(self) -> $t {
+                    self.norm_sqr().sqrt()
+                }
 
+                /// Inverse, so that `self * self.inv()` is one. Not finite when the multivector has no inverse.
+                pub fn inv(self) -> Self {
+                    // self * self.conj() only has scalar and pseudoscalar parts, which behave like a complex number
+                    let c = self.conj();
+                    let m = self * c;
+                    let f = m.r * m.r + m.xyz * m.xyz;
+                    c * MultiVec3 { r: m.r / f,xyz: -m.xyz / f,..Self::ZERO }
+                }
             }
 
             impl Display for MultiVec3<$t> {
@@ -69,31 +192,217 @@ macro_rules! multivec3_impl {
                 };
             }
 
+            impl One for MultiVec3<$t> {
+                const ONE: MultiVec3<$t> = MultiVec3 {
+                    r: <$t>::ONE,
+                    x: <$t>::ZERO,y: <$t>::ZERO,z: <$t>::ZERO,
+                    xy: <$t>::ZERO,xz: <$t>::ZERO,yz: <$t>::ZERO,
+                    xyz: <$t>::ZERO,
+                };
+            }
+
             // multivector + multivector
+            impl Add<MultiVec3<$t>> for MultiVec3<$t> {
+                type Output = MultiVec3<$t>;
+                fn add(self,other: Self) -> Self::Output {
+                    MultiVec3 {
+                        r: self.r + other.r,
+                        x: self.x + other.x,
+                        y: self.y + other.y,
+                        z: self.z + other.z,
+                        xy: self.xy + other.xy,
+                        xz: self.xz + other.xz,
+                        yz: self.yz + other.yz,
+                        xyz: self.xyz + other.xyz,
+                    }
+                }
+            }
 
             // multivector += multivector
+            impl AddAssign<MultiVec3<$t>> for MultiVec3<$t> {
+                fn add_assign(&mut self,other: Self) {
+
+                    self.r += other.r;
+                    self.x += other.x;
+                    self.y += other.y;
+                    self.z += other.z;
+                    self.xy += other.xy;
+                    self.xz += other.xz;
+                    self.yz += other.yz;
+                    self.xyz += other.xyz;
+                }
+            }
 
             // multivector - multivector
+            impl Sub<MultiVec3<$t>> for MultiVec3<$t> {
+                type Output = MultiVec3<$t>;
+                fn sub(self,other: Self) -> Self::Output {
+                    MultiVec3 {
+                        r: self.r - other.r,
+                        x: self.x - other.x,
+                        y: self.y - other.y,
+                        z: self.z - other.z,
+                        xy: self.xy - other.xy,
+                        xz: self.xz - other.xz,
+                        yz: self.yz - other.yz,
+                        xyz: self.xyz - other.xyz,
+                    }
+                }
+            }
 
             // multivector -= multivector
-            
+            impl SubAssign<MultiVec3<$t>> for MultiVec3<$t> {
+                fn sub_assign(&mut self,other: Self) {
+
+                    self.r -= other.r;
+                    self.x -= other.x;
+                    self.y -= other.y;
+                    self.z -= other.z;
+                    self.xy -= other.xy;
+                    self.xz -= other.xz;
+                    self.yz -= other.yz;
+                    self.xyz -= other.xyz;
+                }
+            }
+
             // multivector * scalar
+            impl Mul<$t> for MultiVec3<$t> {
+                type Output = MultiVec3<$t>;
+                fn mul(self,other: $t) -> Self::Output {
+                    MultiVec3 {
+                        r: self.r * other,
+                        x: self.x * other,
+                        y: self.y * other,
+                        z: self.z * other,
+                        xy: self.xy * other,
+                        xz: self.xz * other,
+                        yz: self.yz * other,
+                        xyz: self.xyz * other,
+                    }
+                }
+            }
 
             // scalar * multivector
+            impl Mul<MultiVec3<$t>> for $t {
+                type Output = MultiVec3<$t>;
+                fn mul(self,other: MultiVec3<$t>) -> Self::Output {
+                    MultiVec3 {
+                        r: self * other.r,
+                        x: self * other.x,
+                        y: self * other.y,
+                        z: self * other.z,
+                        xy: self * other.xy,
+                        xz: self * other.xz,
+                        yz: self * other.yz,
+                        xyz: self * other.xyz,
+                    }
+                }
+            }
 
             // multivector * multivector
+            impl Mul<MultiVec3<$t>> for MultiVec3<$t> {
+                type Output = MultiVec3<$t>;
+                fn mul(self,other: Self) -> Self::Output {
+                    MultiVec3 {
+                        r: self.r * other.r + self.x * other.x + self.y * other.y + self.z * other.z - self.xy * other.xy - self.xz * other.xz - self.yz * other.yz - self.xyz * other.xyz,
+                        x: self.r * other.x + self.x * other.r - self.y * other.xy - self.z * other.xz + self.xy * other.y + self.xz * other.z - self.yz * other.xyz - self.xyz * other.yz,
+                        y: self.r * other.y + self.x * other.xy + self.y * other.r - self.z * other.yz - self.xy * other.x + self.xz * other.xyz + self.yz * other.z + self.xyz * other.xz,
+                        z: self.r * other.z + self.x * other.xz + self.y * other.yz + self.z * other.r - self.xy * other.xyz - self.xz * other.x - self.yz * other.y - self.xyz * other.xy,
+                        xy: self.r * other.xy + self.x * other.y - self.y * other.x + self.z * other.xyz + self.xy * other.r - self.xz * other.yz + self.yz * other.xz + self.xyz * other.z,
+                        xz: self.r * other.xz + self.x * other.z - self.y * other.xyz - self.z * other.x + self.xy * other.yz + self.xz * other.r - self.yz * other.xy - self.xyz * other.y,
+                        yz: self.r * other.yz + self.x * other.xyz + self.y * other.z - self.z * other.y - self.xy * other.xz + self.xz * other.xy + self.yz * other.r + self.xyz * other.x,
+                        xyz: self.r * other.xyz + self.x * other.yz - self.y * other.xz + self.z * other.xy + self.xy * other.z - self.xz * other.y + self.yz * other.x + self.xyz * other.r,
+                    }
+                }
+            }
 
             // multivector *= scalar
+            impl MulAssign<$t> for MultiVec3<$t> {
+                fn mul_assign(&mut self,other: $t) {
+
+                    self.r *= other;
+                    self.x *= other;
+                    self.y *= other;
+                    self.z *= other;
+                    self.xy *= other;
+                    self.xz *= other;
+                    self.yz *= other;
+                    self.xyz *= other;
+                }
+            }
 
             // multivector *= multivector
+            impl MulAssign<MultiVec3<$t>> for MultiVec3<$t> {
+                fn mul_assign(&mut self,other: Self) {
+                    *self = *self * other;
+                }
+            }
 
             // multivector / scalar
+            impl Div<$t> for MultiVec3<$t> {
+                type Output = MultiVec3<$t>;
+                fn div(self,other: $t) -> Self::Output {
+                    MultiVec3 {
+                        r: self.r / other,
+                        x: self.x / other,
+                        y: self.y / other,
+                        z: self.z / other,
+                        xy: self.xy / other,
+                        xz: self.xz / other,
+                        yz: self.yz / other,
+                        xyz: self.xyz / other,
+                    }
+                }
+            }
 
             // multivector / multivector
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            impl Div<MultiVec3<$t>> for MultiVec3<$t> {
+                type Output = MultiVec3<$t>;
+                fn div(self,other: Self) -> Self::Output {
+                    self * other.inv()
+                }
+            }
 
             // multivector /= scalar
+            impl DivAssign<$t> for MultiVec3<$t> {
+                fn div_assign(&mut self,other: $t) {
+
+                    self.r /= other;
+                    self.x /= other;
+                    self.y /= other;
+                    self.z /= other;
+                    self.xy /= other;
+                    self.xz /= other;
+                    self.yz /= other;
+                    self.xyz /= other;
+                }
+            }
 
             // multivector /= multivector
+            #[allow(clippy::suspicious_op_assign_impl)]
+            impl DivAssign<MultiVec3<$t>> for MultiVec3<$t> {
+                fn div_assign(&mut self,other: Self) {
+                    *self *= other.inv();
+                }
+            }
+
+            // -multivector
+            impl Neg for MultiVec3<$t> {
+                type Output = MultiVec3<$t>;
+                fn neg(self) -> Self::Output {
+                    MultiVec3 {
+                        r: -self.r,
+                        x: -self.x,
+                        y: -self.y,
+                        z: -self.z,
+                        xy: -self.xy,
+                        xz: -self.xz,
+                        yz: -self.yz,
+                        xyz: -self.xyz,
+                    }
+                }
+            }
         )+
     }
 }
@@ -8,6 +8,17 @@ use {
             Formatter,
             Result,
         },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+        },
     },
 };
 
@@ -40,7 +51,173 @@ macro_rules! multivec4_impl {
     ($($t:ty)+) => {
         $(
             impl MultiVec4<$t> {
+                /// Reverse, reverses the order of the vectors in each blade.
+                pub fn reverse(self) -> Self {
+                    MultiVec4 {
+                        r: self.r,
+                        x: self.x,
+                        y: self.y,
+                        z: self.z,
+                        w: self.w,
+                        xy: -self.xy,
+                        xz: -self.xz,
+                        xw: -self.xw,
+                        yz: -self.yz,
+                        yw: -self.yw,
+                        zw: -self.zw,
+                        xyz: -self.xyz,
+                        xyw: -self.xyw,
+                        xzw: -self.xzw,
+                        yzw: -self.yzw,
+                        xyzw: self.xyzw,
+                    }
+                }
+
+                /// Grade involution, flips the sign of the odd grades.
+                pub fn involute(self) -> Self {
+                    MultiVec4 {
+                        r: self.r,
+                        x: -self.x,
+                        y: -self.y,
+                        z: -self.z,
+                        w: -self.w,
+                        xy: self.xy,
+                        xz: self.xz,
+                        xw: self.xw,
+                        yz: self.yz,
+                        yw: self.yw,
+                        zw: self.zw,
+                        xyz: -self.xyz,
+                        xyw: -self.xyw,
+                        xzw: -self.xzw,
+                        yzw: -self.yzw,
+                        xyzw: self.xyzw,
+                    }
+                }
+
+                /// Clifford conjugate, the combination of [`reverse`](Self::reverse) and [`involute`](Self::involute).
+                pub fn conj(self) -> Self {
+                    MultiVec4 {
+                        r: self.r,
+                        x: -self.x,
+                        y: -self.y,
+                        z: -self.z,
+                        w: -self.w,
+                        xy: -self.xy,
+                        xz: -self.xz,
+                        xw: -self.xw,
+                        yz: -self.yz,
+                        yw: -self.yw,
+                        zw: -self.zw,
+                        xyz: self.xyz,
+                        xyw: self.xyw,
+                        xzw: self.xzw,
+                        yzw: self.yzw,
+                        xyzw: self.xyzw,
+                    }
+                }
+
+                /// Grade projection, keeps only the parts of grade `k`.
+                pub fn grade(self,k: usize) -> Self {
+                    match k {
+                        0 => MultiVec4 { r: self.r,..Self::ZERO },
+                        1 => MultiVec4 { x: self.x,y: self.y,z: self.z,w: self.w,..Self::ZERO },
+                        2 => MultiVec4 { xy: self.xy,xz: self.xz,xw: self.xw,yz: self.yz,yw: self.yw,zw: self.zw,..Self::ZERO },
+                        3 => MultiVec4 { xyz: self.xyz,xyw: self.xyw,xzw: self.xzw,yzw: self.yzw,..Self::ZERO },
+                        4 => MultiVec4 { xyzw: self.xyzw,..Self::ZERO },
+                        _ => Self::ZERO,
+                    }
+                }
+
+                /// Dual, the multivector multiplied by the inverse of the pseudoscalar `xyzw`.
+                pub fn dual(self) -> Self {
+                    MultiVec4 {
+                        r: self.xyzw,
+                        x: self.yzw,
+                        y: -self.xzw,
+                        z: self.xyw,
+                        w: -self.xyz,
+                        xy: -self.zw,
+                        xz: self.yw,
+                        xw: -self.yz,
+                        yz: -self.xw,
+                        yw: self.xz,
+                        zw: -self.xy,
+                        xyz: -self.w,
+                        xyw: self.z,
+                        xzw: -self.y,
+                        yzw: self.x,
+                        xyzw: self.r,
+                    }
+                }
+
+                /// Outer (wedge) product.
+                pub fn outer(self,other: Self) -> Self {
+                    MultiVec4 {
+                        r: self.r * other.r,
+                        x: self.r * other.x + self.x * other.r,
+                        y: self.r * other.y + self.y * other.r,
+                        z: self.r * other.z + self.z * other.r,
+                        w: self.r * other.w + self.w * other.r,
+                        xy: self.r * other.xy + self.x * other.y - self.y * other.x + self.xy * other.r,
+                        xz: self.r * other.xz + self.x * other.z - self.z * other.x + self.xz * other.r,
+                        xw: self.r * other.xw + self.x * other.w - self.w * other.x + self.xw * other.r,
+                        yz: self.r * other.yz + self.y * other.z - self.z * other.y + self.yz * other.r,
+                        yw: self.r * other.yw + self.y * other.w - self.w * other.y + self.yw * other.r,
+                        zw: self.r * other.zw + self.z * other.w - self.w * other.z + self.zw * other.r,
+                        xyz: self.r * other.xyz + self.x * other.yz - self.y * other.xz + self.z * other.xy + self.xy * other.z - self.xz * other.y + self.yz * other.x + self.xyz * other.r,
+                        xyw: self.r * other.xyw + self.x * other.yw - self.y * other.xw + self.w * other.xy + self.xy * other.w - self.xw * other.y + self.yw * other.x + self.xyw * other.r,
+                        xzw: self.r * other.xzw + self.x * other.zw - self.z * other.xw + self.w * other.xz + self.xz * other.w - self.xw * other.z + self.zw * other.x + self.xzw * other.r,
+                        yzw: self.r * other.yzw + self.y * other.zw - self.z * other.yw + self.w * other.yz + self.yz * other.w - self.yw * other.z + self.zw * other.y + self.yzw * other.r,
+                        xyzw: self.r * other.xyzw + self.x * other.yzw - self.y * other.xzw + self.z * other.xyw - self.w * other.xyz + self.xy * other.zw - self.xz * other.yw + self.xw * other.yz + self.yz * other.xw - self.yw * other.xz + self.zw * other.xy + self.xyz * other.w - self.xyw * other.z + self.xzw * other.y - self.yzw * other.x + self.xyzw * other.r,
+                    }
+                }
+
+                /// Inner product, as left contraction.
+                pub fn inner(self,other: Self) -> Self {
+                    MultiVec4 {
+                        r: self.r * other.r + self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w - self.xy * other.xy - self.xz * other.xz - self.xw * other.xw - self.yz * other.yz - self.yw * other.yw - self.zw * other.zw - self.xyz * other.xyz - self.xyw * other.xyw - self.xzw * other.xzw - self.yzw * other.yzw + self.xyzw * other.xyzw,
+                        x: self.r * other.x - self.y * other.xy - self.z * other.xz - self.w * other.xw - self.yz * other.xyz - self.yw * other.xyw - self.zw * other.xzw + self.yzw * other.xyzw,
+                        y: self.r * other.y + self.x * other.xy - self.z * other.yz - self.w * other.yw + self.xz * other.xyz + self.xw * other.xyw - self.zw * other.yzw - self.xzw * other.xyzw,
+                        z: self.r * other.z + self.x * other.xz + self.y * other.yz - self.w * other.zw - self.xy * other.xyz + self.xw * other.xzw + self.yw * other.yzw + self.xyw * other.xyzw,
+                        w: self.r * other.w + self.x * other.xw + self.y * other.yw + self.z * other.zw - self.xy * other.xyw - self.xz * other.xzw - self.yz * other.yzw - self.xyz * other.xyzw,
+                        xy: self.r * other.xy + self.z * other.xyz + self.w * other.xyw - self.zw * other.xyzw,
+                        xz: self.r * other.xz - self.y * other.xyz + self.w * other.xzw + self.yw * other.xyzw,
+                        xw: self.r * other.xw - self.y * other.xyw - self.z * other.xzw - self.yz * other.xyzw,
+                        yz: self.r * other.yz + self.x * other.xyz + self.w * other.yzw - self.xw * other.xyzw,
+                        yw: self.r * other.yw + self.x * other.xyw - self.z * other.yzw + self.xz * other.xyzw,
+                        zw: self.r * other.zw + self.x * other.xzw + self.y * other.yzw - self.xy * other.xyzw,
+                        xyz: self.r * other.xyz - self.w * other.xyzw,
+                        xyw: self.r * other.xyw + self.z * other.xyzw,
+                        xzw: self.r * other.xzw - self.y * other.xyzw,
+                        yzw: self.r * other.yzw + self.x * other.xyzw,
+                        xyzw: self.r * other.xyzw,
+                    }
+                }
+
+                /// Squared norm, the scalar part of the multivector times its reverse.
+                pub fn norm_sqr(self) -> $t {
+                    self.r * self.r + self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w + self.xy * self.xy + self.xz * self.xz + self.xw * self.xw + self.yz * self.yz + self.yw * self.yw + self.zw * self.zw + self.xyz * self.xyz + self.xyw * self.xyw + self.xzw * self.xzw + self.yzw * self.yzw + self.xyzw * self.xyzw
+                }
+
+                /// Norm, the square root of [`norm_sqr`](Self::norm_sqr).
+                pub fn norm(self) -> $t {
+                    self.norm_sqr().sqrt()
+                }
 
+                /// Inverse, so that `self * self.inv()` is one. Not finite when the multivector has no inverse.
+                pub fn inv(self) -> Self {
+                    // self * self.conj() only has scalar, trivector and pseudoscalar parts, negating the latter two gives the scalar denominator
+                    let c = self.conj();
+                    let m = self * c;
+                    let n = MultiVec4 {
+                        r: m.r,
+                        xyz: -m.xyz,xyw: -m.xyw,xzw: -m.xzw,yzw: -m.yzw,
+                        xyzw: -m.xyzw,
+                        ..Self::ZERO
+                    };
+                    (c * n) / (m * n).r
+                }
             }
 
             impl Display for MultiVec4<$t> {
@@ -75,31 +252,306 @@ macro_rules! multivec4_impl {
                 };
             }
 
+            impl One for MultiVec4<$t> {
+                const ONE: MultiVec4<$t> = MultiVec4 {
+                    r: <$t>::ONE,
+                    x: <$t>::ZERO,y: <$t>::ZERO,z: <$t>::ZERO,w: <$t>::ZERO,
+                    xy: <$t>::ZERO,xz: <$t>::ZERO,xw: <$t>::ZERO,yz: <$t>::ZERO,yw: <$t>::ZERO,zw: <$t>::ZERO,
+                    xyz: <$t>::ZERO,xyw: <$t>::ZERO,xzw: <$t>::ZERO,yzw: <$t>::ZERO,
+                    xyzw: <$t>::ZERO,
+                };
+            }
+
             // multivector + multivector
+            impl Add<MultiVec4<$t>> for MultiVec4<$t> {
+                type Output = MultiVec4<$t>;
+                fn add(self,other: Self) -> Self::Output {
+                    MultiVec4 {
+                        r: self.r + other.r,
+                        x: self.x + other.x,
+                        y: self.y + other.y,
+                        z: self.z + other.z,
+                        w: self.w + other.w,
+                        xy: self.xy + other.xy,
+                        xz: self.xz + other.xz,
+                        xw: self.xw + other.xw,
+                        yz: self.yz + other.yz,
+                        yw: self.yw + other.yw,
+                        zw: self.zw + other.zw,
+                        xyz: self.xyz + other.xyz,
+                        xyw: self.xyw + other.xyw,
+                        xzw: self.xzw + other.xzw,
+                        yzw: self.yzw + other.yzw,
+                        xyzw: self.xyzw + other.xyzw,
+                    }
+                }
+            }
 
             // multivector += multivector
+            impl AddAssign<MultiVec4<$t>> for MultiVec4<$t> {
+                fn add_assign(&mut self,other: Self) {
+
+                    self.r += other.r;
+                    self.x += other.x;
+                    self.y += other.y;
+                    self.z += other.z;
+                    self.w += other.w;
+                    self.xy += other.xy;
+                    self.xz += other.xz;
+                    self.xw += other.xw;
+                    self.yz += other.yz;
+                    self.yw += other.yw;
+                    self.zw += other.zw;
+                    self.xyz += other.xyz;
+                    self.xyw += other.xyw;
+                    self.xzw += other.xzw;
+                    self.yzw += other.yzw;
+                    self.xyzw += other.xyzw;
+                }
+            }
 
             // multivector - multivector
+            impl Sub<MultiVec4<$t>> for MultiVec4<$t> {
+                type Output = MultiVec4<$t>;
+                fn sub(self,other: Self) -> Self::Output {
+                    MultiVec4 {
+                        r: self.r - other.r,
+                        x: self.x - other.x,
+                        y: self.y - other.y,
+                        z: self.z - other.z,
+                        w: self.w - other.w,
+                        xy: self.xy - other.xy,
+                        xz: self.xz - other.xz,
+                        xw: self.xw - other.xw,
+                        yz: self.yz - other.yz,
+                        yw: self.yw - other.yw,
+                        zw: self.zw - other.zw,
+                        xyz: self.xyz - other.xyz,
+                        xyw: self.xyw - other.xyw,
+                        xzw: self.xzw - other.xzw,
+                        yzw: self.yzw - other.yzw,
+                        xyzw: self.xyzw - other.xyzw,
+                    }
+                }
+            }
 
             // multivector -= multivector
-            
+            impl SubAssign<MultiVec4<$t>> for MultiVec4<$t> {
+                fn sub_assign(&mut self,other: Self) {
+
+                    self.r -= other.r;
+                    self.x -= other.x;
+                    self.y -= other.y;
+                    self.z -= other.z;
+                    self.w -= other.w;
+                    self.xy -= other.xy;
+                    self.xz -= other.xz;
+                    self.xw -= other.xw;
+                    self.yz -= other.yz;
+                    self.yw -= other.yw;
+                    self.zw -= other.zw;
+                    self.xyz -= other.xyz;
+                    self.xyw -= other.xyw;
+                    self.xzw -= other.xzw;
+                    self.yzw -= other.yzw;
+                    self.xyzw -= other.xyzw;
+                }
+            }
+
             // multivector * scalar
+            impl Mul<$t> for MultiVec4<$t> {
+                type Output = MultiVec4<$t>;
+                fn mul(self,other: $t) -> Self::Output {
+                    MultiVec4 {
+                        r: self.r * other,
+                        x: self.x * other,
+                        y: self.y * other,
+                        z: self.z * other,
+                        w: self.w * other,
+                        xy: self.xy * other,
+                        xz: self.xz * other,
+                        xw: self.xw * other,
+                        yz: self.yz * other,
+                        yw: self.yw * other,
+                        zw: self.zw * other,
+                        xyz: self.xyz * other,
+                        xyw: self.xyw * other,
+                        xzw: self.xzw * other,
+                        yzw: self.yzw * other,
+                        xyzw: self.xyzw * other,
+                    }
+                }
+            }
 
             // scalar * multivector
+            impl Mul<MultiVec4<$t>> for $t {
+                type Output = MultiVec4<$t>;
+                fn mul(self,other: MultiVec4<$t>) -> Self::Output {
+                    MultiVec4 {
+                        r: self * other.r,
+                        x: self * other.x,
+                        y: self * other.y,
+                        z: self * other.z,
+                        w: self * other.w,
+                        xy: self * other.xy,
+                        xz: self * other.xz,
+                        xw: self * other.xw,
+                        yz: self * other.yz,
+                        yw: self * other.yw,
+                        zw: self * other.zw,
+                        xyz: self * other.xyz,
+                        xyw: self * other.xyw,
+                        xzw: self * other.xzw,
+                        yzw: self * other.yzw,
+                        xyzw: self * other.xyzw,
+                    }
+                }
+            }
 
             // multivector * multivector
+            impl Mul<MultiVec4<$t>> for MultiVec4<$t> {
+                type Output = MultiVec4<$t>;
+                fn mul(self,other: Self) -> Self::Output {
+                    MultiVec4 {
+                        r: self.r * other.r + self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w - self.xy * other.xy - self.xz * other.xz - self.xw * other.xw - self.yz * other.yz - self.yw * other.yw - self.zw * other.zw - self.xyz * other.xyz - self.xyw * other.xyw - self.xzw * other.xzw - self.yzw * other.yzw + self.xyzw * other.xyzw,
+                        x: self.r * other.x + self.x * other.r - self.y * other.xy - self.z * other.xz - self.w * other.xw + self.xy * other.y + self.xz * other.z + self.xw * other.w - self.yz * other.xyz - self.yw * other.xyw - self.zw * other.xzw - self.xyz * other.yz - self.xyw * other.yw - self.xzw * other.zw + self.yzw * other.xyzw - self.xyzw * other.yzw,
+                        y: self.r * other.y + self.x * other.xy + self.y * other.r - self.z * other.yz - self.w * other.yw - self.xy * other.x + self.xz * other.xyz + self.xw * other.xyw + self.yz * other.z + self.yw * other.w - self.zw * other.yzw + self.xyz * other.xz + self.xyw * other.xw - self.xzw * other.xyzw - self.yzw * other.zw + self.xyzw * other.xzw,
+                        z: self.r * other.z + self.x * other.xz + self.y * other.yz + self.z * other.r - self.w * other.zw - self.xy * other.xyz - self.xz * other.x + self.xw * other.xzw - self.yz * other.y + self.yw * other.yzw + self.zw * other.w - self.xyz * other.xy + self.xyw * other.xyzw + self.xzw * other.xw + self.yzw * other.yw - self.xyzw * other.xyw,
+                        w: self.r * other.w + self.x * other.xw + self.y * other.yw + self.z * other.zw + self.w * other.r - self.xy * other.xyw - self.xz * other.xzw - self.xw * other.x - self.yz * other.yzw - self.yw * other.y - self.zw * other.z - self.xyz * other.xyzw - self.xyw * other.xy - self.xzw * other.xz - self.yzw * other.yz + self.xyzw * other.xyz,
+                        xy: self.r * other.xy + self.x * other.y - self.y * other.x + self.z * other.xyz + self.w * other.xyw + self.xy * other.r - self.xz * other.yz - self.xw * other.yw + self.yz * other.xz + self.yw * other.xw - self.zw * other.xyzw + self.xyz * other.z + self.xyw * other.w - self.xzw * other.yzw + self.yzw * other.xzw - self.xyzw * other.zw,
+                        xz: self.r * other.xz + self.x * other.z - self.y * other.xyz - self.z * other.x + self.w * other.xzw + self.xy * other.yz + self.xz * other.r - self.xw * other.zw - self.yz * other.xy + self.yw * other.xyzw + self.zw * other.xw - self.xyz * other.y + self.xyw * other.yzw + self.xzw * other.w - self.yzw * other.xyw + self.xyzw * other.yw,
+                        xw: self.r * other.xw + self.x * other.w - self.y * other.xyw - self.z * other.xzw - self.w * other.x + self.xy * other.yw + self.xz * other.zw + self.xw * other.r - self.yz * other.xyzw - self.yw * other.xy - self.zw * other.xz - self.xyz * other.yzw - self.xyw * other.y - self.xzw * other.z + self.yzw * other.xyz - self.xyzw * other.yz,
+                        yz: self.r * other.yz + self.x * other.xyz + self.y * other.z - self.z * other.y + self.w * other.yzw - self.xy * other.xz + self.xz * other.xy - self.xw * other.xyzw + self.yz * other.r - self.yw * other.zw + self.zw * other.yw + self.xyz * other.x - self.xyw * other.xzw + self.xzw * other.xyw + self.yzw * other.w - self.xyzw * other.xw,
+                        yw: self.r * other.yw + self.x * other.xyw + self.y * other.w - self.z * other.yzw - self.w * other.y - self.xy * other.xw + self.xz * other.xyzw + self.xw * other.xy + self.yz * other.zw + self.yw * other.r - self.zw * other.yz + self.xyz * other.xzw + self.xyw * other.x - self.xzw * other.xyz - self.yzw * other.z + self.xyzw * other.xz,
+                        zw: self.r * other.zw + self.x * other.xzw + self.y * other.yzw + self.z * other.w - self.w * other.z - self.xy * other.xyzw - self.xz * other.xw + self.xw * other.xz - self.yz * other.yw + self.yw * other.yz + self.zw * other.r - self.xyz * other.xyw + self.xyw * other.xyz + self.xzw * other.x + self.yzw * other.y - self.xyzw * other.xy,
+                        xyz: self.r * other.xyz + self.x * other.yz - self.y * other.xz + self.z * other.xy - self.w * other.xyzw + self.xy * other.z - self.xz * other.y + self.xw * other.yzw + self.yz * other.x - self.yw * other.xzw + self.zw * other.xyw + self.xyz * other.r - self.xyw * other.zw + self.xzw * other.yw - self.yzw * other.xw + self.xyzw * other.w,
+                        xyw: self.r * other.xyw + self.x * other.yw - self.y * other.xw + self.z * other.xyzw + self.w * other.xy + self.xy * other.w - self.xz * other.yzw - self.xw * other.y + self.yz * other.xzw + self.yw * other.x - self.zw * other.xyz + self.xyz * other.zw + self.xyw * other.r - self.xzw * other.yz + self.yzw * other.xz - self.xyzw * other.z,
+                        xzw: self.r * other.xzw + self.x * other.zw - self.y * other.xyzw - self.z * other.xw + self.w * other.xz + self.xy * other.yzw + self.xz * other.w - self.xw * other.z - self.yz * other.xyw + self.yw * other.xyz + self.zw * other.x - self.xyz * other.yw + self.xyw * other.yz + self.xzw * other.r - self.yzw * other.xy + self.xyzw * other.y,
+                        yzw: self.r * other.yzw + self.x * other.xyzw + self.y * other.zw - self.z * other.yw + self.w * other.yz - self.xy * other.xzw + self.xz * other.xyw - self.xw * other.xyz + self.yz * other.w - self.yw * other.z + self.zw * other.y + self.xyz * other.xw - self.xyw * other.xz + self.xzw * other.xy + self.yzw * other.r - self.xyzw * other.x,
+                        xyzw: self.r * other.xyzw + self.x * other.yzw - self.y * other.xzw + self.z * other.xyw - self.w * other.xyz + self.xy * other.zw - self.xz * other.yw + self.xw * other.yz + self.yz * other.xw - self.yw * other.xz + self.zw * other.xy + self.xyz * other.w - self.xyw * other.z + self.xzw * other.y - self.yzw * other.x + self.xyzw * other.r,
+                    }
+                }
+            }
 
             // multivector *= scalar
+            impl MulAssign<$t> for MultiVec4<$t> {
+                fn mul_assign(&mut self,other: $t) {
+
+                    self.r *= other;
+                    self.x *= other;
+                    self.y *= other;
+                    self.z *= other;
+                    self.w *= other;
+                    self.xy *= other;
+                    self.xz *= other;
+                    self.xw *= other;
+                    self.yz *= other;
+                    self.yw *= other;
+                    self.zw *= other;
+                    self.xyz *= other;
+                    self.xyw *= other;
+                    self.xzw *= other;
+                    self.yzw *= other;
+                    self.xyzw *= other;
+                }
+            }
 
             // multivector *= multivector
+            impl MulAssign<MultiVec4<$t>> for MultiVec4<$t> {
+                fn mul_assign(&mut self,other: Self) {
+                    *self = *self * other;
+                }
+            }
 
             // multivector / scalar
+            impl Div<$t> for MultiVec4<$t> {
+                type Output = MultiVec4<$t>;
+                fn div(self,other: $t) -> Self::Output {
+                    MultiVec4 {
+                        r: self.r / other,
+                        x: self.x / other,
+                        y: self.y / other,
+                        z: self.z / other,
+                        w: self.w / other,
+                        xy: self.xy / other,
+                        xz: self.xz / other,
+                        xw: self.xw / other,
+                        yz: self.yz / other,
+                        yw: self.yw / other,
+                        zw: self.zw / other,
+                        xyz: self.xyz / other,
+                        xyw: self.xyw / other,
+                        xzw: self.xzw / other,
+                        yzw: self.yzw / other,
+                        xyzw: self.xyzw / other,
+                    }
+                }
+            }
 
             // multivector / multivector
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            impl Div<MultiVec4<$t>> for MultiVec4<$t> {
+                type Output = MultiVec4<$t>;
+                fn div(self,other: Self) -> Self::Output {
+                    self * other.inv()
+                }
+            }
 
             // multivector /= scalar
+            impl DivAssign<$t> for MultiVec4<$t> {
+                fn div_assign(&mut self,other: $t) {
+
+                    self.r /= other;
+                    self.x /= other;
+                    self.y /= other;
+                    self.z /= other;
+                    self.w /= other;
+                    self.xy /= other;
+                    self.xz /= other;
+                    self.xw /= other;
+                    self.yz /= other;
+                    self.yw /= other;
+                    self.zw /= other;
+                    self.xyz /= other;
+                    self.xyw /= other;
+                    self.xzw /= other;
+                    self.yzw /= other;
+                    self.xyzw /= other;
+                }
+            }
 
             // multivector /= multivector
+            #[allow(clippy::suspicious_op_assign_impl)]
+            impl DivAssign<MultiVec4<$t>> for MultiVec4<$t> {
+                fn div_assign(&mut self,other: Self) {
+                    *self *= other.inv();
+                }
+            }
+
+            // -multivector
+            impl Neg for MultiVec4<$t> {
+                type Output = MultiVec4<$t>;
+                fn neg(self) -> Self::Output {
+                    MultiVec4 {
+                        r: -self.r,
+                        x: -self.x,
+                        y: -self.y,
+                        z: -self.z,
+                        w: -self.w,
+                        xy: -self.xy,
+                        xz: -self.xz,
+                        xw: -self.xw,
+                        yz: -self.yz,
+                        yw: -self.yw,
+                        zw: -self.zw,
+                        xyz: -self.xyz,
+                        xyw: -self.xyw,
+                        xzw: -self.xzw,
+                        yzw: -self.yzw,
+                        xyzw: -self.xyzw,
+                    }
+                }
+            }
         )+
     }
 }
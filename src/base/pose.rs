@@ -23,6 +23,24 @@ macro_rules! pose_impl {
                         o: o,
                     }
                 }
+
+                /// Rotate direction `v`, ignoring the translation.
+                pub fn transform_direction(self,v: Vec3<$t>) -> Vec3<$t> {
+                    self.o * v
+                }
+
+                /// Interpolate by spherical linear interpolation of the orientation and linear interpolation of the position.
+                pub fn slerp(self,other: Pose<$t>,t: $t) -> Pose<$t> {
+                    Pose {
+                        p: self.p + (other.p - self.p) * t,
+                        o: self.o.slerp(other.o,t),
+                    }
+                }
+
+                /// Interpolate along the screw motion from `self` to `other`, see [`Motor::sclerp`].
+                pub fn sclerp(self,other: Pose<$t>,t: $t) -> Pose<$t> {
+                    Pose::<$t>::from(Motor::<$t>::from(self).sclerp(Motor::<$t>::from(other),t))
+                }
             }
 
             impl One for Pose<$t> {
@@ -55,6 +73,36 @@ macro_rules! pose_impl {
                     self.o *= other.o;
                 }
             }
+
+            impl From<Pose<$t>> for Mat4x4<$t> {
+                fn from(value: Pose<$t>) -> Self {
+                    Mat4x4::<$t>::from_mv(Mat3x3::<$t>::from(value.o),value.p)
+                }
+            }
+
+            impl From<Mat4x4<$t>> for Pose<$t> {
+                fn from(value: Mat4x4<$t>) -> Self {
+                    Pose {
+                        p: Vec3 { x: value.x.w,y: value.y.w,z: value.z.w, },
+                        o: Quaternion::<$t>::from(Rotor::<$t>::from(value)),
+                    }
+                }
+            }
+
+            impl From<Pose<$t>> for Motor<$t> {
+                fn from(value: Pose<$t>) -> Self {
+                    Motor::<$t>::new(Rotor::<$t>::from(value.o),value.p)
+                }
+            }
+
+            impl From<Motor<$t>> for Pose<$t> {
+                fn from(value: Motor<$t>) -> Self {
+                    Pose {
+                        p: value.translation(),
+                        o: Quaternion::<$t>::from(value.rotor().normalize()),
+                    }
+                }
+            }
         )+
     }
 }
@@ -37,6 +37,46 @@ pub struct Quaternion<T> {
     pub k: T,
 }
 
+/// Order of rotation axes for Euler angles.
+///
+/// The angles are intrinsic rotations, applied in the order of the name: `XYZ` first rotates around X, then around the
+/// new Y and then around the new Z. This is the same as extrinsic rotations around Z, Y and X. The last six orders are
+/// proper Euler angles, the first six are Tait-Bryan angles.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerOrder {
+    fn axes(self) -> (usize,usize,usize) {
+        match self {
+            EulerOrder::XYZ => (0,1,2),
+            EulerOrder::XZY => (0,2,1),
+            EulerOrder::YXZ => (1,0,2),
+            EulerOrder::YZX => (1,2,0),
+            EulerOrder::ZXY => (2,0,1),
+            EulerOrder::ZYX => (2,1,0),
+            EulerOrder::XYX => (0,1,0),
+            EulerOrder::XZX => (0,2,0),
+            EulerOrder::YXY => (1,0,1),
+            EulerOrder::YZY => (1,2,1),
+            EulerOrder::ZXZ => (2,0,2),
+            EulerOrder::ZYZ => (2,1,2),
+        }
+    }
+}
+
 macro_rules! quaternion_impl {
     ($($t:ty)+) => {
         $(
@@ -59,6 +99,171 @@ macro_rules! quaternion_impl {
                         k: -self.k / f,
                     }
                 }
+
+                /// Create quaternion that rotates counterclockwise by `angle` radians around `axis`.
+                pub fn from_axis_angle(axis: Vec3<$t>,angle: $t) -> Self {
+                    let l = axis.dot(axis).sqrt();
+                    let (s,c) = (0.5 * angle).sin_cos();
+                    let s = s / l;
+                    Quaternion {
+                        r: c,
+                        i: s * axis.x,
+                        j: s * axis.y,
+                        k: s * axis.z,
+                    }
+                }
+
+                /// Rotation axis and angle in radians of a unit quaternion.
+                pub fn to_axis_angle(self) -> (Vec3<$t>,$t) {
+                    let l = (self.i * self.i + self.j * self.j + self.k * self.k).sqrt();
+                    if l == <$t>::ZERO {
+                        return (Vec3 { x: <$t>::ONE,y: <$t>::ZERO,z: <$t>::ZERO, },<$t>::ZERO);
+                    }
+                    (Vec3 { x: self.i / l,y: self.j / l,z: self.k / l, },2.0 * l.atan2(self.r))
+                }
+
+                /// Create quaternion that rotates the direction of `a` onto the direction of `b` along the shortest arc.
+                pub fn from_vectors(a: Vec3<$t>,b: Vec3<$t>) -> Self {
+                    Quaternion::<$t>::from(Rotor::<$t>::from_vectors(a,b))
+                }
+
+                /// Create quaternion from Euler angles in radians, see [`EulerOrder`].
+                pub fn from_euler(angles: Vec3<$t>,order: EulerOrder) -> Self {
+                    let (a,b,c) = order.axes();
+                    Quaternion::<$t>::from_basis_angle(a,angles.x) * Quaternion::<$t>::from_basis_angle(b,angles.y) * Quaternion::<$t>::from_basis_angle(c,angles.z)
+                }
+
+                /// Euler angles in radians of a unit quaternion, see [`EulerOrder`].
+                ///
+                /// In gimbal lock, the first angle is zero.
+                pub fn to_euler(self,order: EulerOrder) -> Vec3<$t> {
+                    // Bernardes and Viollet, "Quaternion to Euler angles conversion: A direct, general and computationally efficient method"
+                    let (k,j,i) = order.axes();
+                    let proper = i == k;
+                    let k = if proper { 3 - i - j } else { k };
+                    let sign = if (i + 1) % 3 == j { <$t>::ONE } else { -<$t>::ONE };
+                    let v = [self.i,self.j,self.k];
+                    let (a,b,c,d) = if proper {
+                        (self.r,v[i],v[j],v[k] * sign)
+                    }
+                    else {
+                        (self.r - v[j],v[i] + v[k] * sign,v[j] + self.r,v[k] * sign - v[i])
+                    };
+                    let aabb = a * a + b * b;
+                    let t2 = (2.0 * aabb / (aabb + c * c + d * d) - <$t>::ONE).max(-<$t>::ONE).min(<$t>::ONE).acos();
+                    let tp = b.atan2(a);
+                    let tm = d.atan2(c);
+                    let e = <$t>::EPSILON.sqrt();
+                    let (t1,t3) = if t2 < e {
+                        (<$t>::ZERO,2.0 * tp)
+                    }
+                    else if t2 > <$t>::PI - e {
+                        (<$t>::ZERO,2.0 * tm)
+                    }
+                    else {
+                        (tp - tm,tp + tm)
+                    };
+                    let (t2,t3) = if proper { (t2,t3) } else { (t2 - 0.5 * <$t>::PI,t3 * sign) };
+                    let wrap = |t: $t| if t > <$t>::PI { t - 2.0 * <$t>::PI } else if t < -<$t>::PI { t + 2.0 * <$t>::PI } else { t };
+                    Vec3 { x: wrap(t3),y: t2,z: wrap(t1), }
+                }
+
+                pub fn dot(self,other: Self) -> $t {
+                    self.r * other.r + self.i * other.i + self.j * other.j + self.k * other.k
+                }
+
+                pub fn norm(self) -> $t {
+                    self.dot(self).sqrt()
+                }
+
+                pub fn normalize(self) -> Self {
+                    self / self.norm()
+                }
+
+                /// Angle in radians of the rotation from unit quaternion `self` to unit quaternion `other`, between 0 and PI.
+                pub fn angle_between(self,other: Self) -> $t {
+                    2.0 * self.dot(other).abs().min(<$t>::ONE).acos()
+                }
+
+                /// Normalized linear interpolation along the shortest arc.
+                pub fn nlerp(self,other: Self,t: $t) -> Self {
+                    let other = if self.dot(other) < <$t>::ZERO { -other } else { other };
+                    (self * (<$t>::ONE - t) + other * t).normalize()
+                }
+
+                /// Spherical linear interpolation along the shortest arc, at constant angular velocity.
+                pub fn slerp(self,other: Self,t: $t) -> Self {
+                    let mut d = self.dot(other);
+                    let other = if d < <$t>::ZERO {
+                        d = -d;
+                        -other
+                    }
+                    else {
+                        other
+                    };
+                    if d > <$t>::ONE - <$t>::EPSILON {
+                        return self.nlerp(other,t);
+                    }
+                    let a = d.acos();
+                    let f = <$t>::ONE / a.sin();
+                    self * (((<$t>::ONE - t) * a).sin() * f) + other * ((t * a).sin() * f)
+                }
+
+                /// Spherical cubic interpolation between `self` and `other`, with control points `a` and `b` from [`Quaternion::squad_control`].
+                pub fn squad(self,a: Self,b: Self,other: Self,t: $t) -> Self {
+                    self.slerp(other,t).slerp(a.slerp(b,t),2.0 * t * (<$t>::ONE - t))
+                }
+
+                /// Squad control point for key `self`, between keys `prev` and `next`.
+                pub fn squad_control(self,prev: Self,next: Self) -> Self {
+                    let inv = self.conj();
+                    let prev = if self.dot(prev) < <$t>::ZERO { -prev } else { prev };
+                    let next = if self.dot(next) < <$t>::ZERO { -next } else { next };
+                    self * (((inv * prev).ln() + (inv * next).ln()) * -0.25).exp()
+                }
+
+                pub fn exp(self) -> Self {
+                    let l = (self.i * self.i + self.j * self.j + self.k * self.k).sqrt();
+                    let e = self.r.exp();
+                    if l == <$t>::ZERO {
+                        return Quaternion { r: e,i: <$t>::ZERO,j: <$t>::ZERO,k: <$t>::ZERO, };
+                    }
+                    let (s,c) = l.sin_cos();
+                    let s = e * s / l;
+                    Quaternion {
+                        r: e * c,
+                        i: s * self.i,
+                        j: s * self.j,
+                        k: s * self.k,
+                    }
+                }
+
+                pub fn ln(self) -> Self {
+                    let l = (self.i * self.i + self.j * self.j + self.k * self.k).sqrt();
+                    let n = self.norm();
+                    if l == <$t>::ZERO {
+                        return Quaternion { r: n.ln(),i: <$t>::ZERO,j: <$t>::ZERO,k: <$t>::ZERO, };
+                    }
+                    let f = l.atan2(self.r) / l;
+                    Quaternion {
+                        r: n.ln(),
+                        i: f * self.i,
+                        j: f * self.j,
+                        k: f * self.k,
+                    }
+                }
+
+                // rotation around basis axis 0, 1 or 2
+                fn from_basis_angle(axis: usize,angle: $t) -> Self {
+                    let (s,c) = (0.5 * angle).sin_cos();
+                    let mut q = Quaternion { r: c,i: <$t>::ZERO,j: <$t>::ZERO,k: <$t>::ZERO, };
+                    match axis {
+                        0 => q.i = s,
+                        1 => q.j = s,
+                        _ => q.k = s,
+                    }
+                    q
+                }
             }
 
             impl Zero for Quaternion<$t> {
@@ -499,38 +704,24 @@ macro_rules! quaternion_impl {
             // quaternion /= scalar
             impl DivAssign<$t> for Quaternion<$t> {
                 fn div_assign(&mut self,other: $t) {
-                    self.r *= other;
-                    self.i *= other;
-                    self.j *= other;
-                    self.k *= other;
+                    self.r /= other;
+                    self.i /= other;
+                    self.j /= other;
+                    self.k /= other;
                 }
             }
 
             // quaternion /= complex
             impl DivAssign<Complex<$t>> for Quaternion<$t> {
                 fn div_assign(&mut self,other: Complex<$t>) {
-                    let r = self.r * other.r - self.i * other.i;
-                    let i = self.r * other.i + self.i * other.r;
-                    let j = self.j * other.r + self.k * other.i;
-                    let k = -self.j * other.i + self.k * other.r;
-                    self.r = r;
-                    self.i = i;
-                    self.j = j;
-                    self.k = k;
+                    *self = *self / other;
                 }
             }
 
             // quaternion /= quaternion
             impl DivAssign<Quaternion<$t>> for Quaternion<$t> {
                 fn div_assign(&mut self,other: Quaternion<$t>) {
-                    let r = self.r * other.r - self.i * other.i - self.j * other.j - self.k * other.k;
-                    let i = self.r * other.i + self.i * other.r + self.j * other.k - self.k * other.j;
-                    let j = self.r * other.j - self.i * other.k + self.j * other.r + self.k * other.i;
-                    let k = self.r * other.k + self.i * other.j - self.j * other.i + self.k * other.r;
-                    self.r = r;
-                    self.i = i;
-                    self.j = j;
-                    self.k = k;            
+                    *self = *self / other;
                 }
             }
 
@@ -546,6 +737,18 @@ macro_rules! quaternion_impl {
                     }
                 }
             }
+
+            impl From<Mat3x3<$t>> for Quaternion<$t> {
+                fn from(value: Mat3x3<$t>) -> Self {
+                    Quaternion::<$t>::from(Rotor::<$t>::from(value))
+                }
+            }
+
+            impl From<Quaternion<$t>> for Mat3x3<$t> {
+                fn from(value: Quaternion<$t>) -> Self {
+                    Mat3x3::<$t>::from(Rotor::<$t>::from(value))
+                }
+            }
         )+
     }
 }
@@ -24,6 +24,7 @@ use {
             DivAssign,
             Neg,
         },
+        str::FromStr,
     },
 };
 
@@ -36,7 +37,103 @@ fn _gcd<D: Copy + Zero + PartialEq + Rem<Output=D>>(mut a: D,mut b: D) -> D {
     a
 }
 
+// compare a / b with c / d by continued fraction expansion, which cannot overflow
+fn _cmp_fractions<D: Copy + Zero + Ord + Div<Output=D> + Rem<Output=D>>(mut a: D,mut b: D,mut c: D,mut d: D) -> Ordering {
+    let mut reversed = false;
+    loop {
+        let ordering = match (a / b).cmp(&(c / d)) {
+            Ordering::Equal => {
+                let r1 = a % b;
+                let r2 = c % d;
+                match (r1 == D::ZERO,r2 == D::ZERO) {
+                    (true,true) => Ordering::Equal,
+                    (true,false) => Ordering::Less,
+                    (false,true) => Ordering::Greater,
+                    _ => {
+                        // r1 / b against r2 / d is the reverse of b / r1 against d / r2
+                        a = b;
+                        b = r1;
+                        c = d;
+                        d = r2;
+                        reversed = !reversed;
+                        continue;
+                    },
+                }
+            },
+            ordering => ordering,
+        };
+        return if reversed { ordering.reverse() } else { ordering };
+    }
+}
+
+// best rational approximation p / q of x >= 0 with p <= max_p and 1 <= q <= max_q, from the continued fraction of x
+fn _approximate(x: f64,max_p: u128,max_q: u128) -> Option<(u128,u128)> {
+
+    // x is exactly mantissa * 2^exponent
+    let bits = x.to_bits();
+    let biased = ((bits >> 52) & 0x7FF) as i32;
+    let fraction = (bits & ((1 << 52) - 1)) as u128;
+    let (mut mantissa,exponent) = if biased == 0 { (fraction,-1074) } else { (fraction | (1 << 52),biased - 1075) };
+    if exponent >= 0 {
+        // the shift must not push any bits out of the top
+        if exponent as u32 > mantissa.leading_zeros() {
+            return None;
+        }
+        let p = mantissa << exponent;
+        return if p <= max_p { Some((p,1)) } else { None };
+    }
+    let mut shift = -exponent as u32;
+    if shift > 127 {
+        mantissa = mantissa.checked_shr(shift - 127).unwrap_or(0);
+        shift = 127;
+    }
+    let mut num = mantissa;
+    let mut den = 1u128 << shift;
+
+    // convergents p0 / q0 and p1 / q1
+    let (mut p0,mut q0,mut p1,mut q1) = (0u128,1u128,1u128,0u128);
+    loop {
+        let a = num / den;
+        let r = num % den;
+        let p2 = a.checked_mul(p1).and_then(|p| p.checked_add(p0));
+        let q2 = a.checked_mul(q1).and_then(|q| q.checked_add(q0));
+        match (p2,q2) {
+            (Some(p2),Some(q2)) if (p2 <= max_p) && (q2 <= max_q) => {
+                p0 = p1;
+                q0 = q1;
+                p1 = p2;
+                q1 = q2;
+            },
+            _ => {
+                if q1 == 0 {
+                    return None;
+                }
+                // the largest semiconvergent within bounds might be closer than the last convergent
+                let kp = (max_p - p0).checked_div(p1).unwrap_or(a);
+                let kq = (max_q - q0) / q1;
+                let k = Unsigned::min(kp,kq);
+                if k > 0 {
+                    let p = k * p1 + p0;
+                    let q = k * q1 + q0;
+                    if ((p as f64) / (q as f64) - x).abs() < ((p1 as f64) / (q1 as f64) - x).abs() {
+                        return Some((p,q));
+                    }
+                }
+                return Some((p1,q1));
+            },
+        }
+        if r == 0 {
+            return Some((p1,q1));
+        }
+        num = den;
+        den = r;
+    }
+}
+
 /// rational construct
+///
+/// Always kept reduced with a positive denominator, so the sign is on the numerator. Arithmetic panics on overflow,
+/// use the checked variants where overflow is expected.
 #[derive(Copy,Clone,Debug)]
 pub struct Rational<N,D> {
     n: N,
@@ -47,10 +144,188 @@ macro_rules! rational_impl {
     ($(($n:ty,$d:ty))+) => {
         $(
             impl Rational<$n,$d> {
-                fn _reduce(&mut self) {
-                    let gcd = _gcd(self.n as $d,self.d);
-                    self.n /= gcd as $n;
-                    self.d /= gcd;
+                /// Create reduced rational `n / d`.
+                pub fn new(n: $n,d: $d) -> Self {
+                    assert!(d != <$d>::ZERO,"Rational::new: zero denominator");
+                    Self::_from_parts(n < <$n>::ZERO,Self::_abs(n),d).expect("Rational::new: overflow")
+                }
+
+                pub fn numer(self) -> $n {
+                    self.n
+                }
+
+                pub fn denom(self) -> $d {
+                    self.d
+                }
+
+                /// Best rational approximation of `value` with denominator at most `max_denominator`, or `None` if
+                /// `value` is out of range.
+                pub fn approximate(value: f64,max_denominator: $d) -> Option<Self> {
+                    if !value.is_finite() || (max_denominator == <$d>::ZERO) {
+                        return None;
+                    }
+                    let negative = value < 0.0;
+                    let max_p = if negative { (<$n>::MIN as i128).unsigned_abs() } else { <$n>::MAX as u128 };
+                    if max_p == 0 {
+                        return None;
+                    }
+                    let (p,q) = _approximate(value.abs(),max_p,max_denominator as u128)?;
+                    Self::_from_parts(negative,p as $d,q as $d)
+                }
+
+                pub fn checked_add(self,other: Self) -> Option<Self> {
+                    let (negative,n,d) = other._parts();
+                    self._checked_add_parts(negative,n,d)
+                }
+
+                pub fn checked_sub(self,other: Self) -> Option<Self> {
+                    let (negative,n,d) = other._parts();
+                    self._checked_add_parts(!negative,n,d)
+                }
+
+                pub fn checked_mul(self,other: Self) -> Option<Self> {
+                    let (negative,n,d) = other._parts();
+                    self._checked_mul_parts(negative,n,d)
+                }
+
+                /// Divide, or `None` on overflow or division by zero.
+                pub fn checked_div(self,other: Self) -> Option<Self> {
+                    let (negative,n,d) = other._parts();
+                    if n == <$d>::ZERO {
+                        None
+                    }
+                    else {
+                        self._checked_mul_parts(negative,d,n)
+                    }
+                }
+
+                pub fn checked_neg(self) -> Option<Self> {
+                    let (negative,n,d) = self._parts();
+                    Self::_from_parts(!negative,n,d)
+                }
+
+                pub fn is_integer(self) -> bool {
+                    self.d == <$d>::ONE
+                }
+
+                pub fn floor(self) -> Self {
+                    let (negative,n,d) = self._parts();
+                    let q = n / d;
+                    if negative && ((n % d) != <$d>::ZERO) { Self::_integer(true,q + 1) } else { Self::_integer(negative,q) }
+                }
+
+                pub fn ceil(self) -> Self {
+                    let (negative,n,d) = self._parts();
+                    let q = n / d;
+                    if !negative && ((n % d) != <$d>::ZERO) { Self::_integer(false,q + 1) } else { Self::_integer(negative,q) }
+                }
+
+                /// Round half away from zero.
+                pub fn round(self) -> Self {
+                    let (negative,n,d) = self._parts();
+                    let q = n / d;
+                    let r = n % d;
+                    if (r != <$d>::ZERO) && (r >= d - r) { Self::_integer(negative,q + 1) } else { Self::_integer(negative,q) }
+                }
+
+                pub fn trunc(self) -> Self {
+                    let (negative,n,d) = self._parts();
+                    Self::_integer(negative,n / d)
+                }
+
+                pub fn fract(self) -> Self {
+                    self - self.trunc()
+                }
+
+                // magnitude of the numerator
+                fn _abs(n: $n) -> $d {
+                    if n < <$n>::ZERO { (n as $d).wrapping_neg() } else { n as $d }
+                }
+
+                // sign, numerator magnitude and denominator
+                fn _parts(self) -> (bool,$d,$d) {
+                    (self.n < <$n>::ZERO,Self::_abs(self.n),self.d)
+                }
+
+                // reduce and apply the sign, or `None` when the numerator does not fit
+                fn _from_parts(negative: bool,n: $d,d: $d) -> Option<Self> {
+                    let gcd = _gcd(n,d);
+                    let (n,d) = (n / gcd,d / gcd);
+                    let n = if negative && (n != <$d>::ZERO) {
+                        // two's complement negation only works when the magnitude fits
+                        let n = (n as $n).wrapping_neg();
+                        if n >= <$n>::ZERO {
+                            return None;
+                        }
+                        n
+                    }
+                    else {
+                        let n = n as $n;
+                        if n < <$n>::ZERO {
+                            return None;
+                        }
+                        n
+                    };
+                    Some(Rational { n,d, })
+                }
+
+                // integer result of rounding, which always fits
+                fn _integer(negative: bool,n: $d) -> Self {
+                    Self::_from_parts(negative,n,<$d>::ONE).expect("Rational: overflow")
+                }
+
+                // self + (-1)^negative * n / d, Knuth 4.5.1
+                fn _checked_add_parts(self,negative: bool,n: $d,d: $d) -> Option<Self> {
+                    let (self_negative,self_n,self_d) = self._parts();
+                    let gcd = _gcd(self_d,d);
+                    let native = match (self_n.checked_mul(d / gcd),n.checked_mul(self_d / gcd)) {
+                        (Some(a),Some(b)) => if self_negative == negative {
+                            a.checked_add(b).map(|t| (negative,t))
+                        }
+                        else if a >= b {
+                            Some((self_negative,a - b))
+                        }
+                        else {
+                            Some((negative,b - a))
+                        },
+                        _ => None,
+                    };
+                    let (negative,t,gcd2) = match native {
+                        Some((negative,t)) => {
+                            let gcd2 = _gcd(t,gcd);
+                            (negative,t / gcd2,gcd2)
+                        },
+                        None => {
+                            // the cross products or their sum overflow, but dividing by gcd2 might still bring the result in range
+                            let a = BigUint::from(self_n) * BigUint::from(d / gcd);
+                            let b = BigUint::from(n) * BigUint::from(self_d / gcd);
+                            let (negative,t) = if self_negative == negative {
+                                (negative,a + b)
+                            }
+                            else if a >= b {
+                                (self_negative,a - b)
+                            }
+                            else {
+                                (negative,b - a)
+                            };
+                            let r: $d = (&t % &BigUint::from(gcd)).checked_cast()?;
+                            let gcd2 = _gcd(r,gcd);
+                            (negative,(t / BigUint::from(gcd2)).checked_cast()?,gcd2)
+                        },
+                    };
+                    Self::_from_parts(negative,t,(self_d / gcd).checked_mul(d / gcd2)?)
+                }
+
+                // self * (-1)^negative * n / d, cross reduced first
+                fn _checked_mul_parts(self,negative: bool,n: $d,d: $d) -> Option<Self> {
+                    let (self_negative,self_n,self_d) = self._parts();
+                    let gcd1 = _gcd(self_n,d);
+                    let gcd2 = _gcd(n,self_d);
+                    Self::_from_parts(
+                        self_negative != negative,
+                        (self_n / gcd1).checked_mul(n / gcd2)?,
+                        (self_d / gcd2).checked_mul(d / gcd1)?,
+                    )
                 }
             }
 
@@ -68,6 +343,34 @@ macro_rules! rational_impl {
                 }
             }
 
+            impl FromStr for Rational<$n,$d> {
+                type Err = String;
+
+                /// Parse `"n/d"` or `"n"`, either part may be negative.
+                fn from_str(s: &str) -> std::result::Result<Self,String> {
+                    let (n,d) = match s.split_once('/') {
+                        Some((n,d)) => (n.trim(),d.trim()),
+                        None => (s.trim(),"1"),
+                    };
+                    let (d_negative,d) = match d.strip_prefix('-') {
+                        Some(d) => (true,d),
+                        None => (false,d),
+                    };
+                    let n = n.parse::<$n>().map_err(|error| format!("Rational::from_str: {}",error))?;
+                    let d = d.parse::<$d>().map_err(|error| format!("Rational::from_str: {}",error))?;
+                    if d == <$d>::ZERO {
+                        return Err("Rational::from_str: zero denominator".to_string());
+                    }
+                    Self::_from_parts((n < <$n>::ZERO) != d_negative,Self::_abs(n),d).ok_or_else(|| "Rational::from_str: overflow".to_string())
+                }
+            }
+
+            impl From<$n> for Rational<$n,$d> {
+                fn from(value: $n) -> Self {
+                    Rational { n: value,d: <$d>::ONE, }
+                }
+            }
+
             impl PartialEq<Rational<$n,$d>> for $n {
                 fn eq(&self,other: &Rational<$n,$d>) -> bool {
                     (*self == other.n) && (other.d == <$d>::ONE)
@@ -85,192 +388,184 @@ macro_rules! rational_impl {
                     (self.n == other.n) && (self.d == other.d)
                 }
             }
+
+            impl Eq for Rational<$n,$d> { }
             
             impl PartialOrd<Rational<$n,$d>> for $n {
                 fn partial_cmp(&self,other: &Rational<$n,$d>) -> Option<Ordering> {
-                    (self * (other.d as $n)).partial_cmp(&other.n)
+                    Some(Rational::from(*self).cmp(other))
                 }
             }
 
             impl PartialOrd<$n> for Rational<$n,$d> {
                 fn partial_cmp(&self,other: &$n) -> Option<Ordering> {
-                    self.n.partial_cmp(&(*other * (self.d as $n)))
+                    Some(self.cmp(&Rational::from(*other)))
                 }
             }
             
             impl PartialOrd<Rational<$n,$d>> for Rational<$n,$d> {
                 fn partial_cmp(&self,other: &Rational<$n,$d>) -> Option<Ordering> {
-                    (self.n * (other.d as $n)).partial_cmp(&(other.n * (self.d as $n)))
+                    Some(self.cmp(other))
                 }
-            }            
+            }
 
-            impl Add<Rational<$n,$d>> for $n {
+            impl Ord for Rational<$n,$d> {
+                fn cmp(&self,other: &Rational<$n,$d>) -> Ordering {
+                    match (self._parts(),other._parts()) {
+                        ((false,a,b),(false,c,d)) => _cmp_fractions(a,b,c,d),
+                        ((true,a,b),(true,c,d)) => _cmp_fractions(c,d,a,b),
+                        ((negative,_,_),_) => if negative { Ordering::Less } else { Ordering::Greater },
+                    }
+                }
+            }
+
+            impl Add<Rational<$n,$d>> for Rational<$n,$d> {
                 type Output = Rational<$n,$d>;
                 fn add(self,other: Rational<$n,$d>) -> Self::Output {
-                    let mut result = Rational {
-                        n: self * (other.d as $n) + other.n,
-                        d: other.d,            
-                    };
-                    result._reduce();
-                    result
+                    self.checked_add(other).expect("Rational::add: overflow")
                 }
-            }            
+            }
 
             impl Add<$n> for Rational<$n,$d> {
                 type Output = Rational<$n,$d>;
                 fn add(self,other: $n) -> Self::Output {
-                    let mut result = Rational {
-                        n: self.n + (self.d as $n) * other,
-                        d: self.d,
-                    };
-                    result._reduce();
-                    result
+                    self.checked_add(Rational::from(other)).expect("Rational::add: overflow")
                 }
             }
 
-            impl Add<Rational<$n,$d>> for Rational<$n,$d> {
+            impl Add<Rational<$n,$d>> for $n {
                 type Output = Rational<$n,$d>;
                 fn add(self,other: Rational<$n,$d>) -> Self::Output {
-                    let mut result = Rational {
-                        n: self.n * (other.d as $n) + other.n * (self.d as $n),
-                        d: self.d * other.d,
-                    };
-                    result._reduce();
-                    result
+                    Rational::from(self).checked_add(other).expect("Rational::add: overflow")
                 }
             }
 
-            impl AddAssign<$n> for Rational<$n,$d> {
-                fn add_assign(&mut self,other: $n) {
-                    self.n += other * (self.d as $n);
-                    self._reduce();
+            impl AddAssign<Rational<$n,$d>> for Rational<$n,$d> {
+                fn add_assign(&mut self,other: Rational<$n,$d>) {
+                    *self = self.checked_add(other).expect("Rational::add: overflow");
                 }
             }
 
-            impl AddAssign<Rational<$n,$d>> for Rational<$n,$d> {
-                fn add_assign(&mut self,other: Rational<$n,$d>) {
-                    self.n *= other.d as $n;
-                    self.n += other.n * (self.d as $n);
-                    self.d *= other.d;
-                    self._reduce();
+            impl AddAssign<$n> for Rational<$n,$d> {
+                fn add_assign(&mut self,other: $n) {
+                    *self = self.checked_add(Rational::from(other)).expect("Rational::add: overflow");
                 }
             }
 
-            impl Sub<Rational<$n,$d>> for $n {
+            impl Sub<Rational<$n,$d>> for Rational<$n,$d> {
                 type Output = Rational<$n,$d>;
                 fn sub(self,other: Rational<$n,$d>) -> Self::Output {
-                    let mut result = Rational {
-                        n: self * (other.d as $n) - other.n,
-                        d: other.d,            
-                    };
-                    result._reduce();
-                    result
+                    self.checked_sub(other).expect("Rational::sub: overflow")
                 }
-            }            
+            }
 
             impl Sub<$n> for Rational<$n,$d> {
                 type Output = Rational<$n,$d>;
                 fn sub(self,other: $n) -> Self::Output {
-                    let mut result = Rational {
-                        n: self.n - (self.d as $n) * other,
-                        d: self.d,
-                    };
-                    result._reduce();
-                    result
+                    self.checked_sub(Rational::from(other)).expect("Rational::sub: overflow")
                 }
             }
 
-            impl Sub<Rational<$n,$d>> for Rational<$n,$d> {
+            impl Sub<Rational<$n,$d>> for $n {
                 type Output = Rational<$n,$d>;
                 fn sub(self,other: Rational<$n,$d>) -> Self::Output {
-                    let mut result = Rational {
-                        n: self.n * (other.d as $n) - other.n * (self.d as $n),
-                        d: self.d * other.d,
-                    };
-                    result._reduce();
-                    result
+                    Rational::from(self).checked_sub(other).expect("Rational::sub: overflow")
                 }
             }
 
-            impl SubAssign<$n> for Rational<$n,$d> {
-                fn sub_assign(&mut self,other: $n) {
-                    self.n -= other * (self.d as $n);
-                    self._reduce();
+            impl SubAssign<Rational<$n,$d>> for Rational<$n,$d> {
+                fn sub_assign(&mut self,other: Rational<$n,$d>) {
+                    *self = self.checked_sub(other).expect("Rational::sub: overflow");
                 }
             }
 
-            impl SubAssign<Rational<$n,$d>> for Rational<$n,$d> {
-                fn sub_assign(&mut self,other: Rational<$n,$d>) {
-                    self.n *= other.d as $n;
-                    self.n -= other.n * (self.d as $n);
-                    self.d *= other.d;
-                    self._reduce();
+            impl SubAssign<$n> for Rational<$n,$d> {
+                fn sub_assign(&mut self,other: $n) {
+                    *self = self.checked_sub(Rational::from(other)).expect("Rational::sub: overflow");
                 }
             }
 
-            impl Mul<Rational<$n,$d>> for $n {
+            impl Mul<Rational<$n,$d>> for Rational<$n,$d> {
                 type Output = Rational<$n,$d>;
                 fn mul(self,other: Rational<$n,$d>) -> Self::Output {
-                    let mut result = Rational {
-                        n: self * other.n,
-                        d: other.d,            
-                    };
-                    result._reduce();
-                    result
+                    self.checked_mul(other).expect("Rational::mul: overflow")
                 }
-            }            
+            }
 
             impl Mul<$n> for Rational<$n,$d> {
                 type Output = Rational<$n,$d>;
                 fn mul(self,other: $n) -> Self::Output {
-                    let mut result = Rational {
-                        n: self.n * other,
-                        d: self.d,
-                    };
-                    result._reduce();
-                    result
+                    self.checked_mul(Rational::from(other)).expect("Rational::mul: overflow")
                 }
             }
 
-            impl Mul<Rational<$n,$d>> for Rational<$n,$d> {
+            impl Mul<Rational<$n,$d>> for $n {
                 type Output = Rational<$n,$d>;
                 fn mul(self,other: Rational<$n,$d>) -> Self::Output {
-                    let mut result = Rational {
-                        n: self.n * other.n,
-                        d: self.d * other.d,
-                    };
-                    result._reduce();
-                    result
+                    Rational::from(self).checked_mul(other).expect("Rational::mul: overflow")
+                }
+            }
+
+            impl MulAssign<Rational<$n,$d>> for Rational<$n,$d> {
+                fn mul_assign(&mut self,other: Rational<$n,$d>) {
+                    *self = self.checked_mul(other).expect("Rational::mul: overflow");
                 }
             }
 
             impl MulAssign<$n> for Rational<$n,$d> {
                 fn mul_assign(&mut self,other: $n) {
-                    self.n *= other;
-                    self._reduce();
+                    *self = self.checked_mul(Rational::from(other)).expect("Rational::mul: overflow");
                 }
             }
 
-            impl MulAssign<Rational<$n,$d>> for Rational<$n,$d> {
-                fn mul_assign(&mut self,other: Rational<$n,$d>) {
-                    self.n *= other.n;
-                    self.d *= other.d;
-                    self._reduce();
+            impl Div<Rational<$n,$d>> for Rational<$n,$d> {
+                type Output = Rational<$n,$d>;
+                fn div(self,other: Rational<$n,$d>) -> Self::Output {
+                    self.checked_div(other).expect("Rational::div: division by zero or overflow")
+                }
+            }
+
+            impl Div<$n> for Rational<$n,$d> {
+                type Output = Rational<$n,$d>;
+                fn div(self,other: $n) -> Self::Output {
+                    self.checked_div(Rational::from(other)).expect("Rational::div: division by zero or overflow")
+                }
+            }
+
+            impl Div<Rational<$n,$d>> for $n {
+                type Output = Rational<$n,$d>;
+                fn div(self,other: Rational<$n,$d>) -> Self::Output {
+                    Rational::from(self).checked_div(other).expect("Rational::div: division by zero or overflow")
+                }
+            }
+
+            impl DivAssign<Rational<$n,$d>> for Rational<$n,$d> {
+                fn div_assign(&mut self,other: Rational<$n,$d>) {
+                    *self = self.checked_div(other).expect("Rational::div: division by zero or overflow");
+                }
+            }
+
+            impl DivAssign<$n> for Rational<$n,$d> {
+                fn div_assign(&mut self,other: $n) {
+                    *self = self.checked_div(Rational::from(other)).expect("Rational::div: division by zero or overflow");
                 }
             }
 
             impl Unsigned for Rational<$n,$d> {
-                const MIN: Rational<$n,$d> = Rational { n: <$n>::MIN,d: <$d>::MAX, };
+                const MIN: Rational<$n,$d> = Rational { n: <$n>::MIN,d: <$d>::ONE, };
                 const MAX: Rational<$n,$d> = Rational { n: <$n>::MAX,d: <$d>::ONE, };
 
-                fn div_euclid(self,_other: Rational<$n,$d>) -> Rational<$n,$d> {
-                    // TODO
-                    Self::ZERO
+                fn div_euclid(self,other: Rational<$n,$d>) -> Rational<$n,$d> {
+                    let (negative,n,d) = other._parts();
+                    let q = (self / Self::_from_parts(false,n,d).expect("Rational::div_euclid: overflow")).floor();
+                    let (q_negative,q_n,q_d) = q._parts();
+                    Self::_from_parts(q_negative != negative,q_n,q_d).expect("Rational::div_euclid: overflow")
                 }
             
-                fn rem_euclid(self,_other: Rational<$n,$d>) -> Rational<$n,$d> {
-                    // TODO
-                    Self::ZERO
+                fn rem_euclid(self,other: Rational<$n,$d>) -> Rational<$n,$d> {
+                    let (_,n,d) = other._parts();
+                    let magnitude = Self::_from_parts(false,n,d).expect("Rational::rem_euclid: overflow");
+                    self - magnitude * (self / magnitude).floor()
                 }
             
                 fn min(self,other: Rational<$n,$d>) -> Rational<$n,$d> {
@@ -307,173 +602,164 @@ macro_rules! rational_impl {
                     self * b + c
                 }
             
-                fn powi(self,_n: i32) -> Rational<$n,$d> {
-                    // TODO
-                    Self::ZERO
+                fn powi(self,n: i32) -> Rational<$n,$d> {
+                    // powers of coprime numbers stay coprime
+                    let (negative,a,b) = self._parts();
+                    let (a,b) = if n < 0 {
+                        assert!(a != <$d>::ZERO,"Rational::powi: division by zero");
+                        (b,a)
+                    }
+                    else {
+                        (a,b)
+                    };
+                    let e = n.unsigned_abs();
+                    let a = a.checked_pow(e).expect("Rational::powi: overflow");
+                    let b = b.checked_pow(e).expect("Rational::powi: overflow");
+                    Self::_from_parts(negative && ((e & 1) != 0),a,b).expect("Rational::powi: overflow")
                 }
-            }
-        )+
-    }
-}
 
-rational_impl! { (usize,usize) (u8,u8) (u16,u16) (u32,u32) (u64,u64) (u128,u128) (isize,usize) (i8,u8) (i16,u16) (i32,u32) (i64,u64) (i128,u128) }
+                fn checked_add(self,other: Self) -> Option<Self> {
+                    self.checked_add(other)
+                }
 
-macro_rules! rational_impl_unsigned {
-    ($(($n:ty,$d:ty))+) => {
-        $(
-            impl Div<Rational<$n,$d>> for $n {
-                type Output = Rational<$n,$d>;
-                fn div(self,other: Rational<$n,$d>) -> Self::Output {
-                    let mut result = Rational {
-                        n: self * (other.d as $n),
-                        d: other.n as $d,            
-                    };
-                    result._reduce();
-                    result
+                fn checked_sub(self,other: Self) -> Option<Self> {
+                    self.checked_sub(other)
                 }
-            }            
-            
-            impl Div<$n> for Rational<$n,$d> {
-                type Output = Rational<$n,$d>;
-                fn div(self,other: $n) -> Self::Output {
-                    let mut result = Rational {
-                        n: self.n,
-                        d: self.d * (other as $d),
-                    };
-                    result._reduce();
-                    result
+
+                fn checked_mul(self,other: Self) -> Option<Self> {
+                    self.checked_mul(other)
                 }
-            }
-            
-            impl Div<Rational<$n,$d>> for Rational<$n,$d> {
-                type Output = Rational<$n,$d>;
-                fn div(self,other: Rational<$n,$d>) -> Self::Output {
-                    let mut result = Rational {
-                        n: self.n * (other.d as $n),
-                        d: self.d * (other.n as $d),
-                    };
-                    result._reduce();
-                    result
+
+                fn checked_div(self,other: Self) -> Option<Self> {
+                    self.checked_div(other)
                 }
-            }
 
-            impl DivAssign<$n> for Rational<$n,$d> {
-                fn div_assign(&mut self,other: $n) {
-                    self.d *= other as $d;
-                    self._reduce();
+                fn saturating_add(self,other: Self) -> Self {
+                    // closest value through f64 when the exact result does not fit
+                    self.checked_add(other).unwrap_or_else(|| {
+                        let a: f64 = self.lossy_cast();
+                        let b: f64 = other.lossy_cast();
+                        (a + b).saturating_cast()
+                    })
+                }
+
+                fn saturating_sub(self,other: Self) -> Self {
+                    // closest value through f64 when the exact result does not fit
+                    self.checked_sub(other).unwrap_or_else(|| {
+                        let a: f64 = self.lossy_cast();
+                        let b: f64 = other.lossy_cast();
+                        (a - b).saturating_cast()
+                    })
+                }
+
+                fn saturating_mul(self,other: Self) -> Self {
+                    // closest value through f64 when the exact result does not fit
+                    self.checked_mul(other).unwrap_or_else(|| {
+                        let a: f64 = self.lossy_cast();
+                        let b: f64 = other.lossy_cast();
+                        (a * b).saturating_cast()
+                    })
+                }
+
+                fn saturating_div(self,other: Self) -> Self {
+                    // closest value through f64 when the exact result does not fit
+                    self.checked_div(other).unwrap_or_else(|| {
+                        let a: f64 = self.lossy_cast();
+                        let b: f64 = other.lossy_cast();
+                        (a / b).saturating_cast()
+                    })
+                }
+
+                // there is no modular meaning, so wrapping is the saturating result
+                fn wrapping_add(self,other: Self) -> Self {
+                    Unsigned::saturating_add(self,other)
+                }
+
+                fn wrapping_sub(self,other: Self) -> Self {
+                    Unsigned::saturating_sub(self,other)
+                }
+
+                fn wrapping_mul(self,other: Self) -> Self {
+                    Unsigned::saturating_mul(self,other)
+                }
+
+                fn wrapping_div(self,other: Self) -> Self {
+                    Unsigned::saturating_div(self,other)
                 }
             }
 
-            impl DivAssign<Rational<$n,$d>> for Rational<$n,$d> {
-                fn div_assign(&mut self,other: Rational<$n,$d>) {
-                    self.n *= other.d as $n;
-                    self.d *= other.n as $d;
-                    self._reduce();
+            impl Cast<Rational<$n,$d>> for $n {
+                fn checked_cast(self) -> Option<Rational<$n,$d>> {
+                    Some(Rational { n: self,d: <$d>::ONE, })
+                }
+
+                fn saturating_cast(self) -> Rational<$n,$d> {
+                    Rational { n: self,d: <$d>::ONE, }
+                }
+
+                fn lossy_cast(self) -> Rational<$n,$d> {
+                    Rational { n: self,d: <$d>::ONE, }
                 }
             }
         )+
     }
 }
 
-rational_impl_unsigned! { (usize,usize) (u8,u8) (u16,u16) (u32,u32) (u64,u64) (u128,u128) }
+rational_impl! { (usize,usize) (u8,u8) (u16,u16) (u32,u32) (u64,u64) (u128,u128) (isize,usize) (i8,u8) (i16,u16) (i32,u32) (i64,u64) (i128,u128) }
 
-macro_rules! rational_impl_signed {
-    ($(($n:ty,$d:ty))+) => {
+macro_rules! rational_cast_impl {
+    ($f:ty => $(($n:ty,$d:ty))+) => {
         $(
-            impl Div<Rational<$n,$d>> for $n {
-                type Output = Rational<$n,$d>;
-                fn div(self,other: Rational<$n,$d>) -> Self::Output {
-                    let mut result = if other.n < <$n>::ZERO {
-                        Rational {
-                            n: -(self * (other.d as $n)),
-                            d: -other.n as $d,
-                        }
-                    }
-                    else {
-                        Rational {
-                            n: self * (other.d as $n),
-                            d: other.n as $d,            
-                        }
-                    };
-                    result._reduce();
-                    result
+            impl Cast<$f> for Rational<$n,$d> {
+                fn checked_cast(self) -> Option<$f> {
+                    Some(self.lossy_cast())
                 }
-            }            
-            
-            impl Div<$n> for Rational<$n,$d> {
-                type Output = Rational<$n,$d>;
-                fn div(self,other: $n) -> Self::Output {
-                    let mut result = if other < <$n>::ZERO {
-                        Rational {
-                            n: -self.n,
-                            d: self.d * (-other as $d),
-                        }
-                    }
-                    else {
-                        Rational {
-                            n: self.n,
-                            d: self.d * (other as $d),
-                        }
-                    };
-                    result._reduce();
-                    result
+
+                fn saturating_cast(self) -> $f {
+                    self.lossy_cast()
                 }
-            }
-            
-            impl Div<Rational<$n,$d>> for Rational<$n,$d> {
-                type Output = Rational<$n,$d>;
-                fn div(self,other: Rational<$n,$d>) -> Self::Output {
-                    let mut result = if other.n < 0 {
-                        Rational {
-                            n: -self.n * (other.d as $n),
-                            d: self.d * (-other.n as $d),
-                        }
-                    }
-                    else {
-                        Rational {
-                            n: self.n * (other.d as $n),
-                            d: self.d * (other.n as $d),
-                        }
-                    };
-                    result._reduce();
-                    result
+
+                fn lossy_cast(self) -> $f {
+                    (self.n as $f) / (self.d as $f)
                 }
             }
 
-            impl DivAssign<$n> for Rational<$n,$d> {
-                fn div_assign(&mut self,other: $n) {
-                    if other < <$n>::ZERO {
-                        self.n = -self.n;
-                        self.d *= -other as $d;
-                    }
-                    else {
-                        self.d *= other as $d;
-                    }
-                    self._reduce();
+            impl Cast<Rational<$n,$d>> for $f {
+                /// Best rational approximation, or `None` if out of range.
+                fn checked_cast(self) -> Option<Rational<$n,$d>> {
+                    Rational::<$n,$d>::approximate(self as f64,<$d>::MAX)
                 }
-            }
 
-            impl DivAssign<Rational<$n,$d>> for Rational<$n,$d> {
-                fn div_assign(&mut self,other: Rational<$n,$d>) {
-                    if other.n < <$n>::ZERO {
-                        self.n *= -(other.d as $n);
-                        self.d *= -other.n as $d;
+                fn saturating_cast(self) -> Rational<$n,$d> {
+                    if self.is_nan() {
+                        Rational::ZERO
                     }
                     else {
-                        self.n *= other.d as $n;
-                        self.d *= other.n as $d;
+                        match self.checked_cast() {
+                            Some(value) => value,
+                            None => if self > 0.0 { <Rational<$n,$d> as Unsigned>::MAX } else { <Rational<$n,$d> as Unsigned>::MIN },
+                        }
                     }
-                    self._reduce();
+                }
+
+                fn lossy_cast(self) -> Rational<$n,$d> {
+                    self.saturating_cast()
                 }
             }
+        )+
+    }
+}
 
+rational_cast_impl! { f32 => (usize,usize) (u8,u8) (u16,u16) (u32,u32) (u64,u64) (u128,u128) (isize,usize) (i8,u8) (i16,u16) (i32,u32) (i64,u64) (i128,u128) }
+rational_cast_impl! { f64 => (usize,usize) (u8,u8) (u16,u16) (u32,u32) (u64,u64) (u128,u128) (isize,usize) (i8,u8) (i16,u16) (i32,u32) (i64,u64) (i128,u128) }
+
+macro_rules! rational_impl_signed {
+    ($(($n:ty,$d:ty))+) => {
+        $(
             impl Neg for Rational<$n,$d> {
                 type Output = Self;
                 fn neg(self) -> Self::Output {
-                    Rational {
-                        n: -self.n,
-                        d: self.d,
-                    }
+                    self.checked_neg().expect("Rational::neg: overflow")
                 }
             }
 
@@ -542,3 +828,527 @@ pub type r64 = Rational<i64,u64>;
 
 #[allow(non_camel_case_types)]
 pub type r128 = Rational<i128,u128>;
+
+// exact rationals on big integers, never overflow
+
+impl Rational<BigInt,BigUint> {
+    /// Create reduced rational `n / d`.
+    pub fn new(n: BigInt,d: BigUint) -> Self {
+        assert!(!d.is_zero(),"Rational::new: zero denominator");
+        Self::_reduce(n,d)
+    }
+
+    pub fn numer(&self) -> &BigInt {
+        &self.n
+    }
+
+    pub fn denom(&self) -> &BigUint {
+        &self.d
+    }
+
+    /// Divide, or `None` on division by zero.
+    pub fn checked_div(&self,other: &Self) -> Option<Self> {
+        if other.n.is_zero() {
+            None
+        }
+        else {
+            Some(self / other)
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.d == BigUint::ONE
+    }
+
+    pub fn floor(&self) -> Self {
+        let (q,r) = self._divrem();
+        Rational::from(if r < BigInt::ZERO { q - BigInt::ONE } else { q })
+    }
+
+    pub fn ceil(&self) -> Self {
+        let (q,r) = self._divrem();
+        Rational::from(if r > BigInt::ZERO { q + BigInt::ONE } else { q })
+    }
+
+    /// Round half away from zero.
+    pub fn round(&self) -> Self {
+        let (q,r) = self._divrem();
+        if (r.magnitude() << 1) >= self.d {
+            Rational::from(if self.n < BigInt::ZERO { q - BigInt::ONE } else { q + BigInt::ONE })
+        }
+        else {
+            Rational::from(q)
+        }
+    }
+
+    pub fn trunc(&self) -> Self {
+        Rational::from(self._divrem().0)
+    }
+
+    pub fn fract(&self) -> Self {
+        self - &self.trunc()
+    }
+
+    // truncated quotient and remainder of numerator and denominator
+    fn _divrem(&self) -> (BigInt,BigInt) {
+        self.n.divrem(&BigInt::from(self.d.clone()))
+    }
+
+    // divide out the common factor, the denominator is not zero
+    fn _reduce(n: BigInt,d: BigUint) -> Self {
+        let gcd = n.magnitude().gcd(&d);
+        if gcd == BigUint::ONE {
+            Rational { n,d, }
+        }
+        else {
+            let negative = n < BigInt::ZERO;
+            Rational {
+                n: BigInt::new(negative,n.magnitude() / &gcd),
+                d: &d / &gcd,
+            }
+        }
+    }
+}
+
+impl Zero for Rational<BigInt,BigUint> {
+    const ZERO: Self = Rational { n: BigInt::ZERO,d: BigUint::ONE, };
+}
+
+impl One for Rational<BigInt,BigUint> {
+    const ONE: Self = Rational { n: BigInt::ONE,d: BigUint::ONE, };
+}
+
+impl Display for Rational<BigInt,BigUint> {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"{}/{}",self.n,self.d)
+    }
+}
+
+impl FromStr for Rational<BigInt,BigUint> {
+    type Err = String;
+
+    /// Parse `"n/d"` or `"n"`, either part may be negative.
+    fn from_str(s: &str) -> std::result::Result<Self,String> {
+        let (n,d) = match s.split_once('/') {
+            Some((n,d)) => (n.trim(),d.trim()),
+            None => (s.trim(),"1"),
+        };
+        let (d_negative,d) = match d.strip_prefix('-') {
+            Some(d) => (true,d),
+            None => (false,d),
+        };
+        let n = n.parse::<BigInt>().map_err(|error| format!("Rational::from_str: {}",error))?;
+        let d = d.parse::<BigUint>().map_err(|error| format!("Rational::from_str: {}",error))?;
+        if d.is_zero() {
+            return Err("Rational::from_str: zero denominator".to_string());
+        }
+        Ok(Self::_reduce(if d_negative { -n } else { n },d))
+    }
+}
+
+impl From<BigInt> for Rational<BigInt,BigUint> {
+    fn from(value: BigInt) -> Self {
+        Rational { n: value,d: BigUint::ONE, }
+    }
+}
+
+impl PartialEq<Rational<BigInt,BigUint>> for Rational<BigInt,BigUint> {
+    fn eq(&self,other: &Rational<BigInt,BigUint>) -> bool {
+        (self.n == other.n) && (self.d == other.d)
+    }
+}
+
+impl PartialEq<BigInt> for Rational<BigInt,BigUint> {
+    fn eq(&self,other: &BigInt) -> bool {
+        (self.n == *other) && (self.d == BigUint::ONE)
+    }
+}
+
+impl Eq for Rational<BigInt,BigUint> { }
+
+impl PartialOrd<Rational<BigInt,BigUint>> for Rational<BigInt,BigUint> {
+    fn partial_cmp(&self,other: &Rational<BigInt,BigUint>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational<BigInt,BigUint> {
+    fn cmp(&self,other: &Rational<BigInt,BigUint>) -> Ordering {
+        (&self.n * &BigInt::from(other.d.clone())).cmp(&(&other.n * &BigInt::from(self.d.clone())))
+    }
+}
+
+// operators by value and by reference, all through the reference version
+macro_rules! rational_big_op_impl {
+    ($($trait:ident $method:ident $assign_trait:ident $assign_method:ident)+) => {
+        $(
+            impl $trait<Rational<BigInt,BigUint>> for Rational<BigInt,BigUint> {
+                type Output = Rational<BigInt,BigUint>;
+                fn $method(self,other: Rational<BigInt,BigUint>) -> Self::Output {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl $trait<&Rational<BigInt,BigUint>> for Rational<BigInt,BigUint> {
+                type Output = Rational<BigInt,BigUint>;
+                fn $method(self,other: &Rational<BigInt,BigUint>) -> Self::Output {
+                    (&self).$method(other)
+                }
+            }
+
+            impl $trait<Rational<BigInt,BigUint>> for &Rational<BigInt,BigUint> {
+                type Output = Rational<BigInt,BigUint>;
+                fn $method(self,other: Rational<BigInt,BigUint>) -> Self::Output {
+                    self.$method(&other)
+                }
+            }
+
+            impl $trait<BigInt> for Rational<BigInt,BigUint> {
+                type Output = Rational<BigInt,BigUint>;
+                fn $method(self,other: BigInt) -> Self::Output {
+                    (&self).$method(&Rational::from(other))
+                }
+            }
+
+            impl $trait<Rational<BigInt,BigUint>> for BigInt {
+                type Output = Rational<BigInt,BigUint>;
+                fn $method(self,other: Rational<BigInt,BigUint>) -> Self::Output {
+                    (&Rational::from(self)).$method(&other)
+                }
+            }
+
+            impl $assign_trait<Rational<BigInt,BigUint>> for Rational<BigInt,BigUint> {
+                fn $assign_method(&mut self,other: Rational<BigInt,BigUint>) {
+                    *self = (&*self).$method(&other);
+                }
+            }
+
+            impl $assign_trait<&Rational<BigInt,BigUint>> for Rational<BigInt,BigUint> {
+                fn $assign_method(&mut self,other: &Rational<BigInt,BigUint>) {
+                    *self = (&*self).$method(other);
+                }
+            }
+
+            impl $assign_trait<BigInt> for Rational<BigInt,BigUint> {
+                fn $assign_method(&mut self,other: BigInt) {
+                    *self = (&*self).$method(&Rational::from(other));
+                }
+            }
+        )+
+    }
+}
+
+rational_big_op_impl! {
+    Add add AddAssign add_assign
+    Sub sub SubAssign sub_assign
+    Mul mul MulAssign mul_assign
+    Div div DivAssign div_assign
+}
+
+// rational + rational
+impl Add<&Rational<BigInt,BigUint>> for &Rational<BigInt,BigUint> {
+    type Output = Rational<BigInt,BigUint>;
+    fn add(self,other: &Rational<BigInt,BigUint>) -> Self::Output {
+        let gcd = self.d.gcd(&other.d);
+        let a = &self.n * &BigInt::from(&other.d / &gcd);
+        let b = &other.n * &BigInt::from(&self.d / &gcd);
+        Rational::_reduce(a + b,&(&self.d / &gcd) * &other.d)
+    }
+}
+
+// rational - rational
+impl Sub<&Rational<BigInt,BigUint>> for &Rational<BigInt,BigUint> {
+    type Output = Rational<BigInt,BigUint>;
+    fn sub(self,other: &Rational<BigInt,BigUint>) -> Self::Output {
+        self + &-other
+    }
+}
+
+// rational * rational
+impl Mul<&Rational<BigInt,BigUint>> for &Rational<BigInt,BigUint> {
+    type Output = Rational<BigInt,BigUint>;
+    fn mul(self,other: &Rational<BigInt,BigUint>) -> Self::Output {
+        Rational::_reduce(&self.n * &other.n,&self.d * &other.d)
+    }
+}
+
+// rational / rational
+impl Div<&Rational<BigInt,BigUint>> for &Rational<BigInt,BigUint> {
+    type Output = Rational<BigInt,BigUint>;
+    fn div(self,other: &Rational<BigInt,BigUint>) -> Self::Output {
+        assert!(!other.n.is_zero(),"Rational::div: division by zero");
+        let negative = (self.n < BigInt::ZERO) != (other.n < BigInt::ZERO);
+        let n = self.n.magnitude() * &other.d;
+        Rational::_reduce(BigInt::new(negative,n),&self.d * other.n.magnitude())
+    }
+}
+
+// -rational
+impl Neg for &Rational<BigInt,BigUint> {
+    type Output = Rational<BigInt,BigUint>;
+    fn neg(self) -> Self::Output {
+        Rational { n: -&self.n,d: self.d.clone(), }
+    }
+}
+
+impl Neg for Rational<BigInt,BigUint> {
+    type Output = Rational<BigInt,BigUint>;
+    fn neg(self) -> Self::Output {
+        Rational { n: -self.n,d: self.d, }
+    }
+}
+
+// big rationals have no bounds, MIN and MAX only exist for the trait and are nothing to saturate to
+impl Unsigned for Rational<BigInt,BigUint> {
+    const MIN: Rational<BigInt,BigUint> = Rational { n: <BigInt as Unsigned>::MIN,d: BigUint::ONE, };
+    const MAX: Rational<BigInt,BigUint> = Rational { n: <BigInt as Unsigned>::MAX,d: BigUint::ONE, };
+
+    fn div_euclid(self,other: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        let negative = other.n < BigInt::ZERO;
+        let q = (self / other.abs()).floor();
+        if negative { -q } else { q }
+    }
+
+    fn rem_euclid(self,other: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        let magnitude = other.abs();
+        let q = (&self / &magnitude).floor();
+        self - magnitude * q
+    }
+
+    fn min(self,other: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        if other < self {
+            other
+        }
+        else {
+            self
+        }
+    }
+
+    fn max(self,other: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        if other > self {
+            other
+        }
+        else {
+            self
+        }
+    }
+
+    fn clamp(self,min: Rational<BigInt,BigUint>,max: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        if max < self {
+            max
+        }
+        else if min > self {
+            min
+        }
+        else {
+            self
+        }
+    }
+
+    fn mul_add(self,b: Rational<BigInt,BigUint>,c: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        self * b + c
+    }
+
+    fn powi(self,n: i32) -> Rational<BigInt,BigUint> {
+        // powers of coprime numbers stay coprime
+        let e = n.unsigned_abs();
+        let negative = (self.n < BigInt::ZERO) && ((e & 1) != 0);
+        let (a,b) = if n < 0 {
+            assert!(!self.n.is_zero(),"Rational::powi: division by zero");
+            (self.d,self.n.into_magnitude())
+        }
+        else {
+            (self.n.into_magnitude(),self.d)
+        };
+        Rational { n: BigInt::new(negative,a.pow(e)),d: b.pow(e), }
+    }
+
+    fn checked_add(self,other: Rational<BigInt,BigUint>) -> Option<Rational<BigInt,BigUint>> {
+        Some(self + other)
+    }
+
+    fn checked_sub(self,other: Rational<BigInt,BigUint>) -> Option<Rational<BigInt,BigUint>> {
+        Some(self - other)
+    }
+
+    fn checked_mul(self,other: Rational<BigInt,BigUint>) -> Option<Rational<BigInt,BigUint>> {
+        Some(self * other)
+    }
+
+    fn checked_div(self,other: Rational<BigInt,BigUint>) -> Option<Rational<BigInt,BigUint>> {
+        if other.n.is_zero() { None } else { Some(self / other) }
+    }
+
+    fn saturating_add(self,other: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        self + other
+    }
+
+    fn saturating_sub(self,other: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        self - other
+    }
+
+    fn saturating_mul(self,other: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        self * other
+    }
+
+    fn saturating_div(self,other: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        self / other
+    }
+
+    fn wrapping_add(self,other: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        self + other
+    }
+
+    fn wrapping_sub(self,other: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        self - other
+    }
+
+    fn wrapping_mul(self,other: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        self * other
+    }
+
+    fn wrapping_div(self,other: Rational<BigInt,BigUint>) -> Rational<BigInt,BigUint> {
+        self / other
+    }
+}
+
+impl Signed for Rational<BigInt,BigUint> {
+    fn abs(self) -> Self {
+        Rational { n: self.n.abs(),d: self.d, }
+    }
+
+    fn signum(self) -> Self {
+        Rational::from(self.n.signum())
+    }
+
+    fn is_negative(self) -> bool {
+        self.n < BigInt::ZERO
+    }
+
+    fn copysign(self,sign: Self) -> Self {
+        Rational { n: self.n.copysign(sign.n),d: self.d, }
+    }
+}
+
+impl Cast<Rational<BigInt,BigUint>> for BigInt {
+    fn checked_cast(self) -> Option<Rational<BigInt,BigUint>> {
+        Some(Rational::from(self))
+    }
+
+    fn saturating_cast(self) -> Rational<BigInt,BigUint> {
+        Rational::from(self)
+    }
+
+    fn lossy_cast(self) -> Rational<BigInt,BigUint> {
+        Rational::from(self)
+    }
+}
+
+// 2^k, exact for the whole f64 range including subnormals, zero or infinity outside
+fn _exp2(k: i32) -> f64 {
+    if k > 1023 {
+        f64::INFINITY
+    }
+    else if k >= -1022 {
+        f64::from_bits(((k + 1023) as u64) << 52)
+    }
+    else if k >= -1074 {
+        f64::from_bits(1 << (k + 1074))
+    }
+    else {
+        0.0
+    }
+}
+
+macro_rules! rational_big_cast_impl {
+    ($($f:ty)+) => {
+        $(
+            impl Cast<$f> for Rational<BigInt,BigUint> {
+                /// Nearest float, or `None` if it overflows to infinity.
+                fn checked_cast(self) -> Option<$f> {
+                    let value: $f = self.lossy_cast();
+                    if value.is_finite() { Some(value) } else { None }
+                }
+
+                fn saturating_cast(self) -> $f {
+                    let value: $f = self.lossy_cast();
+                    if value.is_finite() { value } else if value > 0.0 { <$f>::MAX } else { <$f>::MIN }
+                }
+
+                fn lossy_cast(self) -> $f {
+                    if self.n.is_zero() {
+                        return 0.0;
+                    }
+                    let negative = self.n < BigInt::ZERO;
+                    let n = self.n.into_magnitude();
+                    let digits = <$f>::MANTISSA_DIGITS as i32;
+
+                    // n / d is in [2^(e - 1),2^(e + 1)), so n / d / 2^k has digits or digits + 1 bits, or fewer when k is
+                    // clamped to the exponent of the smallest subnormal
+                    let e = (n.bits() as i32) - (self.d.bits() as i32);
+                    let mut k = Ord::max(e - digits,<$f>::MIN_EXP - digits);
+                    let (q,r,d) = loop {
+                        let (q,r,d) = if k >= 0 {
+                            let d = &self.d << (k as usize);
+                            let (q,r) = n.divrem(&d);
+                            (q,r,d)
+                        }
+                        else {
+                            let (q,r) = (&n << (-k as usize)).divrem(&self.d);
+                            (q,r,self.d.clone())
+                        };
+                        if (q.bits() as i32) <= digits {
+                            break (q,r,d);
+                        }
+                        k += 1;
+                    };
+
+                    // round once, half to even, then scale exactly
+                    let q = match (r << 1).cmp(&d) {
+                        Ordering::Greater => q + BigUint::ONE,
+                        Ordering::Equal if q.bit(0) => q + BigUint::ONE,
+                        _ => q,
+                    };
+                    let q: f64 = q.lossy_cast();
+                    let value = (q * _exp2(k)) as $f;
+                    if negative { -value } else { value }
+                }
+            }
+
+            impl Cast<Rational<BigInt,BigUint>> for $f {
+                /// Exact value, or `None` if infinite or NaN.
+                fn checked_cast(self) -> Option<Rational<BigInt,BigUint>> {
+                    if !self.is_finite() {
+                        return None;
+                    }
+                    let bits = (self as f64).to_bits();
+                    let exponent = ((bits >> 52) & 0x7FF) as i32;
+                    let mantissa = bits & 0xFFFFFFFFFFFFF;
+                    let (mantissa,exponent) = if exponent == 0 { (mantissa,-1074) } else { (mantissa | (1 << 52),exponent - 1075) };
+                    let n = BigUint::from(mantissa);
+                    let (n,d) = if exponent >= 0 { (n << (exponent as usize),BigUint::ONE) } else { (n,BigUint::ONE << (-exponent as usize)) };
+                    Some(Rational::_reduce(BigInt::new(self < 0.0,n),d))
+                }
+
+                fn saturating_cast(self) -> Rational<BigInt,BigUint> {
+                    if self.is_nan() {
+                        Rational::ZERO
+                    }
+                    else {
+                        match self.checked_cast() {
+                            Some(value) => value,
+                            // there is no largest rational, so infinity saturates to the largest float
+                            None => if self > 0.0 { <$f>::MAX.lossy_cast() } else { <$f>::MIN.lossy_cast() },
+                        }
+                    }
+                }
+
+                fn lossy_cast(self) -> Rational<BigInt,BigUint> {
+                    self.saturating_cast()
+                }
+            }
+        )+
+    }
+}
+
+rational_big_cast_impl! { f32 f64 }
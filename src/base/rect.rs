@@ -1,6 +1,7 @@
 use {
     crate::*,
     std::{
+        cmp::PartialEq,
         fmt::{
             Display,
             Debug,
@@ -10,12 +11,26 @@ use {
     },
 };
 
+/// Rectangle with origin `o` and size `s`.
+///
+/// The origin is the corner with the smallest coordinates, so `o` is the min corner and `o + s` the max corner.
+/// Rectangles with zero or negative width or height are empty.
 #[derive(Copy,Clone,Debug)]
 pub struct Rect<T> {
     pub o: Vec2<T>,
     pub s: Vec2<T>,
 }
 
+/// Placement along one axis, see [`Rect::align`].
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum Align {
+    /// at the smallest coordinate, left or top
+    Start,
+    Center,
+    /// at the largest coordinate, right or bottom
+    End,
+}
+
 macro_rules! rect_impl {
     ($($t:ty)+) => {
         $(
@@ -25,13 +40,135 @@ macro_rules! rect_impl {
                 }
             }
 
+            impl PartialEq for Rect<$t> {
+                fn eq(&self,other: &Self) -> bool {
+                    (self.o == other.o) && (self.s == other.s)
+                }
+            }
+
             impl Rect<$t> {
+                pub fn from_min_max(min: Vec2<$t>,max: Vec2<$t>) -> Self {
+                    Rect { o: min,s: max - min, }
+                }
+
+                pub fn min(&self) -> Vec2<$t> {
+                    self.o
+                }
+
+                pub fn max(&self) -> Vec2<$t> {
+                    self.o + self.s
+                }
+
+                pub fn area(&self) -> $t {
+                    self.s.x * self.s.y
+                }
+
+                pub fn is_empty(&self) -> bool {
+                    (self.s.x <= <$t>::ZERO) || (self.s.y <= <$t>::ZERO)
+                }
+
+                /// Whether `p` is inside, including the min edges and excluding the max edges.
                 pub fn contains(&self,p: Vec2<$t>) -> bool {
                     (p.x >= self.o.x) && (p.y >= self.o.y) && (p.x < self.o.x + self.s.x) && (p.y < self.o.y + self.s.y)
                 }
+
+                /// Whether `other` is completely inside. Empty rectangles are inside everything.
+                pub fn contains_rect(&self,other: &Rect<$t>) -> bool {
+                    let max = self.max();
+                    let other_max = other.max();
+                    other.is_empty() || ((other.o.x >= self.o.x) && (other.o.y >= self.o.y) && (other_max.x <= max.x) && (other_max.y <= max.y))
+                }
+
+                /// Whether the rectangles overlap. Touching edges do not overlap.
+                pub fn intersects(&self,other: &Rect<$t>) -> bool {
+                    self.intersection(other).is_some()
+                }
+
+                /// Overlap of both rectangles, or `None` if they do not overlap.
+                pub fn intersection(&self,other: &Rect<$t>) -> Option<Rect<$t>> {
+                    let r = Rect::<$t>::from_min_max(self.o.max(other.o),self.max().min(other.max()));
+                    if r.is_empty() {
+                        None
+                    }
+                    else {
+                        Some(r)
+                    }
+                }
+
+                /// Smallest rectangle around both rectangles, where empty rectangles are ignored.
+                pub fn union(&self,other: &Rect<$t>) -> Rect<$t> {
+                    if other.is_empty() {
+                        *self
+                    }
+                    else if self.is_empty() {
+                        *other
+                    }
+                    else {
+                        Rect::<$t>::from_min_max(self.o.min(other.o),self.max().max(other.max()))
+                    }
+                }
+
+                /// Grow by `d` on every side.
+                pub fn inflate(&self,d: Vec2<$t>) -> Rect<$t> {
+                    Rect { o: self.o - d,s: self.s + d + d, }
+                }
+
+                /// Shrink by `d` on every side, down to zero size around the center.
+                pub fn deflate(&self,d: Vec2<$t>) -> Rect<$t> {
+                    let two = <$t>::ONE + <$t>::ONE;
+                    let d = d.min(self.s / two);
+                    Rect { o: self.o + d,s: self.s - d - d, }
+                }
+
+                pub fn translate(&self,v: Vec2<$t>) -> Rect<$t> {
+                    Rect { o: self.o + v,s: self.s, }
+                }
+
+                /// Split into a left part `x` wide and a right part with the rest, with `x` clamped to the width.
+                pub fn split_horizontal(&self,x: $t) -> (Rect<$t>,Rect<$t>) {
+                    let x = Unsigned::clamp(x,<$t>::ZERO,self.s.x);
+                    (
+                        Rect { o: self.o,s: Vec2 { x,y: self.s.y, }, },
+                        Rect { o: Vec2 { x: self.o.x + x,y: self.o.y, },s: Vec2 { x: self.s.x - x,y: self.s.y, }, },
+                    )
+                }
+
+                /// Split into a top part `y` high and a bottom part with the rest, with `y` clamped to the height.
+                pub fn split_vertical(&self,y: $t) -> (Rect<$t>,Rect<$t>) {
+                    let y = Unsigned::clamp(y,<$t>::ZERO,self.s.y);
+                    (
+                        Rect { o: self.o,s: Vec2 { x: self.s.x,y, }, },
+                        Rect { o: Vec2 { x: self.o.x,y: self.o.y + y, },s: Vec2 { x: self.s.x,y: self.s.y - y, }, },
+                    )
+                }
+
+                /// Same size, placed inside `parent` as specified by `h` and `v`.
+                ///
+                /// Centering integer rectangles truncates the offset like integer division. Rectangles larger than
+                /// `parent` stick out on the far side, or on both sides when centered.
+                pub fn align(&self,parent: &Rect<$t>,h: Align,v: Align) -> Rect<$t> {
+                    let two = <$t>::ONE + <$t>::ONE;
+                    let place = |o: $t,s: $t,ps: $t,align: Align| match align {
+                        Align::Start => o,
+                        Align::Center => o + (ps - s) / two,
+                        Align::End => o + ps - s,
+                    };
+                    Rect {
+                        o: Vec2 {
+                            x: place(parent.o.x,self.s.x,parent.s.x,h),
+                            y: place(parent.o.y,self.s.y,parent.s.y,v),
+                        },
+                        s: self.s,
+                    }
+                }
+
+                /// Closest point to `p` from min to max, including the max edges.
+                pub fn clamp(&self,p: Vec2<$t>) -> Vec2<$t> {
+                    p.clamp(self.o,self.max())
+                }
             }
         )+
     }
 }
 
-rect_impl! { isize i8 i16 i32 i64 i128 f32 f64 }
+rect_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
@@ -0,0 +1,166 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+    },
+};
+
+/// Box with origin `o` and size `s`, the 3D counterpart of [`Rect`].
+///
+/// `o` is the min corner and `o + s` the max corner. Boxes with zero or negative size on any axis are empty.
+#[derive(Copy,Clone,Debug)]
+pub struct Rect3<T> {
+    pub o: Vec3<T>,
+    pub s: Vec3<T>,
+}
+
+macro_rules! rect3_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Display for Rect3<$t> {
+                fn fmt(&self,f: &mut Formatter) -> Result {
+                    write!(f,"({},{},{} {}x{}x{})",self.o.x,self.o.y,self.o.z,self.s.x,self.s.y,self.s.z)
+                }
+            }
+
+            impl PartialEq for Rect3<$t> {
+                fn eq(&self,other: &Self) -> bool {
+                    (self.o == other.o) && (self.s == other.s)
+                }
+            }
+
+            impl Rect3<$t> {
+                pub fn from_min_max(min: Vec3<$t>,max: Vec3<$t>) -> Self {
+                    Rect3 { o: min,s: max - min, }
+                }
+
+                pub fn min(&self) -> Vec3<$t> {
+                    self.o
+                }
+
+                pub fn max(&self) -> Vec3<$t> {
+                    self.o + self.s
+                }
+
+                pub fn volume(&self) -> $t {
+                    self.s.x * self.s.y * self.s.z
+                }
+
+                pub fn is_empty(&self) -> bool {
+                    (self.s.x <= <$t>::ZERO) || (self.s.y <= <$t>::ZERO) || (self.s.z <= <$t>::ZERO)
+                }
+
+                /// Whether `p` is inside, including the min faces and excluding the max faces.
+                pub fn contains(&self,p: Vec3<$t>) -> bool {
+                    let max = self.max();
+                    (p.x >= self.o.x) && (p.y >= self.o.y) && (p.z >= self.o.z) && (p.x < max.x) && (p.y < max.y) && (p.z < max.z)
+                }
+
+                /// Whether `other` is completely inside. Empty boxes are inside everything.
+                pub fn contains_rect(&self,other: &Rect3<$t>) -> bool {
+                    let max = self.max();
+                    let other_max = other.max();
+                    other.is_empty() || (
+                        (other.o.x >= self.o.x) && (other.o.y >= self.o.y) && (other.o.z >= self.o.z)
+                        && (other_max.x <= max.x) && (other_max.y <= max.y) && (other_max.z <= max.z)
+                    )
+                }
+
+                /// Whether the boxes overlap. Touching faces do not overlap.
+                pub fn intersects(&self,other: &Rect3<$t>) -> bool {
+                    self.intersection(other).is_some()
+                }
+
+                /// Overlap of both boxes, or `None` if they do not overlap.
+                pub fn intersection(&self,other: &Rect3<$t>) -> Option<Rect3<$t>> {
+                    let r = Rect3::<$t>::from_min_max(self.o.max(other.o),self.max().min(other.max()));
+                    if r.is_empty() {
+                        None
+                    }
+                    else {
+                        Some(r)
+                    }
+                }
+
+                /// Smallest box around both boxes, where empty boxes are ignored.
+                pub fn union(&self,other: &Rect3<$t>) -> Rect3<$t> {
+                    if other.is_empty() {
+                        *self
+                    }
+                    else if self.is_empty() {
+                        *other
+                    }
+                    else {
+                        Rect3::<$t>::from_min_max(self.o.min(other.o),self.max().max(other.max()))
+                    }
+                }
+
+                /// Grow by `d` on every side.
+                pub fn inflate(&self,d: Vec3<$t>) -> Rect3<$t> {
+                    Rect3 { o: self.o - d,s: self.s + d + d, }
+                }
+
+                /// Shrink by `d` on every side, down to zero size around the center.
+                pub fn deflate(&self,d: Vec3<$t>) -> Rect3<$t> {
+                    let two = <$t>::ONE + <$t>::ONE;
+                    let d = d.min(self.s / two);
+                    Rect3 { o: self.o + d,s: self.s - d - d, }
+                }
+
+                pub fn translate(&self,v: Vec3<$t>) -> Rect3<$t> {
+                    Rect3 { o: self.o + v,s: self.s, }
+                }
+
+                /// Same size, placed inside `parent` as specified by `x`, `y` and `z`, see [`Rect::align`].
+                pub fn align(&self,parent: &Rect3<$t>,x: Align,y: Align,z: Align) -> Rect3<$t> {
+                    let two = <$t>::ONE + <$t>::ONE;
+                    let place = |o: $t,s: $t,ps: $t,align: Align| match align {
+                        Align::Start => o,
+                        Align::Center => o + (ps - s) / two,
+                        Align::End => o + ps - s,
+                    };
+                    Rect3 {
+                        o: Vec3 {
+                            x: place(parent.o.x,self.s.x,parent.s.x,x),
+                            y: place(parent.o.y,self.s.y,parent.s.y,y),
+                            z: place(parent.o.z,self.s.z,parent.s.z,z),
+                        },
+                        s: self.s,
+                    }
+                }
+
+                /// Closest point to `p` from min to max, including the max faces.
+                pub fn clamp(&self,p: Vec3<$t>) -> Vec3<$t> {
+                    p.clamp(self.o,self.max())
+                }
+            }
+        )+
+    }
+}
+
+rect3_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
+
+macro_rules! rect3_real_impl {
+    ($($t:ty)+) => {
+        $(
+            impl From<Aabb<$t>> for Rect3<$t> {
+                fn from(value: Aabb<$t>) -> Self {
+                    Rect3::<$t>::from_min_max(value.min,value.max)
+                }
+            }
+
+            impl From<Rect3<$t>> for Aabb<$t> {
+                fn from(value: Rect3<$t>) -> Self {
+                    Aabb { min: value.min(),max: value.max(), }
+                }
+            }
+        )+
+    }
+}
+
+rect3_real_impl! { f32 f64 }
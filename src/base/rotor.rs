@@ -0,0 +1,375 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Mul,
+            MulAssign,
+            Neg,
+        },
+    },
+};
+
+/// 3D rotor template.
+///
+/// A rotor is the even-grade part of a [`MultiVec3`]: a scalar `r` and the three bivectors `xy`, `xz` and `yz`. A unit
+/// rotor `R` rotates a vector `v` by the sandwich product `R v R~`, and rotors compose by the geometric product, much like
+/// a [`Quaternion`].
+#[derive(Copy,Clone,Debug)]
+pub struct Rotor<T> {
+    pub r: T,
+    pub xy: T,
+    pub xz: T,
+    pub yz: T,
+}
+
+macro_rules! rotor_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Rotor<$t> {
+                /// Create rotor that rotates counterclockwise by `angle` radians around `axis`.
+                pub fn from_axis_angle(axis: Vec3<$t>,angle: $t) -> Self {
+                    let l = axis.dot(axis).sqrt();
+                    let (s,c) = (0.5 * angle).sin_cos();
+                    let s = s / l;
+                    Rotor {
+                        r: c,
+                        xy: -s * axis.z,
+                        xz: s * axis.y,
+                        yz: -s * axis.x,
+                    }
+                }
+
+                /// Create rotor that rotates the direction of `a` onto the direction of `b` along the shortest arc.
+                pub fn from_vectors(a: Vec3<$t>,b: Vec3<$t>) -> Self {
+                    let la = a.dot(a).sqrt();
+                    let lb = b.dot(b).sqrt();
+                    let r = la * lb + b.dot(a);
+                    let rotor = if r <= <$t>::EPSILON * la * lb {
+                        // opposite vectors, rotate half a turn in any plane containing a
+                        if a.x.abs() > a.z.abs() {
+                            Rotor { r: <$t>::ZERO,xy: <$t>::ZERO,xz: a.x,yz: a.y, }
+                        }
+                        else {
+                            Rotor { r: <$t>::ZERO,xy: a.y,xz: a.z,yz: <$t>::ZERO, }
+                        }
+                    }
+                    else {
+                        // 1 + b a, with the bivector part b ^ a
+                        Rotor {
+                            r,
+                            xy: b.x * a.y - b.y * a.x,
+                            xz: b.x * a.z - b.z * a.x,
+                            yz: b.y * a.z - b.z * a.y,
+                        }
+                    };
+                    rotor.normalize()
+                }
+
+                pub fn reverse(self) -> Self {
+                    Rotor {
+                        r: self.r,
+                        xy: -self.xy,
+                        xz: -self.xz,
+                        yz: -self.yz,
+                    }
+                }
+
+                pub fn norm_sqr(self) -> $t {
+                    self.r * self.r + self.xy * self.xy + self.xz * self.xz + self.yz * self.yz
+                }
+
+                pub fn norm(self) -> $t {
+                    self.norm_sqr().sqrt()
+                }
+
+                pub fn normalize(self) -> Self {
+                    let f = self.norm();
+                    Rotor {
+                        r: self.r / f,
+                        xy: self.xy / f,
+                        xz: self.xz / f,
+                        yz: self.yz / f,
+                    }
+                }
+
+                pub fn inv(self) -> Self {
+                    let f = self.norm_sqr();
+                    Rotor {
+                        r: self.r / f,
+                        xy: -self.xy / f,
+                        xz: -self.xz / f,
+                        yz: -self.yz / f,
+                    }
+                }
+
+                /// Rotation angle in radians, between 0 and PI.
+                pub fn angle(self) -> $t {
+                    let b = (self.xy * self.xy + self.xz * self.xz + self.yz * self.yz).sqrt();
+                    2.0 * b.atan2(self.r.abs())
+                }
+
+                /// Normalized linear interpolation along the shortest arc.
+                pub fn nlerp(self,other: Self,t: $t) -> Self {
+                    let other = if self.dot(other) < <$t>::ZERO { -other } else { other };
+                    let s = <$t>::ONE - t;
+                    Rotor {
+                        r: s * self.r + t * other.r,
+                        xy: s * self.xy + t * other.xy,
+                        xz: s * self.xz + t * other.xz,
+                        yz: s * self.yz + t * other.yz,
+                    }.normalize()
+                }
+
+                /// Spherical linear interpolation along the shortest arc, at constant angular velocity.
+                pub fn slerp(self,other: Self,t: $t) -> Self {
+                    let mut d = self.dot(other);
+                    let other = if d < <$t>::ZERO {
+                        d = -d;
+                        -other
+                    }
+                    else {
+                        other
+                    };
+                    if d > <$t>::ONE - <$t>::EPSILON {
+                        return self.nlerp(other,t);
+                    }
+                    let a = d.acos();
+                    let f = <$t>::ONE / a.sin();
+                    let s0 = ((<$t>::ONE - t) * a).sin() * f;
+                    let s1 = (t * a).sin() * f;
+                    Rotor {
+                        r: s0 * self.r + s1 * other.r,
+                        xy: s0 * self.xy + s1 * other.xy,
+                        xz: s0 * self.xz + s1 * other.xz,
+                        yz: s0 * self.yz + s1 * other.yz,
+                    }
+                }
+
+                fn dot(self,other: Self) -> $t {
+                    self.r * other.r + self.xy * other.xy + self.xz * other.xz + self.yz * other.yz
+                }
+            }
+
+            impl Display for Rotor<$t> {
+                fn fmt(&self,f: &mut Formatter) -> Result {
+                    write!(f,"({}, {},{},{})",self.r,self.xy,self.xz,self.yz)
+                }
+            }
+
+            impl PartialEq for Rotor<$t> {
+                fn eq(&self,other: &Self) -> bool {
+                    (self.r == other.r) && (self.xy == other.xy) && (self.xz == other.xz) && (self.yz == other.yz)
+                }
+            }
+
+            impl One for Rotor<$t> {
+                const ONE: Rotor<$t> = Rotor { r: <$t>::ONE,xy: <$t>::ZERO,xz: <$t>::ZERO,yz: <$t>::ZERO, };
+            }
+
+            // rotor * vector
+            impl Mul<Vec3<$t>> for Rotor<$t> {
+                type Output = Vec3<$t>;
+                fn mul(self,other: Vec3<$t>) -> Self::Output {
+                    let rr = self.r * self.r;
+                    let xyxy = self.xy * self.xy;
+                    let xzxz = self.xz * self.xz;
+                    let yzyz = self.yz * self.yz;
+                    let rxy = self.r * self.xy;
+                    let rxz = self.r * self.xz;
+                    let ryz = self.r * self.yz;
+                    let xyxz = self.xy * self.xz;
+                    let xyyz = self.xy * self.yz;
+                    let xzyz = self.xz * self.yz;
+                    Vec3 {
+                        x: (rr - xyxy - xzxz + yzyz) * other.x + (rxy - xzyz) * (other.y + other.y) + (rxz + xyyz) * (other.z + other.z),
+                        y: (rr - xyxy + xzxz - yzyz) * other.y - (rxy + xzyz) * (other.x + other.x) + (ryz - xyxz) * (other.z + other.z),
+                        z: (rr + xyxy - xzxz - yzyz) * other.z + (xyyz - rxz) * (other.x + other.x) - (ryz + xyxz) * (other.y + other.y),
+                    }
+                }
+            }
+
+            // rotor * rotor
+            impl Mul<Rotor<$t>> for Rotor<$t> {
+                type Output = Rotor<$t>;
+                fn mul(self,other: Rotor<$t>) -> Self::Output {
+                    Rotor {
+                        r: self.r * other.r - self.xy * other.xy - self.xz * other.xz - self.yz * other.yz,
+                        xy: self.r * other.xy + self.xy * other.r - self.xz * other.yz + self.yz * other.xz,
+                        xz: self.r * other.xz + self.xy * other.yz + self.xz * other.r - self.yz * other.xy,
+                        yz: self.r * other.yz - self.xy * other.xz + self.xz * other.xy + self.yz * other.r,
+                    }
+                }
+            }
+
+            // rotor *= rotor
+            impl MulAssign<Rotor<$t>> for Rotor<$t> {
+                fn mul_assign(&mut self,other: Rotor<$t>) {
+                    *self = *self * other;
+                }
+            }
+
+            // -rotor
+            impl Neg for Rotor<$t> {
+                type Output = Rotor<$t>;
+                fn neg(self) -> Self::Output {
+                    Rotor {
+                        r: -self.r,
+                        xy: -self.xy,
+                        xz: -self.xz,
+                        yz: -self.yz,
+                    }
+                }
+            }
+
+            impl From<Rotor<$t>> for MultiVec3<$t> {
+                fn from(value: Rotor<$t>) -> Self {
+                    MultiVec3 {
+                        r: value.r,
+                        xy: value.xy,
+                        xz: value.xz,
+                        yz: value.yz,
+                        ..MultiVec3::ZERO
+                    }
+                }
+            }
+
+            impl From<MultiVec3<$t>> for Rotor<$t> {
+                fn from(value: MultiVec3<$t>) -> Self {
+                    Rotor {
+                        r: value.r,
+                        xy: value.xy,
+                        xz: value.xz,
+                        yz: value.yz,
+                    }
+                }
+            }
+
+            impl From<Quaternion<$t>> for Rotor<$t> {
+                fn from(value: Quaternion<$t>) -> Self {
+                    Rotor {
+                        r: value.r,
+                        xy: -value.k,
+                        xz: value.j,
+                        yz: -value.i,
+                    }
+                }
+            }
+
+            impl From<Rotor<$t>> for Quaternion<$t> {
+                fn from(value: Rotor<$t>) -> Self {
+                    Quaternion {
+                        r: value.r,
+                        i: -value.yz,
+                        j: value.xz,
+                        k: -value.xy,
+                    }
+                }
+            }
+
+            impl From<Rotor<$t>> for Mat3x3<$t> {
+                fn from(value: Rotor<$t>) -> Self {
+                    let rr = value.r * value.r;
+                    let xyxy = value.xy * value.xy;
+                    let xzxz = value.xz * value.xz;
+                    let yzyz = value.yz * value.yz;
+                    let rxy = value.r * value.xy;
+                    let rxz = value.r * value.xz;
+                    let ryz = value.r * value.yz;
+                    let xyxz = value.xy * value.xz;
+                    let xyyz = value.xy * value.yz;
+                    let xzyz = value.xz * value.yz;
+                    Mat3x3 {
+                        x: Vec3 {
+                            x: rr - xyxy - xzxz + yzyz,
+                            y: 2.0 * (rxy - xzyz),
+                            z: 2.0 * (rxz + xyyz),
+                        },
+                        y: Vec3 {
+                            x: -2.0 * (rxy + xzyz),
+                            y: rr - xyxy + xzxz - yzyz,
+                            z: 2.0 * (ryz - xyxz),
+                        },
+                        z: Vec3 {
+                            x: 2.0 * (xyyz - rxz),
+                            y: -2.0 * (ryz + xyxz),
+                            z: rr + xyxy - xzxz - yzyz,
+                        },
+                    }
+                }
+            }
+
+            impl From<Mat3x3<$t>> for Rotor<$t> {
+                fn from(value: Mat3x3<$t>) -> Self {
+                    // pick the largest component first to avoid dividing by something close to zero
+                    let m = value;
+                    let trace = m.x.x + m.y.y + m.z.z;
+                    let rotor = if trace > <$t>::ZERO {
+                        let r = (<$t>::ONE + trace).sqrt() * 0.5;
+                        let f = 0.25 / r;
+                        Rotor {
+                            r,
+                            xy: (m.x.y - m.y.x) * f,
+                            xz: (m.x.z - m.z.x) * f,
+                            yz: (m.y.z - m.z.y) * f,
+                        }
+                    }
+                    else if (m.x.x > m.y.y) && (m.x.x > m.z.z) {
+                        let yz = (<$t>::ONE + m.x.x - m.y.y - m.z.z).sqrt() * 0.5;
+                        let f = 0.25 / yz;
+                        Rotor {
+                            r: (m.y.z - m.z.y) * f,
+                            xy: (m.x.z + m.z.x) * f,
+                            xz: -(m.x.y + m.y.x) * f,
+                            yz,
+                        }
+                    }
+                    else if m.y.y > m.z.z {
+                        let xz = (<$t>::ONE - m.x.x + m.y.y - m.z.z).sqrt() * 0.5;
+                        let f = 0.25 / xz;
+                        Rotor {
+                            r: (m.x.z - m.z.x) * f,
+                            xy: -(m.y.z + m.z.y) * f,
+                            xz,
+                            yz: -(m.x.y + m.y.x) * f,
+                        }
+                    }
+                    else {
+                        let xy = (<$t>::ONE - m.x.x - m.y.y + m.z.z).sqrt() * 0.5;
+                        let f = 0.25 / xy;
+                        Rotor {
+                            r: (m.x.y - m.y.x) * f,
+                            xy,
+                            xz: -(m.y.z + m.z.y) * f,
+                            yz: (m.x.z + m.z.x) * f,
+                        }
+                    };
+                    rotor.normalize()
+                }
+            }
+
+            impl From<Rotor<$t>> for Mat4x4<$t> {
+                fn from(value: Rotor<$t>) -> Self {
+                    Mat4x4::<$t>::from_mv(Mat3x3::from(value),Vec3::ZERO)
+                }
+            }
+
+            impl From<Mat4x4<$t>> for Rotor<$t> {
+                fn from(value: Mat4x4<$t>) -> Self {
+                    Rotor::from(Mat3x3 {
+                        x: Vec3 { x: value.x.x,y: value.x.y,z: value.x.z, },
+                        y: Vec3 { x: value.y.x,y: value.y.y,z: value.y.z, },
+                        z: Vec3 { x: value.z.x,y: value.z.y,z: value.z.z, },
+                    })
+                }
+            }
+        )+
+    }
+}
+
+rotor_impl! { f32 f64 }
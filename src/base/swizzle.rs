@@ -0,0 +1,535 @@
+use crate::*;
+
+// one swizzle accessor, returning a vector with as many components as there are arguments after the name
+macro_rules! swizzle_fn {
+    ($t:ty,$n:ident,$a:ident,$b:ident) => {
+        pub fn $n(self) -> Vec2<$t> {
+            Vec2 { x: self.$a,y: self.$b, }
+        }
+    };
+    ($t:ty,$n:ident,$a:ident,$b:ident,$c:ident) => {
+        pub fn $n(self) -> Vec3<$t> {
+            Vec3 { x: self.$a,y: self.$b,z: self.$c, }
+        }
+    };
+    ($t:ty,$n:ident,$a:ident,$b:ident,$c:ident,$d:ident) => {
+        pub fn $n(self) -> Vec4<$t> {
+            Vec4 { x: self.$a,y: self.$b,z: self.$c,w: self.$d, }
+        }
+    };
+}
+
+
+macro_rules! vec2_swizzle_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Vec2<$t> {
+                swizzle_fn!($t,xx,x,x);
+                swizzle_fn!($t,xy,x,y);
+                swizzle_fn!($t,yx,y,x);
+                swizzle_fn!($t,yy,y,y);
+                swizzle_fn!($t,xxx,x,x,x);
+                swizzle_fn!($t,xxy,x,x,y);
+                swizzle_fn!($t,xyx,x,y,x);
+                swizzle_fn!($t,xyy,x,y,y);
+                swizzle_fn!($t,yxx,y,x,x);
+                swizzle_fn!($t,yxy,y,x,y);
+                swizzle_fn!($t,yyx,y,y,x);
+                swizzle_fn!($t,yyy,y,y,y);
+                swizzle_fn!($t,xxxx,x,x,x,x);
+                swizzle_fn!($t,xxxy,x,x,x,y);
+                swizzle_fn!($t,xxyx,x,x,y,x);
+                swizzle_fn!($t,xxyy,x,x,y,y);
+                swizzle_fn!($t,xyxx,x,y,x,x);
+                swizzle_fn!($t,xyxy,x,y,x,y);
+                swizzle_fn!($t,xyyx,x,y,y,x);
+                swizzle_fn!($t,xyyy,x,y,y,y);
+                swizzle_fn!($t,yxxx,y,x,x,x);
+                swizzle_fn!($t,yxxy,y,x,x,y);
+                swizzle_fn!($t,yxyx,y,x,y,x);
+                swizzle_fn!($t,yxyy,y,x,y,y);
+                swizzle_fn!($t,yyxx,y,y,x,x);
+                swizzle_fn!($t,yyxy,y,y,x,y);
+                swizzle_fn!($t,yyyx,y,y,y,x);
+                swizzle_fn!($t,yyyy,y,y,y,y);
+            }
+        )+
+    }
+}
+
+vec2_swizzle_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
+
+macro_rules! vec3_swizzle_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Vec3<$t> {
+                swizzle_fn!($t,xx,x,x);
+                swizzle_fn!($t,xy,x,y);
+                swizzle_fn!($t,xz,x,z);
+                swizzle_fn!($t,yx,y,x);
+                swizzle_fn!($t,yy,y,y);
+                swizzle_fn!($t,yz,y,z);
+                swizzle_fn!($t,zx,z,x);
+                swizzle_fn!($t,zy,z,y);
+                swizzle_fn!($t,zz,z,z);
+                swizzle_fn!($t,xxx,x,x,x);
+                swizzle_fn!($t,xxy,x,x,y);
+                swizzle_fn!($t,xxz,x,x,z);
+                swizzle_fn!($t,xyx,x,y,x);
+                swizzle_fn!($t,xyy,x,y,y);
+                swizzle_fn!($t,xyz,x,y,z);
+                swizzle_fn!($t,xzx,x,z,x);
+                swizzle_fn!($t,xzy,x,z,y);
+                swizzle_fn!($t,xzz,x,z,z);
+                swizzle_fn!($t,yxx,y,x,x);
+                swizzle_fn!($t,yxy,y,x,y);
+                swizzle_fn!($t,yxz,y,x,z);
+                swizzle_fn!($t,yyx,y,y,x);
+                swizzle_fn!($t,yyy,y,y,y);
+                swizzle_fn!($t,yyz,y,y,z);
+                swizzle_fn!($t,yzx,y,z,x);
+                swizzle_fn!($t,yzy,y,z,y);
+                swizzle_fn!($t,yzz,y,z,z);
+                swizzle_fn!($t,zxx,z,x,x);
+                swizzle_fn!($t,zxy,z,x,y);
+                swizzle_fn!($t,zxz,z,x,z);
+                swizzle_fn!($t,zyx,z,y,x);
+                swizzle_fn!($t,zyy,z,y,y);
+                swizzle_fn!($t,zyz,z,y,z);
+                swizzle_fn!($t,zzx,z,z,x);
+                swizzle_fn!($t,zzy,z,z,y);
+                swizzle_fn!($t,zzz,z,z,z);
+                swizzle_fn!($t,xxxx,x,x,x,x);
+                swizzle_fn!($t,xxxy,x,x,x,y);
+                swizzle_fn!($t,xxxz,x,x,x,z);
+                swizzle_fn!($t,xxyx,x,x,y,x);
+                swizzle_fn!($t,xxyy,x,x,y,y);
+                swizzle_fn!($t,xxyz,x,x,y,z);
+                swizzle_fn!($t,xxzx,x,x,z,x);
+                swizzle_fn!($t,xxzy,x,x,z,y);
+                swizzle_fn!($t,xxzz,x,x,z,z);
+                swizzle_fn!($t,xyxx,x,y,x,x);
+                swizzle_fn!($t,xyxy,x,y,x,y);
+                swizzle_fn!($t,xyxz,x,y,x,z);
+                swizzle_fn!($t,xyyx,x,y,y,x);
+                swizzle_fn!($t,xyyy,x,y,y,y);
+                swizzle_fn!($t,xyyz,x,y,y,z);
+                swizzle_fn!($t,xyzx,x,y,z,x);
+                swizzle_fn!($t,xyzy,x,y,z,y);
+                swizzle_fn!($t,xyzz,x,y,z,z);
+                swizzle_fn!($t,xzxx,x,z,x,x);
+                swizzle_fn!($t,xzxy,x,z,x,y);
+                swizzle_fn!($t,xzxz,x,z,x,z);
+                swizzle_fn!($t,xzyx,x,z,y,x);
+                swizzle_fn!($t,xzyy,x,z,y,y);
+                swizzle_fn!($t,xzyz,x,z,y,z);
+                swizzle_fn!($t,xzzx,x,z,z,x);
+                swizzle_fn!($t,xzzy,x,z,z,y);
+                swizzle_fn!($t,xzzz,x,z,z,z);
+                swizzle_fn!($t,yxxx,y,x,x,x);
+                swizzle_fn!($t,yxxy,y,x,x,y);
+                swizzle_fn!($t,yxxz,y,x,x,z);
+                swizzle_fn!($t,yxyx,y,x,y,x);
+                swizzle_fn!($t,yxyy,y,x,y,y);
+                swizzle_fn!($t,yxyz,y,x,y,z);
+                swizzle_fn!($t,yxzx,y,x,z,x);
+                swizzle_fn!($t,yxzy,y,x,z,y);
+                swizzle_fn!($t,yxzz,y,x,z,z);
+                swizzle_fn!($t,yyxx,y,y,x,x);
+                swizzle_fn!($t,yyxy,y,y,x,y);
+                swizzle_fn!($t,yyxz,y,y,x,z);
+                swizzle_fn!($t,yyyx,y,y,y,x);
+                swizzle_fn!($t,yyyy,y,y,y,y);
+                swizzle_fn!($t,yyyz,y,y,y,z);
+                swizzle_fn!($t,yyzx,y,y,z,x);
+                swizzle_fn!($t,yyzy,y,y,z,y);
+                swizzle_fn!($t,yyzz,y,y,z,z);
+                swizzle_fn!($t,yzxx,y,z,x,x);
+                swizzle_fn!($t,yzxy,y,z,x,y);
+                swizzle_fn!($t,yzxz,y,z,x,z);
+                swizzle_fn!($t,yzyx,y,z,y,x);
+                swizzle_fn!($t,yzyy,y,z,y,y);
+                swizzle_fn!($t,yzyz,y,z,y,z);
+                swizzle_fn!($t,yzzx,y,z,z,x);
+                swizzle_fn!($t,yzzy,y,z,z,y);
+                swizzle_fn!($t,yzzz,y,z,z,z);
+                swizzle_fn!($t,zxxx,z,x,x,x);
+                swizzle_fn!($t,zxxy,z,x,x,y);
+                swizzle_fn!($t,zxxz,z,x,x,z);
+                swizzle_fn!($t,zxyx,z,x,y,x);
+                swizzle_fn!($t,zxyy,z,x,y,y);
+                swizzle_fn!($t,zxyz,z,x,y,z);
+                swizzle_fn!($t,zxzx,z,x,z,x);
+                swizzle_fn!($t,zxzy,z,x,z,y);
+                swizzle_fn!($t,zxzz,z,x,z,z);
+                swizzle_fn!($t,zyxx,z,y,x,x);
+                swizzle_fn!($t,zyxy,z,y,x,y);
+                swizzle_fn!($t,zyxz,z,y,x,z);
+                swizzle_fn!($t,zyyx,z,y,y,x);
+                swizzle_fn!($t,zyyy,z,y,y,y);
+                swizzle_fn!($t,zyyz,z,y,y,z);
+                swizzle_fn!($t,zyzx,z,y,z,x);
+                swizzle_fn!($t,zyzy,z,y,z,y);
+                swizzle_fn!($t,zyzz,z,y,z,z);
+                swizzle_fn!($t,zzxx,z,z,x,x);
+                swizzle_fn!($t,zzxy,z,z,x,y);
+                swizzle_fn!($t,zzxz,z,z,x,z);
+                swizzle_fn!($t,zzyx,z,z,y,x);
+                swizzle_fn!($t,zzyy,z,z,y,y);
+                swizzle_fn!($t,zzyz,z,z,y,z);
+                swizzle_fn!($t,zzzx,z,z,z,x);
+                swizzle_fn!($t,zzzy,z,z,z,y);
+                swizzle_fn!($t,zzzz,z,z,z,z);
+            }
+        )+
+    }
+}
+
+vec3_swizzle_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
+
+macro_rules! vec4_swizzle_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Vec4<$t> {
+                swizzle_fn!($t,xx,x,x);
+                swizzle_fn!($t,xy,x,y);
+                swizzle_fn!($t,xz,x,z);
+                swizzle_fn!($t,xw,x,w);
+                swizzle_fn!($t,yx,y,x);
+                swizzle_fn!($t,yy,y,y);
+                swizzle_fn!($t,yz,y,z);
+                swizzle_fn!($t,yw,y,w);
+                swizzle_fn!($t,zx,z,x);
+                swizzle_fn!($t,zy,z,y);
+                swizzle_fn!($t,zz,z,z);
+                swizzle_fn!($t,zw,z,w);
+                swizzle_fn!($t,wx,w,x);
+                swizzle_fn!($t,wy,w,y);
+                swizzle_fn!($t,wz,w,z);
+                swizzle_fn!($t,ww,w,w);
+                swizzle_fn!($t,xxx,x,x,x);
+                swizzle_fn!($t,xxy,x,x,y);
+                swizzle_fn!($t,xxz,x,x,z);
+                swizzle_fn!($t,xxw,x,x,w);
+                swizzle_fn!($t,xyx,x,y,x);
+                swizzle_fn!($t,xyy,x,y,y);
+                swizzle_fn!($t,xyz,x,y,z);
+                swizzle_fn!($t,xyw,x,y,w);
+                swizzle_fn!($t,xzx,x,z,x);
+                swizzle_fn!($t,xzy,x,z,y);
+                swizzle_fn!($t,xzz,x,z,z);
+                swizzle_fn!($t,xzw,x,z,w);
+                swizzle_fn!($t,xwx,x,w,x);
+                swizzle_fn!($t,xwy,x,w,y);
+                swizzle_fn!($t,xwz,x,w,z);
+                swizzle_fn!($t,xww,x,w,w);
+                swizzle_fn!($t,yxx,y,x,x);
+                swizzle_fn!($t,yxy,y,x,y);
+                swizzle_fn!($t,yxz,y,x,z);
+                swizzle_fn!($t,yxw,y,x,w);
+                swizzle_fn!($t,yyx,y,y,x);
+                swizzle_fn!($t,yyy,y,y,y);
+                swizzle_fn!($t,yyz,y,y,z);
+                swizzle_fn!($t,yyw,y,y,w);
+                swizzle_fn!($t,yzx,y,z,x);
+                swizzle_fn!($t,yzy,y,z,y);
+                swizzle_fn!($t,yzz,y,z,z);
+                swizzle_fn!($t,yzw,y,z,w);
+                swizzle_fn!($t,ywx,y,w,x);
+                swizzle_fn!($t,ywy,y,w,y);
+                swizzle_fn!($t,ywz,y,w,z);
+                swizzle_fn!($t,yww,y,w,w);
+                swizzle_fn!($t,zxx,z,x,x);
+                swizzle_fn!($t,zxy,z,x,y);
+                swizzle_fn!($t,zxz,z,x,z);
+                swizzle_fn!($t,zxw,z,x,w);
+                swizzle_fn!($t,zyx,z,y,x);
+                swizzle_fn!($t,zyy,z,y,y);
+                swizzle_fn!($t,zyz,z,y,z);
+                swizzle_fn!($t,zyw,z,y,w);
+                swizzle_fn!($t,zzx,z,z,x);
+                swizzle_fn!($t,zzy,z,z,y);
+                swizzle_fn!($t,zzz,z,z,z);
+                swizzle_fn!($t,zzw,z,z,w);
+                swizzle_fn!($t,zwx,z,w,x);
+                swizzle_fn!($t,zwy,z,w,y);
+                swizzle_fn!($t,zwz,z,w,z);
+                swizzle_fn!($t,zww,z,w,w);
+                swizzle_fn!($t,wxx,w,x,x);
+                swizzle_fn!($t,wxy,w,x,y);
+                swizzle_fn!($t,wxz,w,x,z);
+                swizzle_fn!($t,wxw,w,x,w);
+                swizzle_fn!($t,wyx,w,y,x);
+                swizzle_fn!($t,wyy,w,y,y);
+                swizzle_fn!($t,wyz,w,y,z);
+                swizzle_fn!($t,wyw,w,y,w);
+                swizzle_fn!($t,wzx,w,z,x);
+                swizzle_fn!($t,wzy,w,z,y);
+                swizzle_fn!($t,wzz,w,z,z);
+                swizzle_fn!($t,wzw,w,z,w);
+                swizzle_fn!($t,wwx,w,w,x);
+                swizzle_fn!($t,wwy,w,w,y);
+                swizzle_fn!($t,wwz,w,w,z);
+                swizzle_fn!($t,www,w,w,w);
+                swizzle_fn!($t,xxxx,x,x,x,x);
+                swizzle_fn!($t,xxxy,x,x,x,y);
+                swizzle_fn!($t,xxxz,x,x,x,z);
+                swizzle_fn!($t,xxxw,x,x,x,w);
+                swizzle_fn!($t,xxyx,x,x,y,x);
+                swizzle_fn!($t,xxyy,x,x,y,y);
+                swizzle_fn!($t,xxyz,x,x,y,z);
+                swizzle_fn!($t,xxyw,x,x,y,w);
+                swizzle_fn!($t,xxzx,x,x,z,x);
+                swizzle_fn!($t,xxzy,x,x,z,y);
+                swizzle_fn!($t,xxzz,x,x,z,z);
+                swizzle_fn!($t,xxzw,x,x,z,w);
+                swizzle_fn!($t,xxwx,x,x,w,x);
+                swizzle_fn!($t,xxwy,x,x,w,y);
+                swizzle_fn!($t,xxwz,x,x,w,z);
+                swizzle_fn!($t,xxww,x,x,w,w);
+                swizzle_fn!($t,xyxx,x,y,x,x);
+                swizzle_fn!($t,xyxy,x,y,x,y);
+                swizzle_fn!($t,xyxz,x,y,x,z);
+                swizzle_fn!($t,xyxw,x,y,x,w);
+                swizzle_fn!($t,xyyx,x,y,y,x);
+                swizzle_fn!($t,xyyy,x,y,y,y);
+                swizzle_fn!($t,xyyz,x,y,y,z);
+                swizzle_fn!($t,xyyw,x,y,y,w);
+                swizzle_fn!($t,xyzx,x,y,z,x);
+                swizzle_fn!($t,xyzy,x,y,z,y);
+                swizzle_fn!($t,xyzz,x,y,z,z);
+                swizzle_fn!($t,xyzw,x,y,z,w);
+                swizzle_fn!($t,xywx,x,y,w,x);
+                swizzle_fn!($t,xywy,x,y,w,y);
+                swizzle_fn!($t,xywz,x,y,w,z);
+                swizzle_fn!($t,xyww,x,y,w,w);
+                swizzle_fn!($t,xzxx,x,z,x,x);
+                swizzle_fn!($t,xzxy,x,z,x,y);
+                swizzle_fn!($t,xzxz,x,z,x,z);
+                swizzle_fn!($t,xzxw,x,z,x,w);
+                swizzle_fn!($t,xzyx,x,z,y,x);
+                swizzle_fn!($t,xzyy,x,z,y,y);
+                swizzle_fn!($t,xzyz,x,z,y,z);
+                swizzle_fn!($t,xzyw,x,z,y,w);
+                swizzle_fn!($t,xzzx,x,z,z,x);
+                swizzle_fn!($t,xzzy,x,z,z,y);
+                swizzle_fn!($t,xzzz,x,z,z,z);
+                swizzle_fn!($t,xzzw,x,z,z,w);
+                swizzle_fn!($t,xzwx,x,z,w,x);
+                swizzle_fn!($t,xzwy,x,z,w,y);
+                swizzle_fn!($t,xzwz,x,z,w,z);
+                swizzle_fn!($t,xzww,x,z,w,w);
+                swizzle_fn!($t,xwxx,x,w,x,x);
+                swizzle_fn!($t,xwxy,x,w,x,y);
+                swizzle_fn!($t,xwxz,x,w,x,z);
+                swizzle_fn!($t,xwxw,x,w,x,w);
+                swizzle_fn!($t,xwyx,x,w,y,x);
+                swizzle_fn!($t,xwyy,x,w,y,y);
+                swizzle_fn!($t,xwyz,x,w,y,z);
+                swizzle_fn!($t,xwyw,x,w,y,w);
+                swizzle_fn!($t,xwzx,x,w,z,x);
+                swizzle_fn!($t,xwzy,x,w,z,y);
+                swizzle_fn!($t,xwzz,x,w,z,z);
+                swizzle_fn!($t,xwzw,x,w,z,w);
+                swizzle_fn!($t,xwwx,x,w,w,x);
+                swizzle_fn!($t,xwwy,x,w,w,y);
+                swizzle_fn!($t,xwwz,x,w,w,z);
+                swizzle_fn!($t,xwww,x,w,w,w);
+                swizzle_fn!($t,yxxx,y,x,x,x);
+                swizzle_fn!($t,yxxy,y,x,x,y);
+                swizzle_fn!($t,yxxz,y,x,x,z);
+                swizzle_fn!($t,yxxw,y,x,x,w);
+                swizzle_fn!($t,yxyx,y,x,y,x);
+                swizzle_fn!($t,yxyy,y,x,y,y);
+                swizzle_fn!($t,yxyz,y,x,y,z);
+                swizzle_fn!($t,yxyw,y,x,y,w);
+                swizzle_fn!($t,yxzx,y,x,z,x);
+                swizzle_fn!($t,yxzy,y,x,z,y);
+                swizzle_fn!($t,yxzz,y,x,z,z);
+                swizzle_fn!($t,yxzw,y,x,z,w);
+                swizzle_fn!($t,yxwx,y,x,w,x);
+                swizzle_fn!($t,yxwy,y,x,w,y);
+                swizzle_fn!($t,yxwz,y,x,w,z);
+                swizzle_fn!($t,yxww,y,x,w,w);
+                swizzle_fn!($t,yyxx,y,y,x,x);
+                swizzle_fn!($t,yyxy,y,y,x,y);
+                swizzle_fn!($t,yyxz,y,y,x,z);
+                swizzle_fn!($t,yyxw,y,y,x,w);
+                swizzle_fn!($t,yyyx,y,y,y,x);
+                swizzle_fn!($t,yyyy,y,y,y,y);
+                swizzle_fn!($t,yyyz,y,y,y,z);
+                swizzle_fn!($t,yyyw,y,y,y,w);
+                swizzle_fn!($t,yyzx,y,y,z,x);
+                swizzle_fn!($t,yyzy,y,y,z,y);
+                swizzle_fn!($t,yyzz,y,y,z,z);
+                swizzle_fn!($t,yyzw,y,y,z,w);
+                swizzle_fn!($t,yywx,y,y,w,x);
+                swizzle_fn!($t,yywy,y,y,w,y);
+                swizzle_fn!($t,yywz,y,y,w,z);
+                swizzle_fn!($t,yyww,y,y,w,w);
+                swizzle_fn!($t,yzxx,y,z,x,x);
+                swizzle_fn!($t,yzxy,y,z,x,y);
+                swizzle_fn!($t,yzxz,y,z,x,z);
+                swizzle_fn!($t,yzxw,y,z,x,w);
+                swizzle_fn!($t,yzyx,y,z,y,x);
+                swizzle_fn!($t,yzyy,y,z,y,y);
+                swizzle_fn!($t,yzyz,y,z,y,z);
+                swizzle_fn!($t,yzyw,y,z,y,w);
+                swizzle_fn!($t,yzzx,y,z,z,x);
+                swizzle_fn!($t,yzzy,y,z,z,y);
+                swizzle_fn!($t,yzzz,y,z,z,z);
+                swizzle_fn!($t,yzzw,y,z,z,w);
+                swizzle_fn!($t,yzwx,y,z,w,x);
+                swizzle_fn!($t,yzwy,y,z,w,y);
+                swizzle_fn!($t,yzwz,y,z,w,z);
+                swizzle_fn!($t,yzww,y,z,w,w);
+                swizzle_fn!($t,ywxx,y,w,x,x);
+                swizzle_fn!($t,ywxy,y,w,x,y);
+                swizzle_fn!($t,ywxz,y,w,x,z);
+                swizzle_fn!($t,ywxw,y,w,x,w);
+                swizzle_fn!($t,ywyx,y,w,y,x);
+                swizzle_fn!($t,ywyy,y,w,y,y);
+                swizzle_fn!($t,ywyz,y,w,y,z);
+                swizzle_fn!($t,ywyw,y,w,y,w);
+                swizzle_fn!($t,ywzx,y,w,z,x);
+                swizzle_fn!($t,ywzy,y,w,z,y);
+                swizzle_fn!($t,ywzz,y,w,z,z);
+                swizzle_fn!($t,ywzw,y,w,z,w);
+                swizzle_fn!($t,ywwx,y,w,w,x);
+                swizzle_fn!($t,ywwy,y,w,w,y);
+                swizzle_fn!($t,ywwz,y,w,w,z);
+                swizzle_fn!($t,ywww,y,w,w,w);
+                swizzle_fn!($t,zxxx,z,x,x,x);
+                swizzle_fn!($t,zxxy,z,x,x,y);
+                swizzle_fn!($t,zxxz,z,x,x,z);
+                swizzle_fn!($t,zxxw,z,x,x,w);
+                swizzle_fn!($t,zxyx,z,x,y,x);
+                swizzle_fn!($t,zxyy,z,x,y,y);
+                swizzle_fn!($t,zxyz,z,x,y,z);
+                swizzle_fn!($t,zxyw,z,x,y,w);
+                swizzle_fn!($t,zxzx,z,x,z,x);
+                swizzle_fn!($t,zxzy,z,x,z,y);
+                swizzle_fn!($t,zxzz,z,x,z,z);
+                swizzle_fn!($t,zxzw,z,x,z,w);
+                swizzle_fn!($t,zxwx,z,x,w,x);
+                swizzle_fn!($t,zxwy,z,x,w,y);
+                swizzle_fn!($t,zxwz,z,x,w,z);
+                swizzle_fn!($t,zxww,z,x,w,w);
+                swizzle_fn!($t,zyxx,z,y,x,x);
+                swizzle_fn!($t,zyxy,z,y,x,y);
+                swizzle_fn!($t,zyxz,z,y,x,z);
+                swizzle_fn!($t,zyxw,z,y,x,w);
+                swizzle_fn!($t,zyyx,z,y,y,x);
+                swizzle_fn!($t,zyyy,z,y,y,y);
+                swizzle_fn!($t,zyyz,z,y,y,z);
+                swizzle_fn!($t,zyyw,z,y,y,w);
+                swizzle_fn!($t,zyzx,z,y,z,x);
+                swizzle_fn!($t,zyzy,z,y,z,y);
+                swizzle_fn!($t,zyzz,z,y,z,z);
+                swizzle_fn!($t,zyzw,z,y,z,w);
+                swizzle_fn!($t,zywx,z,y,w,x);
+                swizzle_fn!($t,zywy,z,y,w,y);
+                swizzle_fn!($t,zywz,z,y,w,z);
+                swizzle_fn!($t,zyww,z,y,w,w);
+                swizzle_fn!($t,zzxx,z,z,x,x);
+                swizzle_fn!($t,zzxy,z,z,x,y);
+                swizzle_fn!($t,zzxz,z,z,x,z);
+                swizzle_fn!($t,zzxw,z,z,x,w);
+                swizzle_fn!($t,zzyx,z,z,y,x);
+                swizzle_fn!($t,zzyy,z,z,y,y);
+                swizzle_fn!($t,zzyz,z,z,y,z);
+                swizzle_fn!($t,zzyw,z,z,y,w);
+                swizzle_fn!($t,zzzx,z,z,z,x);
+                swizzle_fn!($t,zzzy,z,z,z,y);
+                swizzle_fn!($t,zzzz,z,z,z,z);
+                swizzle_fn!($t,zzzw,z,z,z,w);
+                swizzle_fn!($t,zzwx,z,z,w,x);
+                swizzle_fn!($t,zzwy,z,z,w,y);
+                swizzle_fn!($t,zzwz,z,z,w,z);
+                swizzle_fn!($t,zzww,z,z,w,w);
+                swizzle_fn!($t,zwxx,z,w,x,x);
+                swizzle_fn!($t,zwxy,z,w,x,y);
+                swizzle_fn!($t,zwxz,z,w,x,z);
+                swizzle_fn!($t,zwxw,z,w,x,w);
+                swizzle_fn!($t,zwyx,z,w,y,x);
+                swizzle_fn!($t,zwyy,z,w,y,y);
+                swizzle_fn!($t,zwyz,z,w,y,z);
+                swizzle_fn!($t,zwyw,z,w,y,w);
+                swizzle_fn!($t,zwzx,z,w,z,x);
+                swizzle_fn!($t,zwzy,z,w,z,y);
+                swizzle_fn!($t,zwzz,z,w,z,z);
+                swizzle_fn!($t,zwzw,z,w,z,w);
+                swizzle_fn!($t,zwwx,z,w,w,x);
+                swizzle_fn!($t,zwwy,z,w,w,y);
+                swizzle_fn!($t,zwwz,z,w,w,z);
+                swizzle_fn!($t,zwww,z,w,w,w);
+                swizzle_fn!($t,wxxx,w,x,x,x);
+                swizzle_fn!($t,wxxy,w,x,x,y);
+                swizzle_fn!($t,wxxz,w,x,x,z);
+                swizzle_fn!($t,wxxw,w,x,x,w);
+                swizzle_fn!($t,wxyx,w,x,y,x);
+                swizzle_fn!($t,wxyy,w,x,y,y);
+                swizzle_fn!($t,wxyz,w,x,y,z);
+                swizzle_fn!($t,wxyw,w,x,y,w);
+                swizzle_fn!($t,wxzx,w,x,z,x);
+                swizzle_fn!($t,wxzy,w,x,z,y);
+                swizzle_fn!($t,wxzz,w,x,z,z);
+                swizzle_fn!($t,wxzw,w,x,z,w);
+                swizzle_fn!($t,wxwx,w,x,w,x);
+                swizzle_fn!($t,wxwy,w,x,w,y);
+                swizzle_fn!($t,wxwz,w,x,w,z);
+                swizzle_fn!($t,wxww,w,x,w,w);
+                swizzle_fn!($t,wyxx,w,y,x,x);
+                swizzle_fn!($t,wyxy,w,y,x,y);
+                swizzle_fn!($t,wyxz,w,y,x,z);
+                swizzle_fn!($t,wyxw,w,y,x,w);
+                swizzle_fn!($t,wyyx,w,y,y,x);
+                swizzle_fn!($t,wyyy,w,y,y,y);
+                swizzle_fn!($t,wyyz,w,y,y,z);
+                swizzle_fn!($t,wyyw,w,y,y,w);
+                swizzle_fn!($t,wyzx,w,y,z,x);
+                swizzle_fn!($t,wyzy,w,y,z,y);
+                swizzle_fn!($t,wyzz,w,y,z,z);
+                swizzle_fn!($t,wyzw,w,y,z,w);
+                swizzle_fn!($t,wywx,w,y,w,x);
+                swizzle_fn!($t,wywy,w,y,w,y);
+                swizzle_fn!($t,wywz,w,y,w,z);
+                swizzle_fn!($t,wyww,w,y,w,w);
+                swizzle_fn!($t,wzxx,w,z,x,x);
+                swizzle_fn!($t,wzxy,w,z,x,y);
+                swizzle_fn!($t,wzxz,w,z,x,z);
+                swizzle_fn!($t,wzxw,w,z,x,w);
+                swizzle_fn!($t,wzyx,w,z,y,x);
+                swizzle_fn!($t,wzyy,w,z,y,y);
+                swizzle_fn!($t,wzyz,w,z,y,z);
+                swizzle_fn!($t,wzyw,w,z,y,w);
+                swizzle_fn!($t,wzzx,w,z,z,x);
+                swizzle_fn!($t,wzzy,w,z,z,y);
+                swizzle_fn!($t,wzzz,w,z,z,z);
+                swizzle_fn!($t,wzzw,w,z,z,w);
+                swizzle_fn!($t,wzwx,w,z,w,x);
+                swizzle_fn!($t,wzwy,w,z,w,y);
+                swizzle_fn!($t,wzwz,w,z,w,z);
+                swizzle_fn!($t,wzww,w,z,w,w);
+                swizzle_fn!($t,wwxx,w,w,x,x);
+                swizzle_fn!($t,wwxy,w,w,x,y);
+                swizzle_fn!($t,wwxz,w,w,x,z);
+                swizzle_fn!($t,wwxw,w,w,x,w);
+                swizzle_fn!($t,wwyx,w,w,y,x);
+                swizzle_fn!($t,wwyy,w,w,y,y);
+                swizzle_fn!($t,wwyz,w,w,y,z);
+                swizzle_fn!($t,wwyw,w,w,y,w);
+                swizzle_fn!($t,wwzx,w,w,z,x);
+                swizzle_fn!($t,wwzy,w,w,z,y);
+                swizzle_fn!($t,wwzz,w,w,z,z);
+                swizzle_fn!($t,wwzw,w,w,z,w);
+                swizzle_fn!($t,wwwx,w,w,w,x);
+                swizzle_fn!($t,wwwy,w,w,w,y);
+                swizzle_fn!($t,wwwz,w,w,w,z);
+                swizzle_fn!($t,wwww,w,w,w,w);
+            }
+        )+
+    }
+}
+
+vec4_swizzle_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
@@ -1,6 +1,11 @@
 use crate::*;
 
 /// unsigned number trait
+///
+/// For floats, overflow means that finite operands give an infinite or NaN result, and wrapping is the plain IEEE
+/// result. Fixed size rationals have no modular representation, so their wrapping variants return the saturating
+/// result and never panic. Big integers and big rationals cannot overflow, so their wrapping variants return the
+/// plain result.
 pub trait Unsigned: Sized + Zero + One {
     const MIN: Self;
     const MAX: Self;
@@ -11,6 +16,42 @@ pub trait Unsigned: Sized + Zero + One {
     fn clamp(self,min: Self,max: Self) -> Self;
     fn mul_add(self,b: Self,c: Self) -> Self;
     fn powi(self,n: i32) -> Self;
+
+    /// Add, or `None` on overflow.
+    fn checked_add(self,other: Self) -> Option<Self>;
+
+    /// Subtract, or `None` on overflow.
+    fn checked_sub(self,other: Self) -> Option<Self>;
+
+    /// Multiply, or `None` on overflow.
+    fn checked_mul(self,other: Self) -> Option<Self>;
+
+    /// Divide, or `None` on overflow or division by zero.
+    fn checked_div(self,other: Self) -> Option<Self>;
+
+    /// Add, clamping to `MIN` or `MAX` on overflow.
+    fn saturating_add(self,other: Self) -> Self;
+
+    /// Subtract, clamping to `MIN` or `MAX` on overflow.
+    fn saturating_sub(self,other: Self) -> Self;
+
+    /// Multiply, clamping to `MIN` or `MAX` on overflow.
+    fn saturating_mul(self,other: Self) -> Self;
+
+    /// Divide, clamping to `MIN` or `MAX` on overflow.
+    fn saturating_div(self,other: Self) -> Self;
+
+    /// Add, wrapping around on overflow.
+    fn wrapping_add(self,other: Self) -> Self;
+
+    /// Subtract, wrapping around on overflow.
+    fn wrapping_sub(self,other: Self) -> Self;
+
+    /// Multiply, wrapping around on overflow.
+    fn wrapping_mul(self,other: Self) -> Self;
+
+    /// Divide, wrapping around on overflow.
+    fn wrapping_div(self,other: Self) -> Self;
 }
 
 macro_rules! unsigned_impl {
@@ -62,13 +103,194 @@ macro_rules! unsigned_impl {
                     self * b + c
                 }
             
-                fn powi(self,_n: i32) -> Self {
-                    // TODO
-                    Self::ZERO
+                /// Exponentiation by squaring, negative powers truncate like integer division.
+                fn powi(self,n: i32) -> Self {
+                    if n >= 0 {
+                        self.pow(n as u32)
+                    }
+                    else {
+                        (Self::ONE / self).pow(n.unsigned_abs())
+                    }
+                }
+
+                fn checked_add(self,other: Self) -> Option<Self> {
+                    <$t>::checked_add(self,other)
+                }
+
+                fn checked_sub(self,other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self,other)
+                }
+
+                fn checked_mul(self,other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self,other)
+                }
+
+                fn checked_div(self,other: Self) -> Option<Self> {
+                    <$t>::checked_div(self,other)
+                }
+
+                fn saturating_add(self,other: Self) -> Self {
+                    <$t>::saturating_add(self,other)
+                }
+
+                fn saturating_sub(self,other: Self) -> Self {
+                    <$t>::saturating_sub(self,other)
+                }
+
+                fn saturating_mul(self,other: Self) -> Self {
+                    <$t>::saturating_mul(self,other)
+                }
+
+                fn saturating_div(self,other: Self) -> Self {
+                    <$t>::saturating_div(self,other)
+                }
+
+                fn wrapping_add(self,other: Self) -> Self {
+                    <$t>::wrapping_add(self,other)
+                }
+
+                fn wrapping_sub(self,other: Self) -> Self {
+                    <$t>::wrapping_sub(self,other)
+                }
+
+                fn wrapping_mul(self,other: Self) -> Self {
+                    <$t>::wrapping_mul(self,other)
+                }
+
+                fn wrapping_div(self,other: Self) -> Self {
+                    <$t>::wrapping_div(self,other)
+                }
+            }
+        )+
+    }
+}
+
+unsigned_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+// float result, or `None` when finite operands give an infinite or NaN result
+macro_rules! unsigned_float_checked {
+    ($a:expr,$b:expr,$result:expr) => {{
+        let result = $result;
+        if result.is_finite() || !($a.is_finite() && $b.is_finite()) { Some(result) } else { None }
+    }}
+}
+
+// float result, clamped to MIN or MAX when finite operands give an infinite result
+macro_rules! unsigned_float_saturating {
+    ($a:expr,$b:expr,$result:expr) => {{
+        let result = $result;
+        if result.is_infinite() && $a.is_finite() && $b.is_finite() {
+            if result > Self::ZERO { <Self as Unsigned>::MAX } else { <Self as Unsigned>::MIN }
+        }
+        else {
+            result
+        }
+    }}
+}
+
+macro_rules! unsigned_float_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Unsigned for $t {
+                const MIN: Self = Self::MIN;
+                const MAX: Self = Self::MAX;
+
+                fn div_euclid(self,other: Self) -> Self {
+                    self.div_euclid(other)
+                }
+            
+                fn rem_euclid(self,other: Self) -> Self {
+                    self.rem_euclid(other)
+                }
+            
+                fn min(self,other: Self) -> Self {
+                    if other < self {
+                        other
+                    }
+                    else {
+                        self
+                    }
+                }
+            
+                fn max(self,other: Self) -> Self {
+                    if other > self {
+                        other
+                    }
+                    else {
+                        self
+                    }
+                }
+            
+                fn clamp(self,min: Self,max: Self) -> Self {
+                    if max < self {
+                        max
+                    }
+                    else if min > self {
+                        min
+                    }
+                    else {
+                        self
+                    }
+                }
+            
+                fn mul_add(self,b: Self,c: Self) -> Self {
+                    self * b + c
+                }
+            
+                fn powi(self,n: i32) -> Self {
+                    self.powi(n)
+                }
+
+                fn checked_add(self,other: Self) -> Option<Self> {
+                    unsigned_float_checked!(self,other,self + other)
+                }
+
+                fn checked_sub(self,other: Self) -> Option<Self> {
+                    unsigned_float_checked!(self,other,self - other)
+                }
+
+                fn checked_mul(self,other: Self) -> Option<Self> {
+                    unsigned_float_checked!(self,other,self * other)
+                }
+
+                fn checked_div(self,other: Self) -> Option<Self> {
+                    unsigned_float_checked!(self,other,self / other)
+                }
+
+                fn saturating_add(self,other: Self) -> Self {
+                    unsigned_float_saturating!(self,other,self + other)
+                }
+
+                fn saturating_sub(self,other: Self) -> Self {
+                    unsigned_float_saturating!(self,other,self - other)
+                }
+
+                fn saturating_mul(self,other: Self) -> Self {
+                    unsigned_float_saturating!(self,other,self * other)
+                }
+
+                fn saturating_div(self,other: Self) -> Self {
+                    unsigned_float_saturating!(self,other,self / other)
+                }
+
+                fn wrapping_add(self,other: Self) -> Self {
+                    self + other
+                }
+
+                fn wrapping_sub(self,other: Self) -> Self {
+                    self - other
+                }
+
+                fn wrapping_mul(self,other: Self) -> Self {
+                    self * other
+                }
+
+                fn wrapping_div(self,other: Self) -> Self {
+                    self / other
                 }
             }
         )+
     }
 }
 
-unsigned_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+unsigned_float_impl! { f32 f64 }
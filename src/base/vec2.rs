@@ -35,24 +35,58 @@ macro_rules! vec2_impl {
                     self.x * other.x + self.y * other.y
                 }
 
-                //pub fn norm(&self) -> $t {
-                //    self.dot(&self).sqrt()
-                //}
-
-                //pub fn normalize(&mut self) {
-                //    let d = self.norm();
-                //    if d != <$t>::ZERO {
-                //        self.x /= d;
-                //        self.y /= d;
-                //    }
-                //}
-
                 pub fn scale(&self,other: &Vec2<$t>) -> Self {
                     Vec2 {
                         x: self.x * other.x,
                         y: self.y * other.y,
                     }
                 }
+
+                pub fn min(self,other: Vec2<$t>) -> Self {
+                    Vec2 {
+                        x: Unsigned::min(self.x,other.x),
+                        y: Unsigned::min(self.y,other.y),
+                    }
+                }
+
+                pub fn max(self,other: Vec2<$t>) -> Self {
+                    Vec2 {
+                        x: Unsigned::max(self.x,other.x),
+                        y: Unsigned::max(self.y,other.y),
+                    }
+                }
+
+                pub fn abs(self) -> Self {
+                    Vec2 {
+                        x: Signed::abs(self.x),
+                        y: Signed::abs(self.y),
+                    }
+                }
+
+                pub fn clamp(self,min: Vec2<$t>,max: Vec2<$t>) -> Self {
+                    Vec2 {
+                        x: Unsigned::clamp(self.x,min.x,max.x),
+                        y: Unsigned::clamp(self.y,min.y,max.y),
+                    }
+                }
+
+                /// Perpendicular vector, rotated counterclockwise by a quarter turn.
+                pub fn perp(self) -> Self {
+                    Vec2 {
+                        x: -self.y,
+                        y: self.x,
+                    }
+                }
+
+                /// Z-component of the 3D cross product.
+                pub fn cross(self,other: Vec2<$t>) -> $t {
+                    self.x * other.y - self.y * other.x
+                }
+
+                /// Add component `z`.
+                pub fn extend(self,z: $t) -> Vec3<$t> {
+                    Vec3 { x: self.x,y: self.y,z, }
+                }
             }
 
             impl Display for Vec2<$t> {
@@ -172,4 +206,77 @@ macro_rules! vec2_impl {
     }
 }
 
-vec2_impl! { isize i8 i16 i32 i64 i128 f32 f64 }
+vec2_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
+
+macro_rules! vec2_real_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Vec2<$t> {
+                pub fn norm_sqr(self) -> $t {
+                    self.dot(self)
+                }
+
+                pub fn norm(self) -> $t {
+                    self.dot(self).sqrt()
+                }
+
+                /// Unit vector in the same direction, or the vector itself if it has zero length.
+                pub fn normalize(self) -> Self {
+                    let d = self.norm();
+                    if d != <$t>::ZERO {
+                        self / d
+                    }
+                    else {
+                        self
+                    }
+                }
+
+                pub fn distance(self,other: Vec2<$t>) -> $t {
+                    (other - self).norm()
+                }
+
+                pub fn lerp(self,other: Vec2<$t>,t: $t) -> Self {
+                    self + (other - self) * t
+                }
+
+                /// Reflect off a surface with unit normal `n`.
+                pub fn reflect(self,n: Vec2<$t>) -> Self {
+                    self - n * (2.0 * self.dot(n))
+                }
+
+                /// Refract unit vector `self` through a surface with unit normal `n`, with `eta` the ratio of refraction indices.
+                ///
+                /// Returns `None` on total internal reflection.
+                pub fn refract(self,n: Vec2<$t>,eta: $t) -> Option<Self> {
+                    let d = self.dot(n);
+                    let k = <$t>::ONE - eta * eta * (<$t>::ONE - d * d);
+                    if k < <$t>::ZERO {
+                        None
+                    }
+                    else {
+                        Some(self * eta - n * (eta * d + k.sqrt()))
+                    }
+                }
+
+                /// Component of `self` along `other`.
+                pub fn project(self,other: Vec2<$t>) -> Self {
+                    other * (self.dot(other) / other.dot(other))
+                }
+
+                /// Component of `self` perpendicular to `other`.
+                pub fn reject(self,other: Vec2<$t>) -> Self {
+                    self - self.project(other)
+                }
+
+                /// Angle in radians between `self` and `other`, between 0 and PI.
+                pub fn angle_between(self,other: Vec2<$t>) -> $t {
+                    let a = self.normalize();
+                    let b = other.normalize();
+                    2.0 * (a - b).norm().atan2((a + b).norm())
+                }
+            }
+        )+
+    }
+}
+
+vec2_real_impl! { f32 f64 }
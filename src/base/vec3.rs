@@ -36,18 +36,6 @@ macro_rules! vec3_impl {
                     self.x * other.x + self.y * other.y + self.z * other.z
                 }
 
-                //pub fn norm(&self) -> $t {
-                //    self.dot(&self).sqrt()
-                //}
-
-                //pub fn normalize(&mut self) {
-                //    let d = self.norm();
-                //    if d != <$t>::ZERO {
-                //        self.x /= d;
-                //        self.y /= d;
-                //    }
-                //}
-
                 pub fn scale(&self,other: &Vec3<$t>) -> Self {
                     Vec3 {
                         x: self.x * other.x,
@@ -55,6 +43,56 @@ macro_rules! vec3_impl {
                         z: self.z * other.z,
                     }
                 }
+
+                pub fn min(self,other: Vec3<$t>) -> Self {
+                    Vec3 {
+                        x: Unsigned::min(self.x,other.x),
+                        y: Unsigned::min(self.y,other.y),
+                        z: Unsigned::min(self.z,other.z),
+                    }
+                }
+
+                pub fn max(self,other: Vec3<$t>) -> Self {
+                    Vec3 {
+                        x: Unsigned::max(self.x,other.x),
+                        y: Unsigned::max(self.y,other.y),
+                        z: Unsigned::max(self.z,other.z),
+                    }
+                }
+
+                pub fn abs(self) -> Self {
+                    Vec3 {
+                        x: Signed::abs(self.x),
+                        y: Signed::abs(self.y),
+                        z: Signed::abs(self.z),
+                    }
+                }
+
+                pub fn clamp(self,min: Vec3<$t>,max: Vec3<$t>) -> Self {
+                    Vec3 {
+                        x: Unsigned::clamp(self.x,min.x,max.x),
+                        y: Unsigned::clamp(self.y,min.y,max.y),
+                        z: Unsigned::clamp(self.z,min.z,max.z),
+                    }
+                }
+
+                pub fn cross(self,other: Vec3<$t>) -> Self {
+                    Vec3 {
+                        x: self.y * other.z - self.z * other.y,
+                        y: self.z * other.x - self.x * other.z,
+                        z: self.x * other.y - self.y * other.x,
+                    }
+                }
+
+                /// Add component `w`.
+                pub fn extend(self,w: $t) -> Vec4<$t> {
+                    Vec4 { x: self.x,y: self.y,z: self.z,w, }
+                }
+
+                /// Drop component `z`.
+                pub fn truncate(self) -> Vec2<$t> {
+                    Vec2 { x: self.x,y: self.y, }
+                }
             }
 
             impl Display for Vec3<$t> {
@@ -184,4 +222,94 @@ macro_rules! vec3_impl {
     }
 }
 
-vec3_impl! { isize i8 i16 i32 i64 i128 f32 f64 }
+vec3_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
+
+macro_rules! vec3_real_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Vec3<$t> {
+                /// Homogeneous divide, divide by `z` and drop it.
+                pub fn dehomogenize(self) -> Vec2<$t> {
+                    Vec2 { x: self.x / self.z,y: self.y / self.z, }
+                }
+
+                pub fn norm_sqr(self) -> $t {
+                    self.dot(self)
+                }
+
+                pub fn norm(self) -> $t {
+                    self.dot(self).sqrt()
+                }
+
+                /// Unit vector in the same direction, or the vector itself if it has zero length.
+                pub fn normalize(self) -> Self {
+                    let d = self.norm();
+                    if d != <$t>::ZERO {
+                        self / d
+                    }
+                    else {
+                        self
+                    }
+                }
+
+                pub fn distance(self,other: Vec3<$t>) -> $t {
+                    (other - self).norm()
+                }
+
+                pub fn lerp(self,other: Vec3<$t>,t: $t) -> Self {
+                    self + (other - self) * t
+                }
+
+                /// Reflect off a surface with unit normal `n`.
+                pub fn reflect(self,n: Vec3<$t>) -> Self {
+                    self - n * (2.0 * self.dot(n))
+                }
+
+                /// Refract unit vector `self` through a surface with unit normal `n`, with `eta` the ratio of refraction indices.
+                ///
+                /// Returns `None` on total internal reflection.
+                pub fn refract(self,n: Vec3<$t>,eta: $t) -> Option<Self> {
+                    let d = self.dot(n);
+                    let k = <$t>::ONE - eta * eta * (<$t>::ONE - d * d);
+                    if k < <$t>::ZERO {
+                        None
+                    }
+                    else {
+                        Some(self * eta - n * (eta * d + k.sqrt()))
+                    }
+                }
+
+                /// Component of `self` along `other`.
+                pub fn project(self,other: Vec3<$t>) -> Self {
+                    other * (self.dot(other) / other.dot(other))
+                }
+
+                /// Component of `self` perpendicular to `other`.
+                pub fn reject(self,other: Vec3<$t>) -> Self {
+                    self - self.project(other)
+                }
+
+                /// Angle in radians between `self` and `other`, between 0 and PI.
+                pub fn angle_between(self,other: Vec3<$t>) -> $t {
+                    let a = self.normalize();
+                    let b = other.normalize();
+                    2.0 * (a - b).norm().atan2((a + b).norm())
+                }
+
+                /// Two unit vectors that form a right-handed orthonormal basis with unit vector `self`.
+                pub fn orthonormal_basis(self) -> (Vec3<$t>,Vec3<$t>) {
+                    // Duff et al., "Building an Orthonormal Basis, Revisited"
+                    let s = self.z.signum();
+                    let a = -<$t>::ONE / (s + self.z);
+                    let b = self.x * self.y * a;
+                    (
+                        Vec3 { x: <$t>::ONE + s * self.x * self.x * a,y: s * b,z: -s * self.x, },
+                        Vec3 { x: b,y: s + self.y * self.y * a,z: -self.y, },
+                    )
+                }
+            }
+        )+
+    }
+}
+
+vec3_real_impl! { f32 f64 }
@@ -37,18 +37,6 @@ macro_rules! vec4_impl {
                     self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
                 }
 
-                //pub fn norm(&self) -> $t {
-                //    self.dot(&self).sqrt()
-                //}
-
-                //pub fn normalize(&mut self) {
-                //    let d = self.norm();
-                //    if d != <$t>::ZERO {
-                //        self.x /= d;
-                //        self.y /= d;
-                //    }
-                //}
-
                 pub fn scale(&self,other: &Vec4<$t>) -> Self {
                     Vec4 {
                         x: self.x * other.x,
@@ -57,6 +45,47 @@ macro_rules! vec4_impl {
                         w: self.w * other.w,
                     }
                 }
+
+                pub fn min(self,other: Vec4<$t>) -> Self {
+                    Vec4 {
+                        x: Unsigned::min(self.x,other.x),
+                        y: Unsigned::min(self.y,other.y),
+                        z: Unsigned::min(self.z,other.z),
+                        w: Unsigned::min(self.w,other.w),
+                    }
+                }
+
+                pub fn max(self,other: Vec4<$t>) -> Self {
+                    Vec4 {
+                        x: Unsigned::max(self.x,other.x),
+                        y: Unsigned::max(self.y,other.y),
+                        z: Unsigned::max(self.z,other.z),
+                        w: Unsigned::max(self.w,other.w),
+                    }
+                }
+
+                pub fn abs(self) -> Self {
+                    Vec4 {
+                        x: Signed::abs(self.x),
+                        y: Signed::abs(self.y),
+                        z: Signed::abs(self.z),
+                        w: Signed::abs(self.w),
+                    }
+                }
+
+                pub fn clamp(self,min: Vec4<$t>,max: Vec4<$t>) -> Self {
+                    Vec4 {
+                        x: Unsigned::clamp(self.x,min.x,max.x),
+                        y: Unsigned::clamp(self.y,min.y,max.y),
+                        z: Unsigned::clamp(self.z,min.z,max.z),
+                        w: Unsigned::clamp(self.w,min.w,max.w),
+                    }
+                }
+
+                /// Drop component `w`.
+                pub fn truncate(self) -> Vec3<$t> {
+                    Vec3 { x: self.x,y: self.y,z: self.z, }
+                }
             }
 
             impl Display for Vec4<$t> {
@@ -196,4 +225,82 @@ macro_rules! vec4_impl {
     }
 }
 
-vec4_impl! { isize i8 i16 i32 i64 i128 f32 f64 }
+vec4_impl! { isize i8 i16 i32 i64 i128 f32 f64 f16 bf16 }
+
+macro_rules! vec4_real_impl {
+    ($($t:ty)+) => {
+        $(
+            impl Vec4<$t> {
+                /// Homogeneous divide, divide by `w` and drop it.
+                pub fn dehomogenize(self) -> Vec3<$t> {
+                    Vec3 { x: self.x / self.w,y: self.y / self.w,z: self.z / self.w, }
+                }
+
+                pub fn norm_sqr(self) -> $t {
+                    self.dot(self)
+                }
+
+                pub fn norm(self) -> $t {
+                    self.dot(self).sqrt()
+                }
+
+                /// Unit vector in the same direction, or the vector itself if it has zero length.
+                pub fn normalize(self) -> Self {
+                    let d = self.norm();
+                    if d != <$t>::ZERO {
+                        self / d
+                    }
+                    else {
+                        self
+                    }
+                }
+
+                pub fn distance(self,other: Vec4<$t>) -> $t {
+                    (other - self).norm()
+                }
+
+                pub fn lerp(self,other: Vec4<$t>,t: $t) -> Self {
+                    self + (other - self) * t
+                }
+
+                /// Reflect off a surface with unit normal `n`.
+                pub fn reflect(self,n: Vec4<$t>) -> Self {
+                    self - n * (2.0 * self.dot(n))
+                }
+
+                /// Refract unit vector `self` through a surface with unit normal `n`, with `eta` the ratio of refraction indices.
+                ///
+                /// Returns `None` on total internal reflection.
+                pub fn refract(self,n: Vec4<$t>,eta: $t) -> Option<Self> {
+                    let d = self.dot(n);
+                    let k = <$t>::ONE - eta * eta * (<$t>::ONE - d * d);
+                    if k < <$t>::ZERO {
+                        None
+                    }
+                    else {
+                        Some(self * eta - n * (eta * d + k.sqrt()))
+                    }
+                }
+
+                /// Component of `self` along `other`.
+                pub fn project(self,other: Vec4<$t>) -> Self {
+                    other * (self.dot(other) / other.dot(other))
+                }
+
+                /// Component of `self` perpendicular to `other`.
+                pub fn reject(self,other: Vec4<$t>) -> Self {
+                    self - self.project(other)
+                }
+
+                /// Angle in radians between `self` and `other`, between 0 and PI.
+                pub fn angle_between(self,other: Vec4<$t>) -> $t {
+                    let a = self.normalize();
+                    let b = other.normalize();
+                    2.0 * (a - b).norm().atan2((a + b).norm())
+                }
+            }
+        )+
+    }
+}
+
+vec4_real_impl! { f32 f64 }
@@ -10,6 +10,69 @@ macro_rules! dprintln {
     ($($arg:tt)*) => { };
 }
 
+/// Assert that two values are approximately equal with the default tolerances, see [`ApproxEq::approx_eq`].
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($a:expr,$b:expr $(,)?) => {
+        match (&$a,&$b) {
+            (a,b) => if !$crate::ApproxEq::approx_eq(a,b) {
+                panic!("assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}",a,b);
+            },
+        }
+    };
+}
+
+/// Assert that two values differ by at most `epsilon`, see [`ApproxEq::abs_diff_eq`].
+#[macro_export]
+macro_rules! assert_abs_diff_eq {
+    ($a:expr,$b:expr $(,)?) => {
+        match (&$a,&$b) {
+            (a,b) => $crate::assert_abs_diff_eq!(*a,*b,$crate::approx_default_epsilon(a)),
+        }
+    };
+    ($a:expr,$b:expr,$epsilon:expr $(,)?) => {
+        match (&$a,&$b,$epsilon) {
+            (a,b,epsilon) => if !$crate::ApproxEq::abs_diff_eq(a,b,epsilon) {
+                panic!("assertion `left ≈ right` failed (epsilon = {:?})\n  left: {:?}\n right: {:?}",epsilon,a,b);
+            },
+        }
+    };
+}
+
+/// Assert that two values are relatively equal, see [`ApproxEq::relative_eq`].
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($a:expr,$b:expr $(,)?) => {
+        match (&$a,&$b) {
+            (a,b) => $crate::assert_relative_eq!(*a,*b,$crate::approx_default_epsilon(a),$crate::approx_default_max_relative(a)),
+        }
+    };
+    ($a:expr,$b:expr,$epsilon:expr,$max_relative:expr $(,)?) => {
+        match (&$a,&$b,$epsilon,$max_relative) {
+            (a,b,epsilon,max_relative) => if !$crate::ApproxEq::relative_eq(a,b,epsilon,max_relative) {
+                panic!("assertion `left ≈ right` failed (epsilon = {:?}, max_relative = {:?})\n  left: {:?}\n right: {:?}",epsilon,max_relative,a,b);
+            },
+        }
+    };
+}
+
+/// Assert that two values are at most a number of representable steps apart, see [`ApproxEq::ulps_eq`].
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($a:expr,$b:expr $(,)?) => {
+        match (&$a,&$b) {
+            (a,b) => $crate::assert_ulps_eq!(*a,*b,$crate::approx_default_epsilon(a),$crate::approx_default_max_ulps(a)),
+        }
+    };
+    ($a:expr,$b:expr,$epsilon:expr,$max_ulps:expr $(,)?) => {
+        match (&$a,&$b,$epsilon,$max_ulps) {
+            (a,b,epsilon,max_ulps) => if !$crate::ApproxEq::ulps_eq(a,b,epsilon,max_ulps) {
+                panic!("assertion `left ≈ right` failed (epsilon = {:?}, max_ulps = {})\n  left: {:?}\n right: {:?}",epsilon,max_ulps,a,b);
+            },
+        }
+    };
+}
+
 #[doc(hidden)]
 mod sys;
 